@@ -0,0 +1,75 @@
+//! Compares incremental updates of Delaunay triangulations
+//! with rebuilding them from scratch.
+//!
+//! Run with `cargo bench --bench delaunay_updates`.
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use rene::geometries::Point;
+use rene::triangulation::DelaunayTriangulation;
+use rithm::big_int::BigInt;
+use rithm::fraction::Fraction;
+
+type Scalar = Fraction<BigInt<u32, 31>>;
+
+const UPDATES_COUNT: usize = 100;
+
+struct Random(u64);
+
+impl Random {
+    fn point(&mut self, limit: u64) -> Point<Scalar> {
+        let x = self.below(limit);
+        let y = self.below(limit);
+        Point::new(
+            Scalar::from(BigInt::from(x)),
+            Scalar::from(BigInt::from(y)),
+        )
+    }
+
+    fn below(&mut self, limit: u64) -> i64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % limit) as i64
+    }
+}
+
+fn main() {
+    let mut random = Random(0x2545_f491_4f6c_dd1d);
+    for points_count in [1_000, 4_000, 16_000] {
+        let limit = 8 * points_count as u64;
+        let points = (0..points_count)
+            .map(|_| random.point(limit))
+            .collect::<Vec<_>>();
+        let updates = (0..UPDATES_COUNT)
+            .map(|_| random.point(limit))
+            .collect::<Vec<_>>();
+        let mut triangulation = DelaunayTriangulation::from(points.clone());
+        let insertions_duration = measure(|| {
+            for point in &updates {
+                triangulation.insert(point.clone());
+            }
+        });
+        let removals_duration = measure(|| {
+            for point in &updates {
+                triangulation.remove(point);
+            }
+        });
+        let rebuild_duration = measure(|| {
+            black_box(DelaunayTriangulation::from(points.clone()));
+        });
+        println!(
+            "{} points: insertion {:?}, removal {:?}, rebuild {:?}",
+            points_count,
+            insertions_duration / UPDATES_COUNT as u32,
+            removals_duration / UPDATES_COUNT as u32,
+            rebuild_duration,
+        );
+    }
+}
+
+fn measure(function: impl FnOnce()) -> Duration {
+    let start = Instant::now();
+    function();
+    start.elapsed()
+}
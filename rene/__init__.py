@@ -8,6 +8,7 @@ except ImportError:
     from ._rene import (MIN_CONTOUR_VERTICES_COUNT,
                         MIN_MULTIPOLYGON_POLYGONS_COUNT,
                         MIN_MULTISEGMENT_SEGMENTS_COUNT,
+                        FillRule,
                         Location,
                         Orientation,
                         Relation)
@@ -15,6 +16,26 @@ else:
     MIN_CONTOUR_VERTICES_COUNT = _crene.MIN_CONTOUR_VERTICES_COUNT
     MIN_MULTIPOLYGON_POLYGONS_COUNT = _crene.MIN_MULTIPOLYGON_POLYGONS_COUNT
     MIN_MULTISEGMENT_SEGMENTS_COUNT = _crene.MIN_MULTISEGMENT_SEGMENTS_COUNT
+    FillRule = _crene.FillRule
     Location = _crene.Location
     Orientation = _crene.Orientation
     Relation = _crene.Relation
+
+from ._validity import (BorderError,
+                        CollinearVerticesError,
+                        DegenerateSegmentError,
+                        DisconnectedInteriorError,
+                        HoleBorderIntersectionError,
+                        HoleError,
+                        HoleOutsideBorderError,
+                        HolesIntersectionError,
+                        HolesOverlapError,
+                        PolygonError,
+                        PolygonsIntersectionError,
+                        PolygonsOverlapError,
+                        SegmentsIntersectionError,
+                        TooFewPolygonsError,
+                        TooFewSegmentsError,
+                        TooFewVerticesError,
+                        UntouchedNeighbourSegmentsError,
+                        ValidityError)
@@ -4,6 +4,25 @@ import typing as _t
 
 import typing_extensions as _te
 
+from ._validity import (BorderError as BorderError,
+                        CollinearVerticesError as CollinearVerticesError,
+                        DegenerateSegmentError as DegenerateSegmentError,
+                        DisconnectedInteriorError as DisconnectedInteriorError,
+                        HoleBorderIntersectionError as HoleBorderIntersectionError,
+                        HoleError as HoleError,
+                        HoleOutsideBorderError as HoleOutsideBorderError,
+                        HolesIntersectionError as HolesIntersectionError,
+                        HolesOverlapError as HolesOverlapError,
+                        PolygonError as PolygonError,
+                        PolygonsIntersectionError as PolygonsIntersectionError,
+                        PolygonsOverlapError as PolygonsOverlapError,
+                        SegmentsIntersectionError as SegmentsIntersectionError,
+                        TooFewPolygonsError as TooFewPolygonsError,
+                        TooFewSegmentsError as TooFewSegmentsError,
+                        TooFewVerticesError as TooFewVerticesError,
+                        UntouchedNeighbourSegmentsError as UntouchedNeighbourSegmentsError,
+                        ValidityError as ValidityError)
+
 __version__: str
 
 MIN_CONTOUR_VERTICES_COUNT: int = ...
@@ -11,6 +30,26 @@ MIN_MULTIPOLYGON_POLYGONS_COUNT: int = ...
 MIN_MULTISEGMENT_SEGMENTS_COUNT: int = ...
 
 
+@_te.final
+class FillRule:
+    EVEN_ODD: _t.ClassVar[_te.Self] = ...
+    NON_ZERO: _t.ClassVar[_te.Self] = ...
+
+    @_t.overload
+    def __eq__(self, other: _te.Self, /) -> bool:
+        ...
+
+    @_t.overload
+    def __eq__(self, other: _t.Any, /) -> _t.Any:
+        ...
+
+    def __repr__(self) -> str:
+        ...
+
+    def __str__(self) -> str:
+        ...
+
+
 @_te.final
 class Location:
     BOUNDARY: _t.ClassVar[_te.Self] = ...
@@ -7,6 +7,9 @@ from rene import (Relation,
                   hints)
 from rene._hints import (Orienteer,
                          SegmentsIntersector)
+from rene._relating.segment_endpoints import relate_to_segment_endpoints
+from rene._utils import to_sorted_pair
+from .event import is_event_left
 from .events_registry import EventsRegistry
 
 
@@ -65,6 +68,12 @@ def sweep(
             segments, orienteer, segments_intersector,
             unique=False
     )
+    return sweep_events_registry(events_registry)
+
+
+def sweep_events_registry(
+        events_registry: EventsRegistry[hints.Scalar], /
+) -> t.Iterable[Intersection[hints.Scalar]]:
     events = iter(events_registry)
     event = next(events)
     start = events_registry.to_event_start(event)
@@ -90,6 +99,149 @@ def sweep(
     )
 
 
+def to_segments_intersections(
+        segments: t.Sequence[hints.Segment[hints.Scalar]],
+        orienteer: Orienteer[hints.Scalar],
+        segments_intersector: SegmentsIntersector[hints.Scalar],
+        /
+) -> t.List[Intersection[hints.Scalar]]:
+    """
+    Returns intersections of each pair of distinct intersecting segments
+    ordered by segments ids.
+    Sweep reports segments with events at a common point,
+    but overlapping parts of segments share a single entry
+    in the sweep line and get divided only once,
+    so pairs with members of collinear groups are completed afterwards.
+    """
+    if not segments:
+        return []
+    events_registry = EventsRegistry.from_segments(
+            segments, orienteer, segments_intersector,
+            unique=False
+    )
+    result: t.Dict[t.Tuple[int, int], Intersection[hints.Scalar]] = {}
+    for intersection in sweep_events_registry(events_registry):
+        first_segment_id, second_segment_id = (
+            intersection.first_segment_id, intersection.second_segment_id
+        )
+        if first_segment_id < second_segment_id:
+            result.setdefault((first_segment_id, second_segment_id),
+                              intersection)
+        elif first_segment_id > second_segment_id:
+            result.setdefault(
+                    (second_segment_id, first_segment_id),
+                    Intersection(second_segment_id, first_segment_id,
+                                 intersection.relation.complement,
+                                 intersection.start, intersection.end)
+            )
+    collinear_groups: t.Dict[int, t.List[int]] = {}
+    for segment_id in range(len(segments)):
+        collinear_groups.setdefault(
+                events_registry.to_min_collinear_segment_id(segment_id), []
+        ).append(segment_id)
+    for group in collinear_groups.values():
+        for index, first_segment_id in enumerate(group):
+            for second_segment_id in group[index + 1:]:
+                start = max(events_registry.to_segment_start(first_segment_id),
+                            events_registry.to_segment_start(
+                                    second_segment_id
+                            ))
+                end = min(events_registry.to_segment_end(first_segment_id),
+                          events_registry.to_segment_end(second_segment_id))
+                if (start <= end
+                        and (first_segment_id, second_segment_id)
+                        not in result):
+                    result[(first_segment_id, second_segment_id)] = (
+                        to_intersection(events_registry, first_segment_id,
+                                        second_segment_id, start, end,
+                                        orienteer)
+                    )
+
+    def to_group_segments_ids_containing_point(
+            segment_id: int, point: hints.Point[hints.Scalar], /
+    ) -> t.List[int]:
+        return [
+            member_id
+            for member_id in collinear_groups[
+                events_registry.to_min_collinear_segment_id(segment_id)
+            ]
+            if (events_registry.to_segment_start(member_id) <= point
+                <= events_registry.to_segment_end(member_id))
+        ]
+
+    points_intersections = [
+        (intersection.first_segment_id, intersection.second_segment_id,
+         intersection.start)
+        for intersection in result.values()
+        if (intersection.start == intersection.end
+            and (len(collinear_groups[
+                     events_registry.to_min_collinear_segment_id(
+                             intersection.first_segment_id
+                     )
+                 ]) > 1
+                 or len(collinear_groups[
+                            events_registry.to_min_collinear_segment_id(
+                                    intersection.second_segment_id
+                            )
+                        ]) > 1))
+    ]
+    for first_segment_id, second_segment_id, point in points_intersections:
+        first_segments_ids = to_group_segments_ids_containing_point(
+                first_segment_id, point
+        )
+        second_segments_ids = to_group_segments_ids_containing_point(
+                second_segment_id, point
+        )
+        for first_member_id in first_segments_ids:
+            for second_member_id in second_segments_ids:
+                if first_member_id != second_member_id:
+                    key = to_sorted_pair(first_member_id, second_member_id)
+                    if key not in result:
+                        result[key] = to_intersection(events_registry, *key,
+                                                      point, point, orienteer)
+    return [result[key] for key in sorted(result)]
+
+
+def to_unique_non_crossing_or_overlapping_segments(
+        segments: t.Sequence[hints.Segment[hints.Scalar]],
+        orienteer: Orienteer[hints.Scalar],
+        segments_intersector: SegmentsIntersector[hints.Scalar],
+        /
+) -> t.List[t.Tuple[hints.Point[hints.Scalar], hints.Point[hints.Scalar]]]:
+    """
+    Splits segments into unique non-crossing & non-overlapping pieces
+    with sorted endpoints.
+    """
+    events_registry = EventsRegistry.from_segments(
+            segments, orienteer, segments_intersector,
+            unique=True
+    )
+    return [to_sorted_pair(events_registry.to_event_start(event),
+                           events_registry.to_event_end(event))
+            for event in events_registry
+            if not is_event_left(event)]
+
+
+def to_intersection(events_registry: EventsRegistry[hints.Scalar],
+                    first_segment_id: int,
+                    second_segment_id: int,
+                    start: hints.Point[hints.Scalar],
+                    end: hints.Point[hints.Scalar],
+                    orienteer: Orienteer[hints.Scalar],
+                    /) -> Intersection[hints.Scalar]:
+    return Intersection(
+            first_segment_id, second_segment_id,
+            relate_to_segment_endpoints(
+                    events_registry.to_segment_start(first_segment_id),
+                    events_registry.to_segment_end(first_segment_id),
+                    events_registry.to_segment_start(second_segment_id),
+                    events_registry.to_segment_end(second_segment_id),
+                    orienteer
+            ),
+            start, end
+    )
+
+
 def segments_ids_containing_point_to_intersections(
         segments_ids: t.Sequence[int],
         point: hints.Point[hints.Scalar],
@@ -51,8 +51,8 @@ class EventsRegistry(t.Generic[hints.Scalar]):
                       first_segment_id: int,
                       second_segment_id: int,
                       /) -> bool:
-        return (self._to_min_collinear_segment_id(first_segment_id)
-                == self._to_min_collinear_segment_id(second_segment_id))
+        return (self.to_min_collinear_segment_id(first_segment_id)
+                == self.to_min_collinear_segment_id(second_segment_id))
 
     def to_event_end(self, event: Event, /) -> hints.Point[hints.Scalar]:
         return self.to_event_start(self._to_opposite_event(event))
@@ -67,6 +67,12 @@ class EventsRegistry(t.Generic[hints.Scalar]):
     def to_event_start(self, event: Event, /) -> hints.Point[hints.Scalar]:
         return self._endpoints[event]
 
+    def to_min_collinear_segment_id(self, segment_id: int, /) -> int:
+        candidate = segment_id
+        while self._min_collinear_segments_ids[candidate] != candidate:
+            candidate = self._min_collinear_segments_ids[candidate]
+        return candidate
+
     def to_segment_end(
             self, segment_id: int, /
     ) -> hints.Point[hints.Scalar]:
@@ -356,11 +362,11 @@ class EventsRegistry(t.Generic[hints.Scalar]):
     ) -> None:
         first_segment_id = self._to_left_event_segment_id(first)
         second_segment_id = self._to_left_event_segment_id(second)
-        first_min_collinear_segment_id = (
-            self._min_collinear_segments_ids[first_segment_id]
+        first_min_collinear_segment_id = self.to_min_collinear_segment_id(
+                first_segment_id
         )
-        second_min_collinear_segment_id = (
-            self._min_collinear_segments_ids[second_segment_id]
+        second_min_collinear_segment_id = self.to_min_collinear_segment_id(
+                second_segment_id
         )
         min_collinear_segment_id = min(first_min_collinear_segment_id,
                                        second_min_collinear_segment_id)
@@ -391,14 +397,5 @@ class EventsRegistry(t.Generic[hints.Scalar]):
         assert is_event_left(event)
         return self._segments_ids[event // 2]
 
-    def _to_min_collinear_segment_id(self, segment_id: int, /) -> int:
-        candidate = segment_id
-        iterations_count = 0
-        while self._min_collinear_segments_ids[candidate] != candidate:
-            candidate = self._min_collinear_segments_ids[candidate]
-            iterations_count += 1
-        assert iterations_count < 3
-        return candidate
-
     def _to_opposite_event(self, event: Event, /) -> Event:
         return self._opposites[event]
@@ -0,0 +1,489 @@
+from __future__ import annotations
+
+import typing as t
+
+from rene import (MIN_CONTOUR_VERTICES_COUNT,
+                  MIN_MULTIPOLYGON_POLYGONS_COUNT,
+                  MIN_MULTISEGMENT_SEGMENTS_COUNT,
+                  Orientation,
+                  Relation,
+                  hints)
+from rene._hints import (Orienteer,
+                         SegmentsIntersector)
+from rene._relating.shaped import relate_regions
+from rene._validity import (BorderError,
+                            CollinearVerticesError,
+                            DegenerateSegmentError,
+                            DisconnectedInteriorError,
+                            HoleBorderIntersectionError,
+                            HoleError,
+                            HoleOutsideBorderError,
+                            HolesIntersectionError,
+                            HolesOverlapError,
+                            PolygonError,
+                            PolygonsIntersectionError,
+                            PolygonsOverlapError,
+                            SegmentsIntersectionError,
+                            TooFewPolygonsError,
+                            TooFewSegmentsError,
+                            TooFewVerticesError,
+                            UntouchedNeighbourSegmentsError,
+                            ValidityError)
+from .base import (Intersection,
+                   sweep)
+
+_IntersectionKey = t.Tuple[int, int, hints.Point[hints.Scalar],
+                           hints.Point[hints.Scalar]]
+
+
+def validate_contour(
+        contour: hints.Contour[hints.Scalar],
+        orienteer: Orienteer[hints.Scalar],
+        segments_intersector: SegmentsIntersector[hints.Scalar],
+        is_exhaustive: bool,
+        /
+) -> t.List[ValidityError]:
+    """
+    Collects validity errors of the contour,
+    stopping at the first one unless exhaustive.
+    """
+    vertices = contour.vertices
+    if len(vertices) < MIN_CONTOUR_VERTICES_COUNT:
+        return [TooFewVerticesError(len(vertices))]
+    segments = contour.segments
+    result = to_degenerate_segments_errors(segments)
+    if not is_exhaustive and result:
+        return result
+    vertices_count = len(vertices)
+    for vertex_id in range(vertices_count):
+        vertices_ids = ((vertex_id + vertices_count - 2) % vertices_count,
+                        (vertex_id + vertices_count - 1) % vertices_count,
+                        vertex_id)
+        first_vertex, second_vertex, third_vertex = (
+            vertices[vertices_ids[0]], vertices[vertices_ids[1]],
+            vertices[vertices_ids[2]]
+        )
+        if (first_vertex != second_vertex and second_vertex != third_vertex
+                and (orienteer(first_vertex, second_vertex, third_vertex)
+                     is Orientation.COLLINEAR)):
+            result.append(CollinearVerticesError(
+                    vertices_ids, (first_vertex, second_vertex, third_vertex)
+            ))
+            if not is_exhaustive:
+                return result
+    if result:
+        return result
+    intersections_keys: t.Set[_IntersectionKey[hints.Scalar]] = set()
+    neighbour_segments_touches_count = 0
+    for intersection in sweep(segments, orienteer, segments_intersector):
+        if intersection.first_segment_id == intersection.second_segment_id:
+            continue
+        if is_neighbour_segments_touch(intersection, segments):
+            neighbour_segments_touches_count += 1
+        else:
+            intersection_key = to_intersection_key(
+                    intersection.first_segment_id,
+                    intersection.second_segment_id, intersection
+            )
+            if intersection_key not in intersections_keys:
+                intersections_keys.add(intersection_key)
+                result.append(intersection_to_segments_error(intersection))
+                if not is_exhaustive:
+                    return result
+    if neighbour_segments_touches_count != len(segments):
+        result.append(UntouchedNeighbourSegmentsError(
+                neighbour_segments_touches_count, len(segments)
+        ))
+    return result
+
+
+def validate_multipolygon(
+        multipolygon: hints.Multipolygon[hints.Scalar],
+        orienteer: Orienteer[hints.Scalar],
+        segments_intersector: SegmentsIntersector[hints.Scalar],
+        segment_cls: t.Type[hints.Segment[hints.Scalar]],
+        is_exhaustive: bool,
+        /
+) -> t.List[ValidityError]:
+    """
+    Collects validity errors of the multipolygon,
+    stopping at the first one unless exhaustive.
+    """
+    polygons = multipolygon.polygons
+    if len(polygons) < MIN_MULTIPOLYGON_POLYGONS_COUNT:
+        return [TooFewPolygonsError(len(polygons))]
+    result: t.List[ValidityError] = []
+    for polygon_id, polygon in enumerate(polygons):
+        result.extend(
+                PolygonError(polygon_id, error)
+                for error in validate_polygon(polygon, orienteer,
+                                              segments_intersector,
+                                              segment_cls, is_exhaustive)
+        )
+        if not is_exhaustive and result:
+            return result
+    if result:
+        return result
+    borders: t.List[hints.Contour[hints.Scalar]] = []
+    borders_polygons_ids: t.List[int] = []
+    for polygon_id, polygon in enumerate(polygons):
+        borders.append(polygon.border)
+        borders.extend(polygon.holes)
+        borders_polygons_ids.extend([polygon_id] * (len(polygon.holes) + 1))
+    intersections_keys: t.Set[_IntersectionKey[hints.Scalar]] = set()
+    for first_border_id, second_border_id, intersection in (
+            to_borders_intersections(borders, orienteer, segments_intersector)
+    ):
+        first_polygon_id = borders_polygons_ids[first_border_id]
+        second_polygon_id = borders_polygons_ids[second_border_id]
+        if (first_polygon_id == second_polygon_id
+                or intersection.relation is Relation.TOUCH):
+            continue
+        intersection_key = to_intersection_key(first_polygon_id,
+                                               second_polygon_id,
+                                               intersection)
+        if intersection_key in intersections_keys:
+            continue
+        intersections_keys.add(intersection_key)
+        result.append(PolygonsIntersectionError(
+                min(first_polygon_id, second_polygon_id),
+                max(first_polygon_id, second_polygon_id),
+                intersection.relation, intersection.start, intersection.end
+        ))
+        if not is_exhaustive:
+            return result
+    if result:
+        return result
+    for first_polygon_id, second_polygon_id in (
+            to_boxes_ids_pairs_with_intersection(
+                    [polygon.bounding_box for polygon in polygons]
+            )
+    ):
+        if not are_polygons_interiors_disjoint(
+                polygons[first_polygon_id], polygons[second_polygon_id],
+                orienteer, segments_intersector, segment_cls
+        ):
+            result.append(PolygonsOverlapError(
+                    min(first_polygon_id, second_polygon_id),
+                    max(first_polygon_id, second_polygon_id)
+            ))
+            if not is_exhaustive:
+                break
+    return result
+
+
+def validate_multisegment(
+        multisegment: hints.Multisegment[hints.Scalar],
+        orienteer: Orienteer[hints.Scalar],
+        segments_intersector: SegmentsIntersector[hints.Scalar],
+        is_exhaustive: bool,
+        /
+) -> t.List[ValidityError]:
+    """
+    Collects validity errors of the multisegment,
+    stopping at the first one unless exhaustive.
+    """
+    segments = multisegment.segments
+    if len(segments) < MIN_MULTISEGMENT_SEGMENTS_COUNT:
+        return [TooFewSegmentsError(len(segments))]
+    result = to_degenerate_segments_errors(segments)
+    if result:
+        return result
+    intersections_keys: t.Set[_IntersectionKey[hints.Scalar]] = set()
+    for intersection in sweep(segments, orienteer, segments_intersector):
+        if (intersection.first_segment_id == intersection.second_segment_id
+                or intersection.relation is Relation.TOUCH):
+            continue
+        intersection_key = to_intersection_key(
+                intersection.first_segment_id, intersection.second_segment_id,
+                intersection
+        )
+        if intersection_key in intersections_keys:
+            continue
+        intersections_keys.add(intersection_key)
+        result.append(intersection_to_segments_error(intersection))
+        if not is_exhaustive:
+            break
+    return result
+
+
+def validate_polygon(
+        polygon: hints.Polygon[hints.Scalar],
+        orienteer: Orienteer[hints.Scalar],
+        segments_intersector: SegmentsIntersector[hints.Scalar],
+        segment_cls: t.Type[hints.Segment[hints.Scalar]],
+        is_exhaustive: bool,
+        /
+) -> t.List[ValidityError]:
+    """
+    Collects validity errors of the polygon,
+    stopping at the first one unless exhaustive.
+    """
+    border, holes = polygon.border, polygon.holes
+    result: t.List[ValidityError] = [
+        BorderError(error)
+        for error in validate_contour(border, orienteer,
+                                      segments_intersector, is_exhaustive)
+    ]
+    if not is_exhaustive and result:
+        return result
+    for hole_id, hole in enumerate(holes):
+        result.extend(
+                HoleError(hole_id, error)
+                for error in validate_contour(hole, orienteer,
+                                              segments_intersector,
+                                              is_exhaustive)
+        )
+        if not is_exhaustive and result:
+            return result
+    if result:
+        return result
+    borders = [border, *holes]
+    intersections_keys: t.Set[_IntersectionKey[hints.Scalar]] = set()
+    touches: t.List[t.Tuple[int, int, hints.Point[hints.Scalar]]] = []
+    for first_border_id, second_border_id, intersection in (
+            to_borders_intersections(borders, orienteer, segments_intersector)
+    ):
+        if intersection.relation is Relation.TOUCH:
+            touches.append((first_border_id, second_border_id,
+                            intersection.start))
+            continue
+        intersection_key = to_intersection_key(first_border_id,
+                                               second_border_id, intersection)
+        if intersection_key in intersections_keys:
+            continue
+        intersections_keys.add(intersection_key)
+        if first_border_id == 0 or second_border_id == 0:
+            result.append(HoleBorderIntersectionError(
+                    max(first_border_id, second_border_id) - 1,
+                    intersection.relation, intersection.start,
+                    intersection.end
+            ))
+        else:
+            result.append(HolesIntersectionError(
+                    min(first_border_id, second_border_id) - 1,
+                    max(first_border_id, second_border_id) - 1,
+                    intersection.relation, intersection.start,
+                    intersection.end
+            ))
+        if not is_exhaustive:
+            return result
+    if result:
+        return result
+    for hole_id, hole in enumerate(holes):
+        if relate_regions(hole, border, orienteer, segments_intersector,
+                          segment_cls) not in (Relation.WITHIN,
+                                               Relation.ENCLOSED):
+            result.append(HoleOutsideBorderError(hole_id))
+            if not is_exhaustive:
+                return result
+    for first_hole_id, second_hole_id in (
+            to_boxes_ids_pairs_with_intersection(
+                    [hole.bounding_box for hole in holes]
+            )
+    ):
+        if relate_regions(holes[first_hole_id], holes[second_hole_id],
+                          orienteer, segments_intersector,
+                          segment_cls) not in (Relation.DISJOINT,
+                                               Relation.TOUCH):
+            result.append(HolesOverlapError(
+                    min(first_hole_id, second_hole_id),
+                    max(first_hole_id, second_hole_id)
+            ))
+            if not is_exhaustive:
+                return result
+    if result:
+        return result
+    cycle_closing_touch = find_cycle_closing_touch(len(borders), touches)
+    if cycle_closing_touch is not None:
+        first_border_id, second_border_id, point = cycle_closing_touch
+        result.append(DisconnectedInteriorError(
+                max(first_border_id, second_border_id) - 1, point
+        ))
+    return result
+
+
+def are_polygons_interiors_disjoint(
+        first: hints.Polygon[hints.Scalar],
+        second: hints.Polygon[hints.Scalar],
+        orienteer: Orienteer[hints.Scalar],
+        segments_intersector: SegmentsIntersector[hints.Scalar],
+        segment_cls: t.Type[hints.Segment[hints.Scalar]],
+        /
+) -> bool:
+    """
+    Checks if interiors of polygons do not intersect,
+    assuming that their contours do not cross or overlap.
+    """
+    relation = relate_regions(first.border, second.border, orienteer,
+                              segments_intersector, segment_cls)
+    if relation is Relation.DISJOINT or relation is Relation.TOUCH:
+        return True
+    elif relation is Relation.WITHIN or relation is Relation.ENCLOSED:
+        return is_region_inside_some_hole(first.border, second.holes,
+                                          orienteer, segments_intersector,
+                                          segment_cls)
+    elif relation is Relation.COVER or relation is Relation.ENCLOSES:
+        return is_region_inside_some_hole(second.border, first.holes,
+                                          orienteer, segments_intersector,
+                                          segment_cls)
+    else:
+        return False
+
+
+def find_cycle_closing_touch(
+        borders_count: int,
+        touches: t.Iterable[t.Tuple[int, int, hints.Point[hints.Scalar]]],
+        /
+) -> t.Optional[t.Tuple[int, int, hints.Point[hints.Scalar]]]:
+    """
+    Returns a touch which closes a cycle in the graph
+    with borders and their touch points as nodes, if any,
+    such a cycle means that the interior bounded by borders is disconnected.
+    """
+    parents = list(range(borders_count))
+    points_nodes: t.Dict[hints.Point[hints.Scalar], int] = {}
+    edges: t.Set[t.Tuple[int, int]] = set()
+    for first_border_id, second_border_id, point in touches:
+        point_node = points_nodes.get(point)
+        if point_node is None:
+            point_node = points_nodes[point] = len(parents)
+            parents.append(point_node)
+        for border_id in (first_border_id, second_border_id):
+            if (border_id, point_node) in edges:
+                continue
+            edges.add((border_id, point_node))
+            border_root = _to_union_find_root(parents, border_id)
+            point_root = _to_union_find_root(parents, point_node)
+            if border_root == point_root:
+                return first_border_id, second_border_id, point
+            parents[border_root] = point_root
+    return None
+
+
+def intersection_to_segments_error(
+        intersection: Intersection[hints.Scalar], /
+) -> ValidityError:
+    return SegmentsIntersectionError(
+            min(intersection.first_segment_id,
+                intersection.second_segment_id),
+            max(intersection.first_segment_id,
+                intersection.second_segment_id),
+            intersection.relation, intersection.start, intersection.end
+    )
+
+
+def is_neighbour_segments_touch(
+        intersection: Intersection[hints.Scalar],
+        segments: t.Sequence[hints.Segment[hints.Scalar]],
+        /
+) -> bool:
+    """
+    Checks if given intersection of contour segments
+    is a touch of neighbour segments at their common vertex.
+    """
+    first_segment = segments[intersection.first_segment_id]
+    second_segment = segments[intersection.second_segment_id]
+    touches_at_vertices = (
+            intersection.relation is Relation.TOUCH
+            and (intersection.start == first_segment.start
+                 or intersection.start == first_segment.end)
+            and (intersection.start == second_segment.start
+                 or intersection.start == second_segment.end)
+    )
+    segments_ids_difference = abs(intersection.first_segment_id
+                                  - intersection.second_segment_id)
+    return touches_at_vertices and (segments_ids_difference == 1
+                                    or (segments_ids_difference
+                                        == len(segments) - 1))
+
+
+def is_region_inside_some_hole(
+        border: hints.Contour[hints.Scalar],
+        holes: t.Sequence[hints.Contour[hints.Scalar]],
+        orienteer: Orienteer[hints.Scalar],
+        segments_intersector: SegmentsIntersector[hints.Scalar],
+        segment_cls: t.Type[hints.Segment[hints.Scalar]],
+        /
+) -> bool:
+    """
+    Checks if the region bounded by given border lies inside one of holes,
+    assuming that border does not cross holes.
+    """
+    return any(relate_regions(border, hole, orienteer, segments_intersector,
+                              segment_cls) in (Relation.WITHIN,
+                                               Relation.ENCLOSED)
+               for hole in holes)
+
+
+def to_borders_intersections(
+        borders: t.Sequence[hints.Contour[hints.Scalar]],
+        orienteer: Orienteer[hints.Scalar],
+        segments_intersector: SegmentsIntersector[hints.Scalar],
+        /
+) -> t.Iterable[t.Tuple[int, int, Intersection[hints.Scalar]]]:
+    """
+    Lazily yields intersections of segments from different borders
+    along with ids of these borders.
+    """
+    segments: t.List[hints.Segment[hints.Scalar]] = []
+    segments_borders_ids: t.List[int] = []
+    for border_id, border in enumerate(borders):
+        border_segments = border.segments
+        segments.extend(border_segments)
+        segments_borders_ids.extend([border_id] * len(border_segments))
+    for intersection in sweep(segments, orienteer, segments_intersector):
+        first_border_id = segments_borders_ids[intersection.first_segment_id]
+        second_border_id = segments_borders_ids[
+            intersection.second_segment_id
+        ]
+        if first_border_id != second_border_id:
+            yield first_border_id, second_border_id, intersection
+
+
+def to_boxes_ids_pairs_with_intersection(
+        boxes: t.Sequence[hints.Box[hints.Scalar]], /
+) -> t.List[t.Tuple[int, int]]:
+    ids = sorted(range(len(boxes)), key=lambda id_: boxes[id_].min_x)
+    result = []
+    for position, id_ in enumerate(ids):
+        box = boxes[id_]
+        for other_id in ids[position + 1:]:
+            other_box = boxes[other_id]
+            if other_box.min_x > box.max_x:
+                break
+            if not box.disjoint_with(other_box):
+                result.append((id_, other_id))
+    return result
+
+
+def to_degenerate_segments_errors(
+        segments: t.Sequence[hints.Segment[hints.Scalar]], /
+) -> t.List[ValidityError]:
+    return [DegenerateSegmentError(segment_id, segment.start)
+            for segment_id, segment in enumerate(segments)
+            if segment.start == segment.end]
+
+
+def to_intersection_key(
+        first_id: int,
+        second_id: int,
+        intersection: Intersection[hints.Scalar],
+        /
+) -> _IntersectionKey[hints.Scalar]:
+    """
+    Identifies intersection between components with given ids
+    regardless of their order,
+    since sweep can report the same intersection several times.
+    """
+    return (min(first_id, second_id), max(first_id, second_id),
+            intersection.start, intersection.end)
+
+
+def _to_union_find_root(parents: t.List[int], node: int, /) -> int:
+    root = node
+    while parents[root] != root:
+        root = parents[root]
+    while parents[node] != root:
+        parents[node], node = root, parents[node]
+    return root
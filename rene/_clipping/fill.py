@@ -0,0 +1,158 @@
+import typing as t
+
+import typing_extensions as te
+
+from rene import (FillRule,
+                  Orientation,
+                  hints)
+from rene._bentley_ottmann.base import (
+    to_unique_non_crossing_or_overlapping_segments
+)
+from rene._hints import (Orienteer,
+                         SegmentsIntersector)
+from rene._utils import is_odd
+from . import shaped
+from .event import (Event,
+                    left_event_to_position)
+
+_WindedSegment = t.Tuple[hints.Point[hints.Scalar],
+                         hints.Point[hints.Scalar], int]
+
+
+class FillOperation(shaped.Operation[hints.Scalar]):
+    @classmethod
+    def from_winded_segments(
+            cls,
+            winded_segments: t.Sequence[_WindedSegment[hints.Scalar]],
+            orienteer: Orienteer[hints.Scalar],
+            segments_intersector: SegmentsIntersector[hints.Scalar],
+            /
+    ) -> te.Self:
+        endpoints: t.List[hints.Point[hints.Scalar]] = []
+        for start, end, _ in winded_segments:
+            assert start < end
+            endpoints.append(start)
+            endpoints.append(end)
+        self = cls(len(winded_segments), 0, endpoints,
+                   [True] * len(winded_segments), orienteer,
+                   segments_intersector)
+        self._windings = [winding for _, _, winding in winded_segments]
+        self._below_windings = [0] * len(winded_segments)
+        self._current_endpoint_first_event = self._peek()
+        return self
+
+    _below_windings: t.List[int]
+    _windings: t.List[int]
+
+    def _compute_left_event_fields(
+            self, event: Event, below_event: t.Optional[Event], /
+    ) -> None:
+        if below_event is not None:
+            self._below_windings[left_event_to_position(event)] = (
+                    self._below_windings[left_event_to_position(below_event)]
+                    + self._to_left_event_winding(below_event)
+            )
+        super()._compute_left_event_fields(event, below_event)
+
+    def _divide(
+            self, event: Event, mid_point: hints.Point[hints.Scalar], /
+    ) -> t.Tuple[Event, Event]:
+        self._below_windings.append(0)
+        return super()._divide(event, mid_point)
+
+    def _to_left_event_winding(self, event: Event, /) -> int:
+        segment_id = self._left_event_to_segment_id(event)
+        winding = self._windings[segment_id]
+        return (winding
+                if self.have_interior_to_left[segment_id]
+                else -winding)
+
+
+class EvenOddFill(FillOperation[hints.Scalar]):
+    def _detect_if_left_event_from_result(self, event: Event, /) -> bool:
+        below_winding = self._below_windings[left_event_to_position(event)]
+        return is_odd(below_winding) is not is_odd(
+                below_winding + self._to_left_event_winding(event)
+        )
+
+
+class NonZeroFill(FillOperation[hints.Scalar]):
+    def _detect_if_left_event_from_result(self, event: Event, /) -> bool:
+        below_winding = self._below_windings[left_event_to_position(event)]
+        return (below_winding != 0) is not (
+                below_winding + self._to_left_event_winding(event) != 0
+        )
+
+
+def segments_to_valid_polygons(
+        segments: t.Iterable[hints.Segment[hints.Scalar]],
+        fill_rule: FillRule,
+        contour_cls: t.Type[hints.Contour[hints.Scalar]],
+        orienteer: Orienteer[hints.Scalar],
+        polygon_cls: t.Type[hints.Polygon[hints.Scalar]],
+        segments_intersector: SegmentsIntersector[hints.Scalar],
+        /
+) -> t.List[hints.Polygon[hints.Scalar]]:
+    winded_segments = to_winded_segments(
+            [segment for segment in segments if segment.start != segment.end],
+            orienteer, segments_intersector
+    )
+    operation: FillOperation[hints.Scalar]
+    if fill_rule is FillRule.EVEN_ODD:
+        winded_segments = [(start, end, winding)
+                           for start, end, winding in winded_segments
+                           if is_odd(winding)]
+        if not winded_segments:
+            return []
+        operation = EvenOddFill.from_winded_segments(
+                winded_segments, orienteer, segments_intersector
+        )
+    else:
+        assert fill_rule is FillRule.NON_ZERO, fill_rule
+        if not winded_segments:
+            return []
+        operation = NonZeroFill.from_winded_segments(
+                winded_segments, orienteer, segments_intersector
+        )
+    return operation.reduce_events(list(operation), contour_cls, polygon_cls)
+
+
+def to_winded_segments(
+        segments: t.Sequence[hints.Segment[hints.Scalar]],
+        orienteer: Orienteer[hints.Scalar],
+        segments_intersector: SegmentsIntersector[hints.Scalar],
+        /
+) -> t.List[_WindedSegment[hints.Scalar]]:
+    """
+    Splits segments into non-crossing & non-overlapping pieces
+    paired with a signed count of segments directed along them,
+    omitting pieces whose segments cancel each other out.
+    """
+    if not segments:
+        return []
+    pieces = to_unique_non_crossing_or_overlapping_segments(
+            segments, orienteer, segments_intersector
+    )
+    start_pieces_ids: t.Dict[hints.Point[hints.Scalar], t.List[int]] = {}
+    for piece_id, (start, _) in enumerate(pieces):
+        start_pieces_ids.setdefault(start, []).append(piece_id)
+    windings = [0] * len(pieces)
+    for segment in segments:
+        start, end = segment.start, segment.end
+        min_endpoint, max_endpoint, winding = ((start, end, 1)
+                                               if start < end
+                                               else (end, start, -1))
+        piece_start = min_endpoint
+        while piece_start != max_endpoint:
+            piece_id = next(
+                    piece_id
+                    for piece_id in start_pieces_ids[piece_start]
+                    if orienteer(min_endpoint, max_endpoint,
+                                 pieces[piece_id][1])
+                    is Orientation.COLLINEAR
+            )
+            windings[piece_id] += winding
+            piece_start = pieces[piece_id][1]
+    return [(start, end, winding)
+            for (start, end), winding in zip(pieces, windings)
+            if winding != 0]
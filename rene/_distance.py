@@ -0,0 +1,382 @@
+import typing as t
+
+from rene import (Location,
+                  Orientation,
+                  hints)
+from rene._context import Context
+from rene._hints import (Orienteer,
+                         SegmentsIntersector)
+from rene._utils import (cross_multiply,
+                         dot_multiply,
+                         square,
+                         to_sorted_pair)
+
+_Bounds = t.Tuple[hints.Scalar, hints.Scalar, hints.Scalar, hints.Scalar]
+_DistanceSegment = t.Tuple[hints.Point[hints.Scalar],
+                           hints.Point[hints.Scalar]]
+_Geometry = t.Union[
+    hints.Contour[hints.Scalar], hints.Multipolygon[hints.Scalar],
+    hints.Multisegment[hints.Scalar], hints.Point[hints.Scalar],
+    hints.Polygon[hints.Scalar], hints.Segment[hints.Scalar]
+]
+
+
+def are_within_squared_distance(first: _Geometry[hints.Scalar],
+                                second: _Geometry[hints.Scalar],
+                                squared_threshold: hints.Scalar,
+                                context: Context[hints.Scalar],
+                                /) -> bool:
+    first_segments = to_distance_segments(first, context)
+    second_segments = to_distance_segments(second, context)
+    return (to_bounds_squared_distance(to_segments_bounds(first_segments),
+                                       to_segments_bounds(second_segments))
+            <= squared_threshold
+            and (find_covered_probe(first, second, context) is not None
+                 or are_segments_within_squared_distance(
+                         first_segments, second_segments, squared_threshold,
+                         context.orient
+                 )))
+
+
+def to_nearest_points(
+        first: _Geometry[hints.Scalar],
+        second: _Geometry[hints.Scalar],
+        context: Context[hints.Scalar],
+        /
+) -> t.Tuple[hints.Point[hints.Scalar], hints.Point[hints.Scalar]]:
+    covered_probe = find_covered_probe(first, second, context)
+    if covered_probe is not None:
+        return covered_probe, covered_probe
+    first_segments = to_distance_segments(first, context)
+    second_segments = to_distance_segments(second, context)
+    first_id, second_id, _ = to_closest_segments_ids(
+            first_segments, second_segments, context.orient
+    )
+    first_start, first_end = first_segments[first_id]
+    second_start, second_end = second_segments[second_id]
+    return to_segments_pair_nearest_points(
+            first_start, first_end, second_start, second_end, context.orient,
+            context.point_cls, context.intersect_segments
+    )
+
+
+def to_squared_distance(first: _Geometry[hints.Scalar],
+                        second: _Geometry[hints.Scalar],
+                        context: Context[hints.Scalar],
+                        /) -> hints.Scalar:
+    covered_probe = find_covered_probe(first, second, context)
+    if covered_probe is not None:
+        # zero of the coordinates' type
+        return covered_probe.x - covered_probe.x
+    _, _, result = to_closest_segments_ids(
+            to_distance_segments(first, context),
+            to_distance_segments(second, context), context.orient
+    )
+    return result
+
+
+def are_segments_within_squared_distance(
+        first: t.Sequence[_DistanceSegment[hints.Scalar]],
+        second: t.Sequence[_DistanceSegment[hints.Scalar]],
+        squared_threshold: hints.Scalar,
+        orienteer: Orienteer[hints.Scalar],
+        /
+) -> bool:
+    second_bounds = [to_segment_bounds(start, end) for start, end in second]
+    for first_start, first_end in first:
+        first_bounds = to_segment_bounds(first_start, first_end)
+        for (second_start, second_end), second_segment_bounds in zip(
+                second, second_bounds
+        ):
+            if (to_bounds_squared_distance(first_bounds,
+                                           second_segment_bounds)
+                    <= squared_threshold
+                    and to_segments_pair_squared_distance(
+                            first_start, first_end, second_start, second_end,
+                            orienteer
+                    ) <= squared_threshold):
+                return True
+    return False
+
+
+def covers_distance_probe(geometry: _Geometry[hints.Scalar],
+                          point: hints.Point[hints.Scalar],
+                          context: Context[hints.Scalar],
+                          /) -> bool:
+    """
+    Checks if the point lies in the interior or on the boundary
+    of the shaped geometry,
+    linear geometries cover nothing since segments of other geometries
+    can reach them only by intersecting.
+    """
+    return (isinstance(geometry, (context.multipolygon_cls,
+                                  context.polygon_cls))
+            and geometry.locate(point) is not Location.EXTERIOR)
+
+
+def do_segments_cross(first_start: hints.Point[hints.Scalar],
+                      first_end: hints.Point[hints.Scalar],
+                      second_start: hints.Point[hints.Scalar],
+                      second_end: hints.Point[hints.Scalar],
+                      orienteer: Orienteer[hints.Scalar],
+                      /) -> bool:
+    first_start_orientation = orienteer(second_start, second_end,
+                                        first_start)
+    first_end_orientation = orienteer(second_start, second_end, first_end)
+    second_start_orientation = orienteer(first_start, first_end,
+                                         second_start)
+    second_end_orientation = orienteer(first_start, first_end, second_end)
+    return (first_start_orientation is not Orientation.COLLINEAR
+            and first_end_orientation is not Orientation.COLLINEAR
+            and first_start_orientation is not first_end_orientation
+            and second_start_orientation is not Orientation.COLLINEAR
+            and second_end_orientation is not Orientation.COLLINEAR
+            and second_start_orientation is not second_end_orientation)
+
+
+def find_covered_probe(
+        first: _Geometry[hints.Scalar],
+        second: _Geometry[hints.Scalar],
+        context: Context[hints.Scalar],
+        /
+) -> t.Optional[hints.Point[hints.Scalar]]:
+    """
+    Searches for a point of some connected component of one geometry
+    covered by the other one, components which are not covered that way
+    either lie outside or cross the boundary of the shaped geometry.
+    """
+    for probe in to_distance_probes(second, context):
+        if covers_distance_probe(first, probe, context):
+            return probe
+    for probe in to_distance_probes(first, context):
+        if covers_distance_probe(second, probe, context):
+            return probe
+    return None
+
+
+def to_bounds_squared_distance(first: _Bounds[hints.Scalar],
+                               second: _Bounds[hints.Scalar],
+                               /) -> hints.Scalar:
+    first_min_x, first_max_x, first_min_y, first_max_y = first
+    second_min_x, second_max_x, second_min_y, second_max_y = second
+    return (square(to_intervals_gap(first_min_x, first_max_x, second_min_x,
+                                    second_max_x))
+            + square(to_intervals_gap(first_min_y, first_max_y,
+                                      second_min_y, second_max_y)))
+
+
+def to_closest_segments_ids(
+        first: t.Sequence[_DistanceSegment[hints.Scalar]],
+        second: t.Sequence[_DistanceSegment[hints.Scalar]],
+        orienteer: Orienteer[hints.Scalar],
+        /
+) -> t.Tuple[int, int, hints.Scalar]:
+    assert len(first) > 0 and len(second) > 0
+    bounds = ([to_segment_bounds(start, end) for start, end in first],
+              [to_segment_bounds(start, end) for start, end in second])
+    events = sorted(
+            [(0, first_id) for first_id in range(len(first))]
+            + [(1, second_id) for second_id in range(len(second))],
+            key=lambda event: bounds[event[0]][event[1]][0]
+    )
+    # sweeping by minimal abscissas, so each pair of segments is checked
+    # when the one which starts rightmost is reached,
+    # segments ending too far to the left of the sweep line are dropped
+    # since they cannot get closer to the following ones
+    active_ids: t.Tuple[t.List[int], t.List[int]] = ([], [])
+    result: t.Optional[t.Tuple[int, int, hints.Scalar]] = None
+    for side, segment_id in events:
+        other_side = 1 - side
+        segment_bounds = bounds[side][segment_id]
+        if result is not None:
+            min_x, min_squared_distance = segment_bounds[0], result[2]
+            active_ids[other_side][:] = [
+                other_id
+                for other_id in active_ids[other_side]
+                if (bounds[other_side][other_id][1] >= min_x
+                    or square(min_x - bounds[other_side][other_id][1])
+                    < min_squared_distance)
+            ]
+        for other_id in active_ids[other_side]:
+            if (result is not None
+                    and to_bounds_squared_distance(
+                            segment_bounds, bounds[other_side][other_id]
+                    ) >= result[2]):
+                continue
+            first_id, second_id = ((segment_id, other_id)
+                                   if side == 0
+                                   else (other_id, segment_id))
+            first_start, first_end = first[first_id]
+            second_start, second_end = second[second_id]
+            squared_distance = to_segments_pair_squared_distance(
+                    first_start, first_end, second_start, second_end,
+                    orienteer
+            )
+            if not squared_distance:
+                return first_id, second_id, squared_distance
+            if result is None or squared_distance < result[2]:
+                result = first_id, second_id, squared_distance
+        active_ids[side].append(segment_id)
+    assert result is not None
+    return result
+
+
+def to_distance_probes(
+        geometry: _Geometry[hints.Scalar], context: Context[hints.Scalar], /
+) -> t.List[hints.Point[hints.Scalar]]:
+    """Returns a point per connected component of the geometry."""
+    if isinstance(geometry, context.point_cls):
+        return [geometry]
+    elif isinstance(geometry, context.segment_cls):
+        return [geometry.start]
+    elif isinstance(geometry, context.contour_cls):
+        return [geometry.vertices[0]]
+    elif isinstance(geometry, context.multisegment_cls):
+        return [segment.start for segment in geometry.segments]
+    elif isinstance(geometry, context.polygon_cls):
+        return [geometry.border.vertices[0]]
+    elif isinstance(geometry, context.multipolygon_cls):
+        return [polygon.border.vertices[0] for polygon in geometry.polygons]
+    else:
+        raise TypeError('Expected non-empty geometry, '
+                        f'but got {type(geometry)!r}.')
+
+
+def to_distance_segments(
+        geometry: _Geometry[hints.Scalar], context: Context[hints.Scalar], /
+) -> t.List[_DistanceSegment[hints.Scalar]]:
+    """Returns segments of the geometry, points become degenerate ones."""
+    if isinstance(geometry, context.point_cls):
+        return [(geometry, geometry)]
+    elif isinstance(geometry, context.segment_cls):
+        return [(geometry.start, geometry.end)]
+    elif isinstance(geometry, (context.contour_cls,
+                               context.multisegment_cls)):
+        return [(segment.start, segment.end) for segment in geometry.segments]
+    elif isinstance(geometry, context.polygon_cls):
+        return [(segment.start, segment.end)
+                for contour in [geometry.border, *geometry.holes]
+                for segment in contour.segments]
+    elif isinstance(geometry, context.multipolygon_cls):
+        return [(segment.start, segment.end)
+                for polygon in geometry.polygons
+                for contour in [polygon.border, *polygon.holes]
+                for segment in contour.segments]
+    else:
+        raise TypeError('Expected non-empty geometry, '
+                        f'but got {type(geometry)!r}.')
+
+
+def to_intervals_gap(first_min: hints.Scalar,
+                     first_max: hints.Scalar,
+                     second_min: hints.Scalar,
+                     second_max: hints.Scalar,
+                     /) -> hints.Scalar:
+    return (second_min - first_max
+            if first_max < second_min
+            else (first_min - second_max
+                  if second_max < first_min
+                  else first_min - first_min))
+
+
+def to_point_segment_nearest_point(
+        point: hints.Point[hints.Scalar],
+        start: hints.Point[hints.Scalar],
+        end: hints.Point[hints.Scalar],
+        point_cls: t.Type[hints.Point[hints.Scalar]],
+        /
+) -> hints.Point[hints.Scalar]:
+    projection = dot_multiply(start, end, start, point)
+    if projection <= 0:
+        return start
+    squared_length = dot_multiply(start, end, start, end)
+    if projection >= squared_length:
+        return end
+    scale = projection / squared_length
+    return point_cls(start.x + (end.x - start.x) * scale,
+                     start.y + (end.y - start.y) * scale)
+
+
+def to_point_segment_squared_distance(point: hints.Point[hints.Scalar],
+                                      start: hints.Point[hints.Scalar],
+                                      end: hints.Point[hints.Scalar],
+                                      /) -> hints.Scalar:
+    projection = dot_multiply(start, end, start, point)
+    if projection <= 0:
+        return dot_multiply(start, point, start, point)
+    squared_length = dot_multiply(start, end, start, end)
+    if projection >= squared_length:
+        return dot_multiply(end, point, end, point)
+    return (square(cross_multiply(start, end, start, point))
+            / squared_length)
+
+
+def to_segment_bounds(start: hints.Point[hints.Scalar],
+                      end: hints.Point[hints.Scalar],
+                      /) -> _Bounds[hints.Scalar]:
+    min_x, max_x = to_sorted_pair(start.x, end.x)
+    min_y, max_y = to_sorted_pair(start.y, end.y)
+    return min_x, max_x, min_y, max_y
+
+
+def to_segments_bounds(
+        segments: t.Sequence[_DistanceSegment[hints.Scalar]], /
+) -> _Bounds[hints.Scalar]:
+    segments_bounds = [to_segment_bounds(start, end)
+                       for start, end in segments]
+    return (min(min_x for min_x, _, _, _ in segments_bounds),
+            max(max_x for _, max_x, _, _ in segments_bounds),
+            min(min_y for _, _, min_y, _ in segments_bounds),
+            max(max_y for _, _, _, max_y in segments_bounds))
+
+
+def to_segments_pair_nearest_points(
+        first_start: hints.Point[hints.Scalar],
+        first_end: hints.Point[hints.Scalar],
+        second_start: hints.Point[hints.Scalar],
+        second_end: hints.Point[hints.Scalar],
+        orienteer: Orienteer[hints.Scalar],
+        point_cls: t.Type[hints.Point[hints.Scalar]],
+        segments_intersector: SegmentsIntersector[hints.Scalar],
+        /
+) -> t.Tuple[hints.Point[hints.Scalar], hints.Point[hints.Scalar]]:
+    if do_segments_cross(first_start, first_end, second_start, second_end,
+                         orienteer):
+        cross_point = segments_intersector(first_start, first_end,
+                                           second_start, second_end)
+        return cross_point, cross_point
+    point, start, end, is_point_from_second = min(
+            [(first_start, second_start, second_end, False),
+             (first_end, second_start, second_end, False),
+             (second_start, first_start, first_end, True),
+             (second_end, first_start, first_end, True)],
+            key=lambda candidate: to_point_segment_squared_distance(
+                    *candidate[:3]
+            )
+    )
+    nearest_point = to_point_segment_nearest_point(point, start, end,
+                                                   point_cls)
+    return ((nearest_point, point)
+            if is_point_from_second
+            else (point, nearest_point))
+
+
+def to_segments_pair_squared_distance(first_start: hints.Point[hints.Scalar],
+                                      first_end: hints.Point[hints.Scalar],
+                                      second_start: hints.Point[hints.Scalar],
+                                      second_end: hints.Point[hints.Scalar],
+                                      orienteer: Orienteer[hints.Scalar],
+                                      /) -> hints.Scalar:
+    if do_segments_cross(first_start, first_end, second_start, second_end,
+                         orienteer):
+        return first_start.x - first_start.x
+    return min(
+            to_point_segment_squared_distance(first_start, second_start,
+                                              second_end),
+            to_point_segment_squared_distance(first_end, second_start,
+                                              second_end),
+            to_point_segment_squared_distance(second_start, first_start,
+                                              first_end),
+            to_point_segment_squared_distance(second_end, first_start,
+                                              first_end)
+    )
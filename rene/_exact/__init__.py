@@ -1,11 +1,16 @@
 from __future__ import annotations
 
+import typing as t
+
 from rithm.fraction import Fraction
 
 from rene import (Orientation,
+                  Relation,
                   hints)
+from rene._bentley_ottmann.base import to_segments_intersections
 from rene._context import Context
 from rene._utils import (cross_multiply,
+                         points_to_convex_hull,
                          to_sign)
 from .box import Box
 from .contour import Contour
@@ -17,7 +22,15 @@ from .polygon import Polygon
 from .segment import Segment
 from .trapezoidation import Trapezoidation
 from .triangulation import (ConstrainedDelaunayTriangulation,
-                            DelaunayTriangulation)
+                            DelaunayTriangulation,
+                            VoronoiDiagram)
+
+
+def convex_hull(points: t.Sequence[hints.Point[Fraction]], /) -> t.Union[
+    hints.Contour[Fraction], hints.Empty[Fraction], hints.Point[Fraction],
+    hints.Segment[Fraction]
+]:
+    return points_to_convex_hull(points, orient, Contour, Empty, Segment)
 
 
 def orient(vertex: hints.Point[Fraction],
@@ -33,6 +46,40 @@ def orient(vertex: hints.Point[Fraction],
                   else Orientation.CLOCKWISE))
 
 
+def segments_intersections(
+        segments: t.Sequence[hints.Segment[Fraction]], /
+) -> t.List[
+    t.Tuple[
+        int, int, Relation,
+        t.Union[hints.Point[Fraction], hints.Segment[Fraction]]
+    ]
+]:
+    segments_ids = [segment_id
+                    for segment_id, segment in enumerate(segments)
+                    if segment.start != segment.end]
+    result: t.List[
+        t.Tuple[
+            int, int, Relation,
+            t.Union[hints.Point[Fraction], hints.Segment[Fraction]]
+        ]
+    ] = []
+    for intersection in to_segments_intersections(
+            [segments[segment_id] for segment_id in segments_ids],
+            orient, to_segments_intersection
+    ):
+        first_segment_id = segments_ids[intersection.first_segment_id]
+        second_segment_id = segments_ids[intersection.second_segment_id]
+        if (intersection.relation is Relation.CROSS
+                or intersection.relation is Relation.TOUCH):
+            result.append((first_segment_id, second_segment_id,
+                           intersection.relation, intersection.start))
+        else:
+            result.append((first_segment_id, second_segment_id,
+                           Relation.OVERLAP,
+                           Segment(intersection.start, intersection.end)))
+    return result
+
+
 def to_segments_intersection(
         first_start: hints.Point[Fraction],
         first_end: hints.Point[Fraction],
@@ -66,13 +113,16 @@ _context = Context(box_cls=Box,
                    segment_cls=Segment,
                    segments_intersection_scale=to_segments_intersection_scale,
                    segments_intersector=to_segments_intersection)
+Box._context = _context
 ConstrainedDelaunayTriangulation._context = _context
 Contour._context = _context
 DelaunayTriangulation._context = _context
 Empty._context = _context
 Multipolygon._context = _context
 Multisegment._context = _context
+Point._context = _context
 Polygon._context = _context
 Segment._context = _context
 Trapezoidation._context = _context
+VoronoiDiagram._context = _context
 del _context
@@ -14,6 +14,10 @@ _Coordinate = t.Union[Fraction, Int, Rational, float, int]
 
 @te.final
 class Box(BaseBox[Fraction]):
+    @property
+    def area(self) -> Fraction:
+        return (self._max_x - self._min_x) * (self._max_y - self._min_y)
+
     @property
     def max_x(self) -> Fraction:
         return self._max_x
@@ -9,11 +9,21 @@ from rithm.fraction import Fraction
 from rene import (MIN_CONTOUR_VERTICES_COUNT,
                   hints)
 from rene._geometries.base_contour import BaseContour
-from rene._utils import to_contour_segments
+from rene._utils import (to_contour_segments,
+                         to_doubled_signed_area,
+                         vertices_to_centroid)
 
 
 @te.final
 class Contour(BaseContour[Fraction]):
+    @property
+    def area(self) -> Fraction:
+        return to_doubled_signed_area(self._vertices) / 2
+
+    @property
+    def centroid(self) -> hints.Point[Fraction]:
+        return vertices_to_centroid(self._vertices, self._context.point_cls)
+
     @property
     def segments(self) -> t.Sequence[hints.Segment[Fraction]]:
         return _ContourSegments(self._segments, _TOKEN)
@@ -13,6 +13,10 @@ _CompoundT = t.TypeVar('_CompoundT',
 
 @te.final
 class Empty(BaseEmpty[Fraction]):
+    @property
+    def area(self) -> Fraction:
+        return Fraction(0)
+
     _context: t.ClassVar[Context[Fraction]]
 
     __module__ = 'rene.exact'
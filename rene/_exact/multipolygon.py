@@ -10,10 +10,45 @@ from rene import (MIN_MULTIPOLYGON_POLYGONS_COUNT,
                   hints)
 from rene._context import Context
 from rene._geometries.base_multipolygon import BaseMultipolygon
+from rene._utils import (region_moments_to_centroid,
+                         to_polygon_doubled_area,
+                         to_polygon_region_moments,
+                         vertices_to_centroid)
 
 
 @te.final
 class Multipolygon(BaseMultipolygon[Fraction]):
+    @property
+    def area(self) -> Fraction:
+        return sum([to_polygon_doubled_area(polygon)
+                    for polygon in self._polygons],
+                   Fraction(0)) / 2
+
+    @property
+    def centroid(self) -> hints.Point[Fraction]:
+        polygons, point_cls = self._polygons, self._context.point_cls
+        doubled_area, x_moment, y_moment = to_polygon_region_moments(
+                polygons[0]
+        )
+        for polygon in polygons[1:]:
+            (
+                polygon_doubled_area, polygon_x_moment, polygon_y_moment
+            ) = to_polygon_region_moments(polygon)
+            doubled_area += polygon_doubled_area
+            x_moment += polygon_x_moment
+            y_moment += polygon_y_moment
+        result = region_moments_to_centroid(
+                (doubled_area, x_moment, y_moment), point_cls
+        )
+        return (vertices_to_centroid(
+                        [vertex
+                         for polygon in polygons
+                         for vertex in polygon.border.vertices],
+                        point_cls
+                )
+                if result is None
+                else result)
+
     @property
     def polygons(self) -> t.Sequence[hints.Polygon[Fraction]]:
         return _MultipolygonPolygons(self._polygons, _TOKEN)
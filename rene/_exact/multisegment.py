@@ -14,6 +14,17 @@ from rene._geometries.base_multisegment import BaseMultisegment
 
 @te.final
 class Multisegment(BaseMultisegment[Fraction]):
+    @property
+    def centroid(self) -> hints.Point[Fraction]:
+        segments = self._segments
+        doubled_segments_count = 2 * len(segments)
+        return self._context.point_cls(
+                sum([segment.start.x + segment.end.x for segment in segments],
+                    Fraction(0)) / doubled_segments_count,
+                sum([segment.start.y + segment.end.y for segment in segments],
+                    Fraction(0)) / doubled_segments_count
+        )
+
     @property
     def segments(self) -> t.Sequence[hints.Segment[Fraction]]:
         return _MultisegmentSegments(self._segments, _TOKEN)
@@ -9,14 +9,31 @@ from rithm.fraction import Fraction
 from rene import hints
 from rene._context import Context
 from rene._geometries.base_polygon import BasePolygon
+from rene._utils import (region_moments_to_centroid,
+                         to_polygon_doubled_area,
+                         to_polygon_region_moments,
+                         vertices_to_centroid)
 
 
 @te.final
 class Polygon(BasePolygon[Fraction]):
+    @property
+    def area(self) -> Fraction:
+        return to_polygon_doubled_area(self) / 2
+
     @property
     def border(self) -> hints.Contour[Fraction]:
         return self._border
 
+    @property
+    def centroid(self) -> hints.Point[Fraction]:
+        point_cls = self._context.point_cls
+        result = region_moments_to_centroid(to_polygon_region_moments(self),
+                                            point_cls)
+        return (vertices_to_centroid(self._border.vertices, point_cls)
+                if result is None
+                else result)
+
     @property
     def holes(self) -> t.Sequence[hints.Contour[Fraction]]:
         return _PolygonHoles(self._holes, _TOKEN)
@@ -12,6 +12,11 @@ from rene._geometries.base_segment import BaseSegment
 
 @te.final
 class Segment(BaseSegment[Fraction]):
+    @property
+    def centroid(self) -> hints.Point[Fraction]:
+        return self._context.point_cls((self._start.x + self._end.x) / 2,
+                                       (self._start.y + self._end.y) / 2)
+
     @property
     def end(self) -> hints.Point[Fraction]:
         return self._end
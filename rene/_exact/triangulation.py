@@ -1,6 +1,7 @@
 from __future__ import annotations
 
 import typing as t
+from numbers import Rational
 
 import typing_extensions as te
 from rithm.fraction import Fraction
@@ -14,11 +15,24 @@ from rene._triangulation.constrained_delaunay import (
 from rene._triangulation.delaunay import (
     DelaunayTriangulation as _RawDelaunayTriangulation
 )
+from rene._triangulation.voronoi import (
+    VoronoiDiagram as _RawVoronoiDiagram
+)
 from rene._utils import shrink_collinear_vertices
 
 
 @te.final
 class ConstrainedDelaunayTriangulation:
+    @classmethod
+    def from_multipolygon(
+            cls, multipolygon: hints.Multipolygon[Fraction], /
+    ) -> te.Self:
+        return cls(
+                _RawConstrainedDelaunayTriangulation.from_multipolygon(
+                        multipolygon, cls._context.orient
+                )
+        )
+
     @classmethod
     def from_polygon(cls, polygon: hints.Polygon[Fraction], /) -> te.Self:
         return cls(
@@ -72,6 +86,25 @@ class DelaunayTriangulation:
         return cls(_RawDelaunayTriangulation.from_points(points,
                                                          cls._context.orient))
 
+    def alpha_shape(
+            self, squared_alpha: t.Union[Fraction, Rational, float, int], /
+    ) -> t.Union[
+        hints.Empty[Fraction], hints.Multipolygon[Fraction],
+        hints.Polygon[Fraction]
+    ]:
+        squared_alpha = Fraction(squared_alpha)
+        contour_cls, polygon_cls = (self._context.contour_cls,
+                                    self._context.polygon_cls)
+        polygons = [
+            polygon_cls(contour_cls(vertices), [])
+            for vertices in self._raw.triangles_vertices()
+            if _is_triangle_within_squared_circumradius(*vertices,
+                                                        squared_alpha)
+        ]
+        return (_unite_polygons(polygons)
+                if polygons
+                else self._context.empty_cls())
+
     @property
     def border(self) -> hints.Contour[Fraction]:
         boundary_points = self._raw.to_boundary_points()
@@ -105,3 +138,77 @@ class DelaunayTriangulation:
 
     def __bool__(self) -> bool:
         return bool(self._raw)
+
+
+@te.final
+class VoronoiDiagram:
+    @classmethod
+    def from_points(cls,
+                    points: t.Sequence[hints.Point[Fraction]],
+                    box: hints.Box[Fraction],
+                    /) -> te.Self:
+        context = cls._context
+        return cls(_RawVoronoiDiagram.from_delaunay_triangulation(
+                _RawDelaunayTriangulation.from_points(points, context.orient),
+                box, context.orient, context.point_cls
+        ))
+
+    @property
+    def cells(self) -> t.Sequence[t.Optional[hints.Contour[Fraction]]]:
+        contour_cls = self._context.contour_cls
+        return [None if vertices is None else contour_cls(vertices)
+                for vertices in self._raw.cells_vertices]
+
+    @property
+    def sites(self) -> t.Sequence[hints.Point[Fraction]]:
+        return list(self._raw.sites)
+
+    @property
+    def vertices(self) -> t.Sequence[hints.Point[Fraction]]:
+        return list(self._raw.vertices)
+
+    _context: Context[Fraction]
+    _raw: _RawVoronoiDiagram[Fraction]
+
+    __module__ = 'rene.exact'
+    __slots__ = '_raw',
+
+    def __init_subclass__(cls, /, **_kwargs: t.Any) -> t.NoReturn:
+        raise TypeError(f'type {cls.__qualname__!r} '
+                        'is not an acceptable base type')
+
+    def __new__(cls, raw: _RawVoronoiDiagram[Fraction], /) -> te.Self:
+        self = super().__new__(cls)
+        self._raw = raw
+        return self
+
+
+def _is_triangle_within_squared_circumradius(
+        first: hints.Point[Fraction],
+        second: hints.Point[Fraction],
+        third: hints.Point[Fraction],
+        squared_alpha: Fraction,
+        /
+) -> bool:
+    doubled_area = ((second.x - first.x) * (third.y - first.y)
+                    - (second.y - first.y) * (third.x - first.x))
+    return (_to_squared_distance(first, second)
+            * _to_squared_distance(second, third)
+            * _to_squared_distance(third, first)
+            <= squared_alpha * (2 * doubled_area) ** 2)
+
+
+def _to_squared_distance(first: hints.Point[Fraction],
+                         second: hints.Point[Fraction],
+                         /) -> Fraction:
+    return (first.x - second.x) ** 2 + (first.y - second.y) ** 2
+
+
+def _unite_polygons(
+        polygons: t.Sequence[hints.Polygon[Fraction]], /
+) -> t.Union[hints.Multipolygon[Fraction], hints.Polygon[Fraction]]:
+    if len(polygons) == 1:
+        return polygons[0]
+    middle = len(polygons) // 2
+    return (_unite_polygons(polygons[:middle])
+            | _unite_polygons(polygons[middle:]))
@@ -8,6 +8,8 @@ import typing_extensions as te
 
 from rene import (Relation,
                   hints)
+from rene._context import Context
+from rene._utils import points_to_convex_hull
 
 
 class BaseBox(ABC, t.Generic[hints.Scalar]):
@@ -31,6 +33,21 @@ class BaseBox(ABC, t.Generic[hints.Scalar]):
     def min_y(self) -> hints.Scalar:
         ...
 
+    def convex_hull(self) -> t.Union[
+            hints.Contour[hints.Scalar], hints.Empty[hints.Scalar],
+            hints.Point[hints.Scalar], hints.Segment[hints.Scalar]
+    ]:
+        context = self._context
+        point_cls = context.point_cls
+        return points_to_convex_hull(
+                [point_cls(self.min_x, self.min_y),
+                 point_cls(self.max_x, self.min_y),
+                 point_cls(self.max_x, self.max_y),
+                 point_cls(self.min_x, self.max_y)],
+                context.orient, context.contour_cls, context.empty_cls,
+                context.segment_cls
+        )
+
     def covers(self, other: te.Self, /) -> bool:
         return (other.max_x < self.max_x and other.max_y < self.max_y
                 and self.min_x < other.min_x and self.min_y < other.min_y)
@@ -369,6 +386,8 @@ class BaseBox(ABC, t.Generic[hints.Scalar]):
                 and other.min_x < self.min_x
                 and other.min_y < self.min_y)
 
+    _context: t.ClassVar[Context[t.Any]]
+
     @t.overload
     def __eq__(self, other: te.Self, /) -> bool:
         ...
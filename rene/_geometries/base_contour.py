@@ -6,13 +6,12 @@ from abc import (ABC,
 
 import typing_extensions as te
 
-from rene import (MIN_CONTOUR_VERTICES_COUNT,
+from rene import (FillRule,
                   Location,
                   Orientation,
                   Relation,
                   hints)
-from rene._bentley_ottmann.base import (Intersection,
-                                        sweep)
+from rene._bentley_ottmann.validation import validate_contour
 from rene._clipping import (
     intersect_multisegmental_with_multipolygon,
     intersect_multisegmental_with_multisegmental,
@@ -27,11 +26,17 @@ from rene._clipping import (
     unite_multisegmental_with_multisegmental,
     unite_multisegmental_with_segment
 )
+from rene._clipping.fill import segments_to_valid_polygons
 from .base_compound import BaseCompound
+from rene._distance import (are_within_squared_distance,
+                            to_nearest_points,
+                            to_squared_distance)
 from rene._relating import contour
-from rene._utils import (are_contour_vertices_non_degenerate,
+from rene._utils import (collect_maybe_empty_polygons,
+                         points_to_convex_hull,
                          to_arg_min,
                          to_contour_orientation)
+from rene._validity import ValidityError
 
 
 class BaseContour(ABC, BaseCompound[hints.Scalar]):
@@ -69,20 +74,31 @@ class BaseContour(ABC, BaseCompound[hints.Scalar]):
     def vertices(self) -> t.Sequence[hints.Point[hints.Scalar]]:
         ...
 
+    def convex_hull(self) -> t.Union[
+            hints.Contour[hints.Scalar], hints.Empty[hints.Scalar],
+            hints.Point[hints.Scalar], hints.Segment[hints.Scalar]
+    ]:
+        context = self._context
+        return points_to_convex_hull(self.vertices, context.orient,
+                                     context.contour_cls, context.empty_cls,
+                                     context.segment_cls)
+
+    def explain_validity(self) -> t.List[ValidityError]:
+        context = self._context
+        return validate_contour(self, context.orient,
+                                context.intersect_segments, True)
+
     def is_valid(self) -> bool:
-        if not are_contour_vertices_non_degenerate(self.vertices,
-                                                   self._context.orient):
-            return False
-        segments = self.segments
-        if len(segments) < MIN_CONTOUR_VERTICES_COUNT:
-            return False
-        neighbour_segments_touches_count = 0
-        for intersection in sweep(segments, self._context.orient,
-                                  self._context.intersect_segments):
-            if not _neighbour_segments_vertices_touch(intersection, segments):
-                return False
-            neighbour_segments_touches_count += 1
-        return neighbour_segments_touches_count == len(segments)
+        context = self._context
+        return not validate_contour(self, context.orient,
+                                    context.intersect_segments, False)
+
+    def is_within_squared_distance(self,
+                                   other: hints.Geometry[hints.Scalar],
+                                   squared_threshold: hints.Scalar,
+                                   /) -> bool:
+        return are_within_squared_distance(self, other, squared_threshold,
+                                           self._context)
 
     def locate(self, point: hints.Point[hints.Scalar], /) -> Location:
         return (Location.EXTERIOR
@@ -90,6 +106,25 @@ class BaseContour(ABC, BaseCompound[hints.Scalar]):
                        for segment in self.segments)
                 else Location.BOUNDARY)
 
+    def make_valid(self, fill_rule: FillRule, /) -> t.Union[
+            hints.Empty[hints.Scalar], hints.Multipolygon[hints.Scalar],
+            hints.Polygon[hints.Scalar]
+    ]:
+        context = self._context
+        return collect_maybe_empty_polygons(
+                segments_to_valid_polygons(
+                        self.segments, fill_rule, context.contour_cls,
+                        context.orient, context.polygon_cls,
+                        context.intersect_segments
+                ),
+                context.empty_cls, context.multipolygon_cls
+        )
+
+    def nearest_points(
+            self, other: hints.Geometry[hints.Scalar], /
+    ) -> t.Tuple[hints.Point[hints.Scalar], hints.Point[hints.Scalar]]:
+        return to_nearest_points(self, other, self._context)
+
     def relate_to(self, other: hints.Compound[hints.Scalar], /) -> Relation:
         context = self._context
         if isinstance(other, context.contour_cls):
@@ -114,6 +149,16 @@ class BaseContour(ABC, BaseCompound[hints.Scalar]):
         else:
             raise TypeError(f'Unsupported type: {type(other)!r}.')
 
+    def squared_distance_to(self,
+                            other: hints.Geometry[hints.Scalar],
+                            /) -> hints.Scalar:
+        return to_squared_distance(self, other, self._context)
+
+    def validate(self) -> None:
+        errors = self.explain_validity()
+        if errors:
+            raise errors[0]
+
     @t.overload
     def __and__(
             self, other: hints.Empty[hints.Scalar], /
@@ -386,27 +431,3 @@ def _are_non_empty_unique_sequences_rotationally_equivalent(
                 or (left[1:index + 1] == right[:index][::-1]
                     and left[index + 1:] == right[len(right) - 1:index:-1]))
 
-
-def _neighbour_segments_vertices_touch(
-        intersection: Intersection[hints.Scalar],
-        segments: t.Sequence[hints.Segment[hints.Scalar]],
-        /
-) -> bool:
-    first_segment = segments[intersection.first_segment_id]
-    second_segment = segments[intersection.second_segment_id]
-    touches_at_vertices = (
-            intersection.relation is Relation.TOUCH
-            and (intersection.start == first_segment.start
-                 or intersection.start == first_segment.end)
-            and (intersection.start == second_segment.start
-                 or intersection.start == second_segment.end)
-    )
-    neighbour_segments_intersection = (
-            abs(intersection.first_segment_id
-                - intersection.second_segment_id) == 1
-            or (intersection.first_segment_id == len(segments) - 1
-                and intersection.second_segment_id == 0)
-            or (intersection.second_segment_id == len(segments) - 1
-                and intersection.first_segment_id == 0)
-    )
-    return touches_at_vertices and neighbour_segments_intersection
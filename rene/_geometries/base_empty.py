@@ -12,6 +12,9 @@ _CompoundT = t.TypeVar('_CompoundT',
 
 
 class BaseEmpty(BaseCompound[hints.Scalar]):
+    def convex_hull(self) -> te.Self:
+        return self
+
     def locate(self, _point: hints.Point[hints.Scalar], /) -> Location:
         return Location.EXTERIOR
 
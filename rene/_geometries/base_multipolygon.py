@@ -3,12 +3,15 @@ from __future__ import annotations
 import typing as t
 from abc import (ABC,
                  abstractmethod)
+from itertools import chain
 
 import typing_extensions as te
 
-from rene import (Location,
+from rene import (FillRule,
+                  Location,
                   Relation,
                   hints)
+from rene._bentley_ottmann.validation import validate_multipolygon
 from rene._clipping import (intersect_multipolygon_with_multipolygon,
                             intersect_multipolygon_with_multisegmental,
                             intersect_multipolygon_with_polygon,
@@ -19,8 +22,16 @@ from rene._clipping import (intersect_multipolygon_with_multipolygon,
                             symmetric_subtract_polygon_from_multipolygon,
                             unite_multipolygon_with_multipolygon,
                             unite_multipolygon_with_polygon)
+from rene._clipping.fill import segments_to_valid_polygons
 from rene._context import Context
+from rene._distance import (are_within_squared_distance,
+                            to_nearest_points,
+                            to_squared_distance)
 from rene._relating import multipolygon
+from rene._utils import (collect_maybe_empty_polygons,
+                         points_to_convex_hull,
+                         polygon_to_correctly_oriented_segments)
+from rene._validity import ValidityError
 from .base_compound import BaseCompound
 
 
@@ -50,6 +61,38 @@ class BaseMultipolygon(ABC, BaseCompound[hints.Scalar]):
                 min_y = polygon_bounding_box.min_y
         return self._context.box_cls(min_x, max_x, min_y, max_y)
 
+    def convex_hull(self) -> t.Union[
+            hints.Contour[hints.Scalar], hints.Empty[hints.Scalar],
+            hints.Point[hints.Scalar], hints.Segment[hints.Scalar]
+    ]:
+        context = self._context
+        return points_to_convex_hull(
+                [vertex
+                 for polygon in self.polygons
+                 for vertex in polygon.border.vertices],
+                context.orient, context.contour_cls, context.empty_cls,
+                context.segment_cls
+        )
+
+    def explain_validity(self) -> t.List[ValidityError]:
+        context = self._context
+        return validate_multipolygon(self, context.orient,
+                                     context.intersect_segments,
+                                     context.segment_cls, True)
+
+    def is_valid(self) -> bool:
+        context = self._context
+        return not validate_multipolygon(self, context.orient,
+                                         context.intersect_segments,
+                                         context.segment_cls, False)
+
+    def is_within_squared_distance(self,
+                                   other: hints.Geometry[hints.Scalar],
+                                   squared_threshold: hints.Scalar,
+                                   /) -> bool:
+        return are_within_squared_distance(self, other, squared_threshold,
+                                           self._context)
+
     def locate(self, point: hints.Point[hints.Scalar], /) -> Location:
         for polygon in self.polygons:
             location = polygon.locate(point)
@@ -57,6 +100,31 @@ class BaseMultipolygon(ABC, BaseCompound[hints.Scalar]):
                 return location
         return Location.EXTERIOR
 
+    def make_valid(self, fill_rule: FillRule, /) -> t.Union[
+            hints.Empty[hints.Scalar], hints.Multipolygon[hints.Scalar],
+            hints.Polygon[hints.Scalar]
+    ]:
+        context = self._context
+        return collect_maybe_empty_polygons(
+                segments_to_valid_polygons(
+                        chain.from_iterable(
+                                polygon_to_correctly_oriented_segments(
+                                        polygon, context.orient,
+                                        context.segment_cls
+                                )
+                                for polygon in self.polygons
+                        ),
+                        fill_rule, context.contour_cls, context.orient,
+                        context.polygon_cls, context.intersect_segments
+                ),
+                context.empty_cls, context.multipolygon_cls
+        )
+
+    def nearest_points(
+            self, other: hints.Geometry[hints.Scalar], /
+    ) -> t.Tuple[hints.Point[hints.Scalar], hints.Point[hints.Scalar]]:
+        return to_nearest_points(self, other, self._context)
+
     def relate_to(self, other: hints.Compound[hints.Scalar], /) -> Relation:
         context = self._context
         if isinstance(other, context.contour_cls):
@@ -83,6 +151,16 @@ class BaseMultipolygon(ABC, BaseCompound[hints.Scalar]):
 
     _context: t.ClassVar[Context[t.Any]]
 
+    def squared_distance_to(self,
+                            other: hints.Geometry[hints.Scalar],
+                            /) -> hints.Scalar:
+        return to_squared_distance(self, other, self._context)
+
+    def validate(self) -> None:
+        errors = self.explain_validity()
+        if errors:
+            raise errors[0]
+
     @t.overload
     def __and__(
             self, other: hints.Empty[hints.Scalar], /
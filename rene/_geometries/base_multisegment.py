@@ -8,7 +8,7 @@ import typing_extensions as te
 from rene import (Location,
                   Relation,
                   hints)
-from rene._bentley_ottmann.base import sweep
+from rene._bentley_ottmann.validation import validate_multisegment
 from rene._clipping import (
     intersect_multisegmental_with_multipolygon,
     intersect_multisegmental_with_multisegmental,
@@ -23,7 +23,12 @@ from rene._clipping import (
     unite_multisegmental_with_multisegmental,
     unite_multisegmental_with_segment
 )
+from rene._distance import (are_within_squared_distance,
+                            to_nearest_points,
+                            to_squared_distance)
 from rene._relating import multisegment
+from rene._utils import points_to_convex_hull
+from rene._validity import ValidityError
 from .base_compound import BaseCompound
 
 
@@ -56,11 +61,35 @@ class BaseMultisegment(ABC, BaseCompound[hints.Scalar]):
                 min_y = segment_min_y
         return self._context.box_cls(min_x, max_x, min_y, max_y)
 
+    def convex_hull(self) -> t.Union[
+            hints.Contour[hints.Scalar], hints.Empty[hints.Scalar],
+            hints.Point[hints.Scalar], hints.Segment[hints.Scalar]
+    ]:
+        context = self._context
+        return points_to_convex_hull(
+                [endpoint
+                 for segment in self.segments
+                 for endpoint in (segment.start, segment.end)],
+                context.orient, context.contour_cls, context.empty_cls,
+                context.segment_cls
+        )
+
+    def explain_validity(self) -> t.List[ValidityError]:
+        context = self._context
+        return validate_multisegment(self, context.orient,
+                                     context.intersect_segments, True)
+
     def is_valid(self) -> bool:
-        return all(intersection.relation is Relation.TOUCH
-                   for intersection in sweep(self.segments,
-                                             self._context.orient,
-                                             self._context.intersect_segments))
+        context = self._context
+        return not validate_multisegment(self, context.orient,
+                                         context.intersect_segments, False)
+
+    def is_within_squared_distance(self,
+                                   other: hints.Geometry[hints.Scalar],
+                                   squared_threshold: hints.Scalar,
+                                   /) -> bool:
+        return are_within_squared_distance(self, other, squared_threshold,
+                                           self._context)
 
     def locate(self, point: hints.Point[hints.Scalar], /) -> Location:
         for segment in self.segments:
@@ -69,6 +98,11 @@ class BaseMultisegment(ABC, BaseCompound[hints.Scalar]):
                 return location
         return Location.EXTERIOR
 
+    def nearest_points(
+            self, other: hints.Geometry[hints.Scalar], /
+    ) -> t.Tuple[hints.Point[hints.Scalar], hints.Point[hints.Scalar]]:
+        return to_nearest_points(self, other, self._context)
+
     def relate_to(self, other: hints.Compound[hints.Scalar], /) -> Relation:
         context = self._context
         if isinstance(other, context.contour_cls):
@@ -100,6 +134,16 @@ class BaseMultisegment(ABC, BaseCompound[hints.Scalar]):
         else:
             raise TypeError(f'Unsupported type: {type(other)!r}.')
 
+    def squared_distance_to(self,
+                            other: hints.Geometry[hints.Scalar],
+                            /) -> hints.Scalar:
+        return to_squared_distance(self, other, self._context)
+
+    def validate(self) -> None:
+        errors = self.explain_validity()
+        if errors:
+            raise errors[0]
+
     @t.overload
     def __and__(
             self, other: hints.Empty[hints.Scalar], /
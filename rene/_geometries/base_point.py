@@ -5,6 +5,10 @@ from abc import (ABC,
 import typing_extensions as te
 
 from rene import hints
+from rene._context import Context
+from rene._distance import (are_within_squared_distance,
+                            to_nearest_points,
+                            to_squared_distance)
 
 
 class BasePoint(ABC, t.Generic[hints.Scalar]):
@@ -18,6 +22,28 @@ class BasePoint(ABC, t.Generic[hints.Scalar]):
     def y(self) -> hints.Scalar:
         ...
 
+    def convex_hull(self) -> te.Self:
+        return self
+
+    def is_within_squared_distance(self,
+                                   other: hints.Geometry[hints.Scalar],
+                                   squared_threshold: hints.Scalar,
+                                   /) -> bool:
+        return are_within_squared_distance(self, other, squared_threshold,
+                                           self._context)
+
+    def nearest_points(
+            self, other: hints.Geometry[hints.Scalar], /
+    ) -> t.Tuple[hints.Point[hints.Scalar], hints.Point[hints.Scalar]]:
+        return to_nearest_points(self, other, self._context)
+
+    def squared_distance_to(self,
+                            other: hints.Geometry[hints.Scalar],
+                            /) -> hints.Scalar:
+        return to_squared_distance(self, other, self._context)
+
+    _context: t.ClassVar[Context[t.Any]]
+
     @t.overload
     def __eq__(self, other: te.Self, /) -> bool:
         ...
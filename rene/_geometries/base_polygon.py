@@ -6,9 +6,11 @@ from abc import (ABC,
 
 import typing_extensions as te
 
-from rene import (Location,
+from rene import (FillRule,
+                  Location,
                   Relation,
                   hints)
+from rene._bentley_ottmann.validation import validate_polygon
 from rene._clipping import (intersect_polygon_with_multipolygon,
                             intersect_polygon_with_multisegmental,
                             intersect_polygon_with_polygon,
@@ -19,8 +21,16 @@ from rene._clipping import (intersect_polygon_with_multipolygon,
                             symmetric_subtract_polygon_from_polygon,
                             unite_polygon_with_multipolygon,
                             unite_polygon_with_polygon)
+from rene._clipping.fill import segments_to_valid_polygons
+from rene._distance import (are_within_squared_distance,
+                            to_nearest_points,
+                            to_squared_distance)
 from rene._relating import polygon
-from rene._utils import locate_point_in_region
+from rene._utils import (collect_maybe_empty_polygons,
+                         locate_point_in_region,
+                         points_to_convex_hull,
+                         polygon_to_correctly_oriented_segments)
+from rene._validity import ValidityError
 from .base_compound import BaseCompound
 
 
@@ -39,6 +49,34 @@ class BasePolygon(ABC, BaseCompound[hints.Scalar]):
     def bounding_box(self) -> hints.Box[hints.Scalar]:
         return self.border.bounding_box
 
+    def convex_hull(self) -> t.Union[
+            hints.Contour[hints.Scalar], hints.Empty[hints.Scalar],
+            hints.Point[hints.Scalar], hints.Segment[hints.Scalar]
+    ]:
+        context = self._context
+        return points_to_convex_hull(self.border.vertices, context.orient,
+                                     context.contour_cls, context.empty_cls,
+                                     context.segment_cls)
+
+    def explain_validity(self) -> t.List[ValidityError]:
+        context = self._context
+        return validate_polygon(self, context.orient,
+                                context.intersect_segments,
+                                context.segment_cls, True)
+
+    def is_valid(self) -> bool:
+        context = self._context
+        return not validate_polygon(self, context.orient,
+                                    context.intersect_segments,
+                                    context.segment_cls, False)
+
+    def is_within_squared_distance(self,
+                                   other: hints.Geometry[hints.Scalar],
+                                   squared_threshold: hints.Scalar,
+                                   /) -> bool:
+        return are_within_squared_distance(self, other, squared_threshold,
+                                           self._context)
+
     def locate(self, point: hints.Point[hints.Scalar], /) -> Location:
         location_without_holes = locate_point_in_region(self.border, point,
                                                         self._context.orient)
@@ -52,6 +90,27 @@ class BasePolygon(ABC, BaseCompound[hints.Scalar]):
                     return Location.BOUNDARY
         return location_without_holes
 
+    def make_valid(self, fill_rule: FillRule, /) -> t.Union[
+            hints.Empty[hints.Scalar], hints.Multipolygon[hints.Scalar],
+            hints.Polygon[hints.Scalar]
+    ]:
+        context = self._context
+        return collect_maybe_empty_polygons(
+                segments_to_valid_polygons(
+                        polygon_to_correctly_oriented_segments(
+                                self, context.orient, context.segment_cls
+                        ),
+                        fill_rule, context.contour_cls, context.orient,
+                        context.polygon_cls, context.intersect_segments
+                ),
+                context.empty_cls, context.multipolygon_cls
+        )
+
+    def nearest_points(
+            self, other: hints.Geometry[hints.Scalar], /
+    ) -> t.Tuple[hints.Point[hints.Scalar], hints.Point[hints.Scalar]]:
+        return to_nearest_points(self, other, self._context)
+
     def relate_to(self, other: hints.Compound[hints.Scalar], /) -> Relation:
         context = self._context
         if isinstance(other, context.contour_cls):
@@ -74,6 +133,16 @@ class BasePolygon(ABC, BaseCompound[hints.Scalar]):
         else:
             raise TypeError(f'Unsupported type: {type(other)!r}.')
 
+    def squared_distance_to(self,
+                            other: hints.Geometry[hints.Scalar],
+                            /) -> hints.Scalar:
+        return to_squared_distance(self, other, self._context)
+
+    def validate(self) -> None:
+        errors = self.explain_validity()
+        if errors:
+            raise errors[0]
+
     @t.overload
     def __and__(
             self, other: hints.Empty[hints.Scalar], /
@@ -18,9 +18,13 @@ from rene._clipping import (intersect_segment_with_multipolygon,
                             symmetric_subtract_segment_from_segment,
                             unite_segment_with_multisegmental,
                             unite_segment_with_segment)
+from rene._distance import (are_within_squared_distance,
+                            to_nearest_points,
+                            to_squared_distance)
 from rene._geometries.base_compound import BaseCompound
 from rene._relating import segment
-from rene._utils import locate_point_in_segment
+from rene._utils import (locate_point_in_segment,
+                         points_to_convex_hull)
 
 
 class BaseSegment(ABC, BaseCompound[hints.Scalar]):
@@ -41,10 +45,31 @@ class BaseSegment(ABC, BaseCompound[hints.Scalar]):
                                      min(self.end.y, self.start.y),
                                      max(self.end.y, self.start.y))
 
+    def convex_hull(self) -> t.Union[
+            hints.Contour[hints.Scalar], hints.Empty[hints.Scalar],
+            hints.Point[hints.Scalar], hints.Segment[hints.Scalar]
+    ]:
+        context = self._context
+        return points_to_convex_hull([self.start, self.end], context.orient,
+                                     context.contour_cls, context.empty_cls,
+                                     context.segment_cls)
+
+    def is_within_squared_distance(self,
+                                   other: hints.Geometry[hints.Scalar],
+                                   squared_threshold: hints.Scalar,
+                                   /) -> bool:
+        return are_within_squared_distance(self, other, squared_threshold,
+                                           self._context)
+
     def locate(self, point: hints.Point[hints.Scalar], /) -> Location:
         return locate_point_in_segment(self.start, self.end, point,
                                        self._context.orient)
 
+    def nearest_points(
+            self, other: hints.Geometry[hints.Scalar], /
+    ) -> t.Tuple[hints.Point[hints.Scalar], hints.Point[hints.Scalar]]:
+        return to_nearest_points(self, other, self._context)
+
     def relate_to(self, other: hints.Compound[hints.Scalar], /) -> Relation:
         context = self._context
         if isinstance(other, context.contour_cls):
@@ -67,6 +92,11 @@ class BaseSegment(ABC, BaseCompound[hints.Scalar]):
         else:
             raise TypeError(f'Unsupported type: {type(other)!r}.')
 
+    def squared_distance_to(self,
+                            other: hints.Geometry[hints.Scalar],
+                            /) -> hints.Scalar:
+        return to_squared_distance(self, other, self._context)
+
     @t.overload
     def __and__(
             self, other: hints.Empty[hints.Scalar], /
@@ -14,7 +14,8 @@ from rene import (Orientation,
 from rene._hints import (Orienteer,
                          SegmentsIntersector)
 from rene._utils import (all_same,
-                         is_even)
+                         is_even,
+                         to_oriented_segments)
 from .event import (UNDEFINED_EVENT,
                     Event,
                     is_event_left,
@@ -595,6 +596,27 @@ class RelationState(t.Generic[hints.Scalar]):
         )
 
 
+def relate_regions(first: hints.Contour[hints.Scalar],
+                   second: hints.Contour[hints.Scalar],
+                   orienteer: Orienteer[hints.Scalar],
+                   segments_intersector: SegmentsIntersector[hints.Scalar],
+                   segment_cls: t.Type[hints.Segment[hints.Scalar]],
+                   /) -> Relation:
+    first_bounding_box, second_bounding_box = (first.bounding_box,
+                                               second.bounding_box)
+    if first_bounding_box.disjoint_with(second_bounding_box):
+        return Relation.DISJOINT
+    min_max_x = min(first_bounding_box.max_x, second_bounding_box.max_x)
+    return Operation.from_segments_iterables(
+            to_oriented_segments(first.vertices, Orientation.COUNTERCLOCKWISE,
+                                 orienteer, segment_cls),
+            to_oriented_segments(second.vertices,
+                                 Orientation.COUNTERCLOCKWISE, orienteer,
+                                 segment_cls),
+            orienteer, segments_intersector
+    ).to_relation(True, True, min_max_x)
+
+
 def _populate_with_segments(
         segments: t.Iterable[hints.Segment[hints.Scalar]],
         endpoints: t.List[hints.Point[hints.Scalar]],
@@ -14,6 +14,17 @@ class Base(enum.Enum):
         return f'{type(self).__qualname__}.{self.name}'
 
 
+@te.final
+@enum.unique
+class FillRule(Base):
+    #: point is inside if any ray from it
+    #: crosses the boundary an odd number of times
+    EVEN_ODD = 0
+    #: point is inside if the boundary winds around it
+    #: a non-zero number of times
+    NON_ZERO = 1
+
+
 @te.final
 @enum.unique
 class Location(Base):
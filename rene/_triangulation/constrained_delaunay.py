@@ -28,6 +28,21 @@ BORDER_CONTOUR_INDEX = 0
 
 
 class ConstrainedDelaunayTriangulation(t.Generic[hints.Scalar]):
+    @classmethod
+    def from_multipolygon(cls,
+                          multipolygon: hints.Multipolygon[hints.Scalar],
+                          orienteer: Orienteer[hints.Scalar],
+                          /) -> te.Self:
+        contours_vertices = [
+            contour.vertices
+            for polygon in multipolygon.polygons
+            for contour in [polygon.border, *polygon.holes]
+        ]
+        self = cls._from_contours_vertices(contours_vertices, orienteer)
+        self.cut_exterior([len(contour_vertices)
+                           for contour_vertices in contours_vertices])
+        return self
+
     @classmethod
     def from_polygon(cls,
                      polygon: hints.Polygon[hints.Scalar],
@@ -35,6 +50,29 @@ class ConstrainedDelaunayTriangulation(t.Generic[hints.Scalar]):
                      /) -> te.Self:
         contours_vertices = [polygon.border.vertices,
                              *[hole.vertices for hole in polygon.holes]]
+        self = cls._from_contours_vertices(contours_vertices, orienteer)
+        contours_sizes = [len(contour_vertices)
+                          for contour_vertices in contours_vertices]
+        self.bound(contours_sizes)
+        self.cut(contours_vertices)
+        self._triangular_holes_vertices_indices = (
+            to_triangular_contours_vertices_indices(
+                    [hole_index
+                     for hole_index, hole_size in enumerate(contours_sizes[1:],
+                                                            start=1)
+                     if hole_size == 3],
+                    self._polygon_vertices_positions
+            )
+        )
+        return self
+
+    @classmethod
+    def _from_contours_vertices(
+            cls,
+            contours_vertices: t.List[t.Sequence[hints.Point[hints.Scalar]]],
+            orienteer: Orienteer[hints.Scalar],
+            /
+    ) -> te.Self:
         vertices = list(chain.from_iterable(
                 (
                     ContourVertex(index, vertex_index, vertex)
@@ -59,19 +97,11 @@ class ConstrainedDelaunayTriangulation(t.Generic[hints.Scalar]):
             )
         mesh = Mesh.from_points(points)
         left_side, right_side = build_delaunay_triangulation(mesh, orienteer)
-        contours_sizes = [len(contour_vertices)
-                          for contour_vertices in contours_vertices]
-        triangular_holes_indices = [
-            hole_index
-            for hole_index, hole_size in enumerate(contours_sizes[1:],
-                                                   start=1)
-            if hole_size == 3
-        ]
         self = cls(left_side, right_side, mesh, polygon_vertices_positions,
-                   triangular_holes_indices, orienteer)
-        self.constrain(contours_sizes, contours_vertices)
-        self.bound(contours_sizes)
-        self.cut(contours_vertices)
+                   set(), orienteer)
+        self.constrain([len(contour_vertices)
+                        for contour_vertices in contours_vertices],
+                       contours_vertices)
         return self
 
     @property
@@ -161,6 +191,63 @@ class ConstrainedDelaunayTriangulation(t.Generic[hints.Scalar]):
                                    self._orienteer):
                 self.delete_edge(edge)
 
+    def cut_exterior(self, contours_sizes: t.List[int], /) -> None:
+        mesh = self.mesh
+        faces_interior_flags: t.List[t.Optional[bool]] = [
+            None
+        ] * (len(mesh.left_from_start) // 2)
+        queue = [
+            (to_opposite_edge(edge),
+             is_polygon_edge(mesh, edge, contours_sizes,
+                             self._polygon_vertices_positions))
+            for edge in mesh.to_edges()
+            if (not is_triangular_face(mesh, edge, self._orienteer)
+                and is_triangular_face(mesh, to_opposite_edge(edge),
+                                       self._orienteer))
+        ]
+        triangular_holes_vertices_indices: t.Set[
+            t.Tuple[int, int, int]
+        ] = set()
+        while queue:
+            edge, is_interior = queue.pop()
+            if faces_interior_flags[edge // 2] is not None:
+                continue
+            next_edge = mesh.to_left_from_end(edge)
+            face_edges = (edge, next_edge, mesh.to_left_from_end(next_edge))
+            for face_edge in face_edges:
+                faces_interior_flags[face_edge // 2] = is_interior
+            are_contour_edges = True
+            for face_edge in face_edges:
+                is_constraint = is_polygon_edge(
+                        mesh, face_edge, contours_sizes,
+                        self._polygon_vertices_positions
+                )
+                are_contour_edges = are_contour_edges and is_constraint
+                opposite_edge = to_opposite_edge(face_edge)
+                if (faces_interior_flags[opposite_edge // 2] is None
+                        and is_triangular_face(mesh, opposite_edge,
+                                               self._orienteer)):
+                    queue.append((opposite_edge,
+                                  is_interior is not is_constraint))
+            if not is_interior and are_contour_edges:
+                triangular_holes_vertices_indices.add(
+                        to_triangle_vertices_indices(mesh, edge)
+                )
+        self._triangular_holes_vertices_indices = (
+            triangular_holes_vertices_indices
+        )
+        exterior_edges = [
+            edge
+            for edge in mesh.to_unique_edges()
+            if (faces_interior_flags[edge // 2] is not True
+                and faces_interior_flags[to_opposite_edge(edge) // 2]
+                is not True
+                and not is_polygon_edge(mesh, edge, contours_sizes,
+                                        self._polygon_vertices_positions))
+        ]
+        for edge in exterior_edges:
+            self.delete_edge(edge)
+
     def delete_edge(self, edge: QuadEdge, /) -> None:
         if (edge == self.right_side
                 or to_opposite_edge(edge) == self.right_side):
@@ -211,28 +298,17 @@ class ConstrainedDelaunayTriangulation(t.Generic[hints.Scalar]):
                     and orient_point_to_edge(
                             mesh, edge, third_vertex, self._orienteer
                     ) is Orientation.COUNTERCLOCKWISE
-                    and
-                    not (self._triangular_holes_indices
-                         and are_triangular_hole_vertices(
-                                    self._polygon_vertices_positions[
-                                        mesh.to_start_index(edge)
-                                    ],
-                                    self._polygon_vertices_positions[
-                                        mesh.to_end_index(edge)
-                                    ],
-                                    self._polygon_vertices_positions[
-                                        mesh.to_end_index(
-                                                mesh.to_left_from_start(edge)
-                                        )
-                                    ],
-                                    self._triangular_holes_indices
-                            ))):
+                    and not (
+                            self._triangular_holes_vertices_indices
+                            and (to_triangle_vertices_indices(mesh, edge)
+                                 in self._triangular_holes_vertices_indices)
+                    )):
                 result.append((first_vertex, second_vertex, third_vertex))
         return result
 
     __slots__ = (
         '_left_side', '_mesh', '_orienteer', '_polygon_vertices_positions',
-        '_right_side', '_triangular_holes_indices'
+        '_right_side', '_triangular_holes_vertices_indices'
     )
 
     def __init__(
@@ -241,16 +317,18 @@ class ConstrainedDelaunayTriangulation(t.Generic[hints.Scalar]):
             right_side: QuadEdge,
             mesh: Mesh[hints.Scalar],
             polygon_vertices_positions: t.List[t.List[PolygonVertexPosition]],
-            triangular_holes_indices: t.Container[int],
+            triangular_holes_vertices_indices: t.Container[
+                t.Tuple[int, int, int]
+            ],
             orienteer: Orienteer[hints.Scalar],
             /
     ) -> None:
         (
             self._left_side, self._mesh, self._orienteer,
             self._polygon_vertices_positions, self._right_side,
-            self._triangular_holes_indices
+            self._triangular_holes_vertices_indices
         ) = (left_side, mesh, orienteer, polygon_vertices_positions,
-             right_side, triangular_holes_indices)
+             right_side, triangular_holes_vertices_indices)
 
     def __bool__(self) -> bool:
         result = bool(self.mesh)
@@ -283,26 +361,6 @@ def are_polygon_edge_indices(
             or (first == contour_size - 1 and second == 0))
 
 
-def are_triangular_hole_vertices(
-        first_positions: t.List[PolygonVertexPosition],
-        second_positions: t.List[PolygonVertexPosition],
-        third_positions: t.List[PolygonVertexPosition],
-        triangular_holes_indices: t.Container[int],
-        /
-) -> bool:
-    first_contours_indices = [position.contour_index
-                              for position in first_positions]
-    second_contours_indices = [position.contour_index
-                               for position in second_positions]
-    third_contours_indices = [position.contour_index
-                              for position in third_positions]
-    return any((contour_index != BORDER_CONTOUR_INDEX
-                and contour_index in second_contours_indices
-                and contour_index in third_contours_indices
-                and contour_index in triangular_holes_indices)
-               for contour_index in first_contours_indices)
-
-
 def detect_crossings(mesh: Mesh[hints.Scalar],
                      base_edge: QuadEdge,
                      constraint_start: hints.Point[hints.Scalar],
@@ -434,6 +492,17 @@ def is_segment_inside_hole(
                                          orienteer)))
 
 
+def is_triangular_face(mesh: Mesh[hints.Scalar],
+                       edge: QuadEdge,
+                       orienteer: Orienteer[hints.Scalar],
+                       /) -> bool:
+    next_edge = mesh.to_left_from_end(edge)
+    return (mesh.to_left_from_end(mesh.to_left_from_end(next_edge)) == edge
+            and orient_point_to_edge(mesh, edge, mesh.to_end(next_edge),
+                                     orienteer)
+            is Orientation.COUNTERCLOCKWISE)
+
+
 def mouth_edge_to_incidents(mesh: Mesh[hints.Scalar],
                             edge: QuadEdge,
                             orienteer: Orienteer[hints.Scalar],
@@ -621,3 +690,37 @@ def to_constraint_index(
     return (max(first_vertex_index, second_vertex_index)
             if abs(second_vertex_index - first_vertex_index) == 1
             else 0)
+
+
+def to_triangle_vertices_indices(mesh: Mesh[hints.Scalar],
+                                 edge: QuadEdge,
+                                 /) -> t.Tuple[int, int, int]:
+    first, second, third = sorted(
+            [mesh.to_start_index(edge), mesh.to_end_index(edge),
+             mesh.to_end_index(mesh.to_left_from_end(edge))]
+    )
+    return first, second, third
+
+
+def to_triangular_contours_vertices_indices(
+        triangular_contours_indices: t.List[int],
+        polygon_vertices_positions: t.List[t.List[PolygonVertexPosition]],
+        /
+) -> t.Set[t.Tuple[int, int, int]]:
+    contours_vertices_indices = {contour_index: [0, 0, 0]
+                                 for contour_index
+                                 in triangular_contours_indices}
+    for vertex_index, positions in enumerate(polygon_vertices_positions):
+        for position in positions:
+            try:
+                vertices_indices = contours_vertices_indices[
+                    position.contour_index
+                ]
+            except KeyError:
+                continue
+            vertices_indices[position.index] = vertex_index
+    result: t.Set[t.Tuple[int, int, int]] = set()
+    for vertices_indices in contours_vertices_indices.values():
+        first, second, third = sorted(vertices_indices)
+        result.add((first, second, third))
+    return result
@@ -0,0 +1,229 @@
+from __future__ import annotations
+
+import typing as t
+
+import typing_extensions as te
+
+from rene import (MIN_CONTOUR_VERTICES_COUNT,
+                  Orientation,
+                  hints)
+from rene._hints import Orienteer
+from rene._utils import (deduplicate,
+                         shrink_collinear_vertices,
+                         to_sign)
+from .delaunay import DelaunayTriangulation
+from .mesh import (Mesh,
+                   orient_point_to_edge)
+from .quad_edge import (UNDEFINED_EDGE,
+                        QuadEdge)
+
+
+class VoronoiDiagram(t.Generic[hints.Scalar]):
+    @classmethod
+    def from_delaunay_triangulation(
+            cls,
+            triangulation: DelaunayTriangulation[hints.Scalar],
+            box: hints.Box[hints.Scalar],
+            orienteer: Orienteer[hints.Scalar],
+            point_cls: t.Type[hints.Point[hints.Scalar]],
+            /
+    ) -> te.Self:
+        mesh = triangulation.mesh
+        sites = mesh.endpoints
+        vertices = deduplicate(sorted(
+                [to_circumcenter(first, second, third, point_cls)
+                 for first, second, third
+                 in triangulation.triangles_vertices()]
+        ))
+        cells: t.List[t.Optional[t.List[hints.Point[hints.Scalar]]]]
+        if vertices:
+            sites_edges = [UNDEFINED_EDGE] * len(sites)
+            for edge in mesh.to_edges():
+                start_index = mesh.to_start_index(edge)
+                if sites_edges[start_index] == UNDEFINED_EDGE:
+                    sites_edges[start_index] = edge
+            cells = []
+            for site, site_edge in zip(sites, sites_edges):
+                site_edges = to_vertex_edges(mesh, site_edge)
+                cells.append(
+                        to_bounded_cell_vertices(mesh, site, site_edges,
+                                                 point_cls)
+                        if is_bounded_cell(mesh, site_edges, orienteer)
+                        else to_clipped_cell_vertices(
+                                site,
+                                [mesh.to_end(edge) for edge in site_edges],
+                                box, orienteer, point_cls
+                        )
+                )
+        else:
+            # sites are collinear, so sorted sites are neighbours
+            cells = [
+                to_clipped_cell_vertices(site,
+                                         sites[max(index - 1, 0):index]
+                                         + sites[index + 1:index + 2],
+                                         box, orienteer, point_cls)
+                for index, site in enumerate(sites)
+            ]
+        return cls(cells, sites, vertices)
+
+    @property
+    def cells_vertices(
+            self
+    ) -> t.Sequence[t.Optional[t.List[hints.Point[hints.Scalar]]]]:
+        return self._cells_vertices
+
+    @property
+    def sites(self) -> t.Sequence[hints.Point[hints.Scalar]]:
+        return self._sites
+
+    @property
+    def vertices(self) -> t.Sequence[hints.Point[hints.Scalar]]:
+        return self._vertices
+
+    _cells_vertices: t.List[t.Optional[t.List[hints.Point[hints.Scalar]]]]
+    _sites: t.List[hints.Point[hints.Scalar]]
+    _vertices: t.List[hints.Point[hints.Scalar]]
+
+    __slots__ = '_cells_vertices', '_sites', '_vertices'
+
+    def __new__(
+            cls,
+            cells_vertices: t.List[
+                t.Optional[t.List[hints.Point[hints.Scalar]]]
+            ],
+            sites: t.List[hints.Point[hints.Scalar]],
+            vertices: t.List[hints.Point[hints.Scalar]],
+            /
+    ) -> te.Self:
+        self = super().__new__(cls)
+        self._cells_vertices, self._sites, self._vertices = (
+            cells_vertices, sites, vertices
+        )
+        return self
+
+
+def intersect_segment_with_bisector(
+        start: hints.Point[hints.Scalar],
+        end: hints.Point[hints.Scalar],
+        start_value: hints.Scalar,
+        end_value: hints.Scalar,
+        point_cls: t.Type[hints.Point[hints.Scalar]],
+        /
+) -> hints.Point[hints.Scalar]:
+    scale = start_value / (start_value - end_value)
+    return point_cls(start.x + (end.x - start.x) * scale,
+                     start.y + (end.y - start.y) * scale)
+
+
+def is_bounded_cell(mesh: Mesh[hints.Scalar],
+                    vertex_edges: t.Sequence[QuadEdge],
+                    orienteer: Orienteer[hints.Scalar],
+                    /) -> bool:
+    return (len(vertex_edges) >= 3
+            and all(orient_point_to_edge(
+                    mesh, edge,
+                    mesh.to_end(vertex_edges[(index + 1) % len(vertex_edges)]),
+                    orienteer
+            ) is Orientation.COUNTERCLOCKWISE
+                    for index, edge in enumerate(vertex_edges)))
+
+
+def to_bisector_value(site: hints.Point[hints.Scalar],
+                      neighbour: hints.Point[hints.Scalar],
+                      point: hints.Point[hints.Scalar],
+                      /) -> hints.Scalar:
+    return ((neighbour.x - site.x) * ((point.x + point.x)
+                                      - (site.x + neighbour.x))
+            + (neighbour.y - site.y) * ((point.y + point.y)
+                                        - (site.y + neighbour.y)))
+
+
+def to_bounded_cell_vertices(
+        mesh: Mesh[hints.Scalar],
+        site: hints.Point[hints.Scalar],
+        site_edges: t.Sequence[QuadEdge],
+        point_cls: t.Type[hints.Point[hints.Scalar]],
+        /
+) -> t.List[hints.Point[hints.Scalar]]:
+    result: t.List[hints.Point[hints.Scalar]] = []
+    for index, edge in enumerate(site_edges):
+        vertex = to_circumcenter(
+                site, mesh.to_end(edge),
+                mesh.to_end(site_edges[(index + 1) % len(site_edges)]),
+                point_cls
+        )
+        if not result or result[-1] != vertex:
+            result.append(vertex)
+    if len(result) > 1 and result[0] == result[-1]:
+        del result[-1]
+    return result
+
+
+def to_circumcenter(first: hints.Point[hints.Scalar],
+                    second: hints.Point[hints.Scalar],
+                    third: hints.Point[hints.Scalar],
+                    point_cls: t.Type[hints.Point[hints.Scalar]],
+                    /) -> hints.Point[hints.Scalar]:
+    second_dx, second_dy = second.x - first.x, second.y - first.y
+    third_dx, third_dy = third.x - first.x, third.y - first.y
+    second_squared_norm = second_dx * second_dx + second_dy * second_dy
+    third_squared_norm = third_dx * third_dx + third_dy * third_dy
+    cross_product = second_dx * third_dy - second_dy * third_dx
+    denominator = cross_product + cross_product
+    return point_cls(
+            first.x + (third_dy * second_squared_norm
+                       - second_dy * third_squared_norm) / denominator,
+            first.y + (second_dx * third_squared_norm
+                       - third_dx * second_squared_norm) / denominator
+    )
+
+
+def to_clipped_cell_vertices(
+        site: hints.Point[hints.Scalar],
+        neighbours: t.Sequence[hints.Point[hints.Scalar]],
+        box: hints.Box[hints.Scalar],
+        orienteer: Orienteer[hints.Scalar],
+        point_cls: t.Type[hints.Point[hints.Scalar]],
+        /
+) -> t.Optional[t.List[hints.Point[hints.Scalar]]]:
+    vertices = [point_cls(box.min_x, box.min_y),
+                point_cls(box.max_x, box.min_y),
+                point_cls(box.max_x, box.max_y),
+                point_cls(box.min_x, box.max_y)]
+    for neighbour in neighbours:
+        values = [to_bisector_value(site, neighbour, vertex)
+                  for vertex in vertices]
+        clipped_vertices = []
+        for index, vertex in enumerate(vertices):
+            next_index = (index + 1) % len(vertices)
+            value, next_value = values[index], values[next_index]
+            value_sign, next_value_sign = (to_sign(value),
+                                           to_sign(next_value))
+            if value_sign <= 0:
+                clipped_vertices.append(vertex)
+            if value_sign * next_value_sign < 0:
+                clipped_vertices.append(intersect_segment_with_bisector(
+                        vertex, vertices[next_index], value, next_value,
+                        point_cls
+                ))
+        vertices = clipped_vertices
+        if not vertices:
+            return None
+    if len(vertices) < MIN_CONTOUR_VERTICES_COUNT:
+        return None
+    vertices = shrink_collinear_vertices(vertices, orienteer)
+    return vertices if len(vertices) >= MIN_CONTOUR_VERTICES_COUNT else None
+
+
+def to_vertex_edges(mesh: Mesh[hints.Scalar],
+                    start: QuadEdge,
+                    /) -> t.List[QuadEdge]:
+    result = []
+    if start != UNDEFINED_EDGE:
+        edge = start
+        while True:
+            result.append(edge)
+            edge = mesh.to_left_from_start(edge)
+            if edge == start:
+                break
+    return result
@@ -143,6 +143,15 @@ def deduplicate(values: t.List[_T], /) -> t.List[_T]:
     return [value for value, _ in groupby(values)]
 
 
+def dot_multiply(first_start: hints.Point[hints.Scalar],
+                 first_end: hints.Point[hints.Scalar],
+                 second_start: hints.Point[hints.Scalar],
+                 second_end: hints.Point[hints.Scalar],
+                 /) -> hints.Scalar:
+    return ((first_end.x - first_start.x) * (second_end.x - second_start.x)
+            + (first_end.y - first_start.y) * (second_end.y - second_start.y))
+
+
 def flags_to_false_indices(flags: t.Sequence[bool], /) -> t.List[int]:
     return [index for index, flag in enumerate(flags) if not flag]
 
@@ -267,6 +276,25 @@ def point_vertex_line_divides_angle(
             is orienteer(vertex, point, second_ray_point))
 
 
+def points_to_convex_hull(
+        points: t.Iterable[hints.Point[hints.Scalar]],
+        orienteer: Orienteer[hints.Scalar],
+        contour_cls: t.Type[hints.Contour[hints.Scalar]],
+        empty_cls: t.Type[hints.Empty[hints.Scalar]],
+        segment_cls: t.Type[hints.Segment[hints.Scalar]],
+        /
+) -> t.Union[
+    hints.Contour[hints.Scalar], hints.Empty[hints.Scalar],
+    hints.Point[hints.Scalar], hints.Segment[hints.Scalar]
+]:
+    vertices = to_convex_hull_vertices(points, orienteer)
+    return (contour_cls(vertices)
+            if len(vertices) >= MIN_CONTOUR_VERTICES_COUNT
+            else (segment_cls(vertices[0], vertices[1])
+                  if len(vertices) == 2
+                  else (vertices[0] if vertices else empty_cls())))
+
+
 def polygon_to_correctly_oriented_segments(
         polygon: hints.Polygon[hints.Scalar],
         orienteer: Orienteer[hints.Scalar],
@@ -285,6 +313,18 @@ def polygon_to_segments_count(polygon: hints.Polygon[hints.Scalar], /) -> int:
                                               for hole in polygon.holes)
 
 
+def region_moments_to_centroid(
+        moments: t.Tuple[hints.Scalar, hints.Scalar, hints.Scalar],
+        point_cls: t.Type[hints.Point[hints.Scalar]],
+        /
+) -> t.Optional[hints.Point[hints.Scalar]]:
+    doubled_area, x_moment, y_moment = moments
+    if doubled_area == 0:
+        return None
+    divisor = doubled_area + doubled_area + doubled_area
+    return point_cls(x_moment / divisor, y_moment / divisor)
+
+
 def rotate_sequence(value: t.Sequence[_T]) -> t.List[_T]:
     return [value[0], *value[:0:-1]]
 
@@ -373,6 +413,48 @@ def to_contour_segments(vertices: t.Sequence[hints.Point[hints.Scalar]],
     return result
 
 
+def to_convex_hull_vertices(
+        points: t.Iterable[hints.Point[hints.Scalar]],
+        orienteer: Orienteer[hints.Scalar],
+        /
+) -> t.List[hints.Point[hints.Scalar]]:
+    """
+    Based on Andrew's monotone chain algorithm,
+    returns distinct points for degenerate cases.
+    """
+    sorted_points = deduplicate(sorted(points))
+    if len(sorted_points) < MIN_CONTOUR_VERTICES_COUNT:
+        return sorted_points
+    result: t.List[hints.Point[hints.Scalar]] = []
+    for point in sorted_points:
+        while (len(result) >= 2
+               and (orienteer(result[-2], result[-1], point)
+                    is not Orientation.COUNTERCLOCKWISE)):
+            del result[-1]
+        result.append(point)
+    lower_hull_size = len(result)
+    for point in reversed(sorted_points[:-1]):
+        while (len(result) > lower_hull_size
+               and (orienteer(result[-2], result[-1], point)
+                    is not Orientation.COUNTERCLOCKWISE)):
+            del result[-1]
+        result.append(point)
+    del result[-1]
+    return result
+
+
+def to_doubled_signed_area(
+        vertices: t.Sequence[hints.Point[hints.Scalar]], /
+) -> hints.Scalar:
+    first_vertex = vertices[0]
+    result = cross_multiply(first_vertex, vertices[1], first_vertex,
+                            vertices[2])
+    for index in range(2, len(vertices) - 1):
+        result += cross_multiply(first_vertex, vertices[index], first_vertex,
+                                 vertices[index + 1])
+    return result
+
+
 def to_oriented_segments(vertices: t.Sequence[hints.Point[hints.Scalar]],
                          target_orientation: Orientation,
                          orienteer: Orienteer[hints.Scalar],
@@ -388,6 +470,63 @@ def to_oriented_segments(vertices: t.Sequence[hints.Point[hints.Scalar]],
     )
 
 
+def to_polygon_doubled_area(polygon: hints.Polygon[hints.Scalar],
+                            /) -> hints.Scalar:
+    result = to_doubled_signed_area(polygon.border.vertices)
+    if result < 0:
+        result = -result
+    for hole in polygon.holes:
+        hole_doubled_area = to_doubled_signed_area(hole.vertices)
+        result = (result + hole_doubled_area
+                  if hole_doubled_area < 0
+                  else result - hole_doubled_area)
+    return result
+
+
+def to_polygon_region_moments(
+        polygon: hints.Polygon[hints.Scalar], /
+) -> t.Tuple[hints.Scalar, hints.Scalar, hints.Scalar]:
+    doubled_area, x_moment, y_moment = to_region_signed_moments(
+            polygon.border.vertices
+    )
+    if doubled_area < 0:
+        doubled_area, x_moment, y_moment = (-doubled_area, -x_moment,
+                                            -y_moment)
+    for hole in polygon.holes:
+        hole_doubled_area, hole_x_moment, hole_y_moment = (
+            to_region_signed_moments(hole.vertices)
+        )
+        if hole_doubled_area < 0:
+            doubled_area, x_moment, y_moment = (
+                doubled_area + hole_doubled_area, x_moment + hole_x_moment,
+                y_moment + hole_y_moment
+            )
+        else:
+            doubled_area, x_moment, y_moment = (
+                doubled_area - hole_doubled_area, x_moment - hole_x_moment,
+                y_moment - hole_y_moment
+            )
+    return doubled_area, x_moment, y_moment
+
+
+def to_region_signed_moments(
+        vertices: t.Sequence[hints.Point[hints.Scalar]], /
+) -> t.Tuple[hints.Scalar, hints.Scalar, hints.Scalar]:
+    first_vertex = vertices[0]
+    doubled_area, x_moment, y_moment = to_triangle_signed_moments(
+            first_vertex, vertices[1], vertices[2]
+    )
+    for index in range(2, len(vertices) - 1):
+        (
+            triangle_doubled_area, triangle_x_moment, triangle_y_moment
+        ) = to_triangle_signed_moments(first_vertex, vertices[index],
+                                       vertices[index + 1])
+        doubled_area += triangle_doubled_area
+        x_moment += triangle_x_moment
+        y_moment += triangle_y_moment
+    return doubled_area, x_moment, y_moment
+
+
 def to_sign(value: t.Any, /) -> int:
     return 1 if value > 0 else (-1 if value else 0)
 
@@ -398,6 +537,17 @@ def to_sorted_pair(
     return (first, second) if first < second else (second, first)
 
 
+def to_triangle_signed_moments(
+        first: hints.Point[hints.Scalar],
+        second: hints.Point[hints.Scalar],
+        third: hints.Point[hints.Scalar],
+        /
+) -> t.Tuple[hints.Scalar, hints.Scalar, hints.Scalar]:
+    doubled_area = cross_multiply(first, second, first, third)
+    return (doubled_area, (first.x + second.x + third.x) * doubled_area,
+            (first.y + second.y + third.y) * doubled_area)
+
+
 def validate_seed(
         seed: t.Any, _max_usize_value: int = (sys.maxsize << 1) + 1, /
 ) -> None:
@@ -427,3 +577,16 @@ def subtract_segments_overlap(
     return ((subtrahend_end, minuend_end)
             if subtrahend_start < minuend_start < subtrahend_end
             else (minuend_start, subtrahend_start))
+
+
+def vertices_to_centroid(vertices: t.Iterable[hints.Point[hints.Scalar]],
+                         point_cls: t.Type[hints.Point[hints.Scalar]],
+                         /) -> hints.Point[hints.Scalar]:
+    vertices_iterator = iter(vertices)
+    first_vertex = next(vertices_iterator)
+    x_sum, y_sum, vertices_count = first_vertex.x, first_vertex.y, 1
+    for vertex in vertices_iterator:
+        x_sum += vertex.x
+        y_sum += vertex.y
+        vertices_count += 1
+    return point_cls(x_sum / vertices_count, y_sum / vertices_count)
@@ -0,0 +1,171 @@
+from __future__ import annotations
+
+import typing as t
+
+from rene import Relation
+
+
+class ValidityError(ValueError):
+    pass
+
+
+class BorderError(ValidityError):
+    def __init__(self, error: ValidityError, /) -> None:
+        super().__init__(f'border: {error}')
+        self.error = error
+
+
+class CollinearVerticesError(ValidityError):
+    def __init__(self,
+                 vertices_ids: t.Tuple[int, int, int],
+                 vertices: t.Tuple[t.Any, t.Any, t.Any],
+                 /) -> None:
+        super().__init__('vertices {},{},{} are collinear: {}, {}, {}'
+                         .format(*vertices_ids, *vertices))
+        self.vertices_ids, self.vertices = vertices_ids, vertices
+
+
+class DegenerateSegmentError(ValidityError):
+    def __init__(self, segment_id: int, point: t.Any, /) -> None:
+        super().__init__(f'segment {segment_id} is degenerate at {point}')
+        self.segment_id, self.point = segment_id, point
+
+
+class DisconnectedInteriorError(ValidityError):
+    def __init__(self, hole_id: int, point: t.Any, /) -> None:
+        super().__init__(f'hole {hole_id} disconnects the interior '
+                         f'at {point}')
+        self.hole_id, self.point = hole_id, point
+
+
+class HoleError(ValidityError):
+    def __init__(self, hole_id: int, error: ValidityError, /) -> None:
+        super().__init__(f'hole {hole_id}: {error}')
+        self.hole_id, self.error = hole_id, error
+
+
+class HoleBorderIntersectionError(ValidityError):
+    def __init__(self,
+                 hole_id: int,
+                 relation: Relation,
+                 start: t.Any,
+                 end: t.Any,
+                 /) -> None:
+        super().__init__(f'hole {hole_id} and border'
+                         + _intersection_to_description(relation, start, end))
+        self.hole_id, self.relation, self.start, self.end = (
+            hole_id, relation, start, end
+        )
+
+
+class HoleOutsideBorderError(ValidityError):
+    def __init__(self, hole_id: int, /) -> None:
+        super().__init__(f'hole {hole_id} is outside the border')
+        self.hole_id = hole_id
+
+
+class HolesIntersectionError(ValidityError):
+    def __init__(self,
+                 first_hole_id: int,
+                 second_hole_id: int,
+                 relation: Relation,
+                 start: t.Any,
+                 end: t.Any,
+                 /) -> None:
+        super().__init__(f'holes {first_hole_id} and {second_hole_id}'
+                         + _intersection_to_description(relation, start, end))
+        self.first_hole_id, self.second_hole_id = first_hole_id, second_hole_id
+        self.relation, self.start, self.end = relation, start, end
+
+
+class HolesOverlapError(ValidityError):
+    def __init__(self, first_hole_id: int, second_hole_id: int, /) -> None:
+        super().__init__(f'holes {first_hole_id} and {second_hole_id} '
+                         'overlap')
+        self.first_hole_id, self.second_hole_id = first_hole_id, second_hole_id
+
+
+class PolygonError(ValidityError):
+    def __init__(self, polygon_id: int, error: ValidityError, /) -> None:
+        super().__init__(f'polygon {polygon_id}: {error}')
+        self.polygon_id, self.error = polygon_id, error
+
+
+class PolygonsIntersectionError(ValidityError):
+    def __init__(self,
+                 first_polygon_id: int,
+                 second_polygon_id: int,
+                 relation: Relation,
+                 start: t.Any,
+                 end: t.Any,
+                 /) -> None:
+        super().__init__(f'polygons {first_polygon_id} '
+                         f'and {second_polygon_id}'
+                         + _intersection_to_description(relation, start, end))
+        self.first_polygon_id, self.second_polygon_id = (first_polygon_id,
+                                                         second_polygon_id)
+        self.relation, self.start, self.end = relation, start, end
+
+
+class PolygonsOverlapError(ValidityError):
+    def __init__(self,
+                 first_polygon_id: int,
+                 second_polygon_id: int,
+                 /) -> None:
+        super().__init__(f'polygons {first_polygon_id} '
+                         f'and {second_polygon_id} overlap')
+        self.first_polygon_id, self.second_polygon_id = (first_polygon_id,
+                                                         second_polygon_id)
+
+
+class SegmentsIntersectionError(ValidityError):
+    def __init__(self,
+                 first_segment_id: int,
+                 second_segment_id: int,
+                 relation: Relation,
+                 start: t.Any,
+                 end: t.Any,
+                 /) -> None:
+        super().__init__(f'segments {first_segment_id} '
+                         f'and {second_segment_id}'
+                         + _intersection_to_description(relation, start, end))
+        self.first_segment_id, self.second_segment_id = (first_segment_id,
+                                                         second_segment_id)
+        self.relation, self.start, self.end = relation, start, end
+
+
+class TooFewPolygonsError(ValidityError):
+    def __init__(self, polygons_count: int, /) -> None:
+        super().__init__(f'too few polygons: {polygons_count}')
+        self.polygons_count = polygons_count
+
+
+class TooFewSegmentsError(ValidityError):
+    def __init__(self, segments_count: int, /) -> None:
+        super().__init__(f'too few segments: {segments_count}')
+        self.segments_count = segments_count
+
+
+class TooFewVerticesError(ValidityError):
+    def __init__(self, vertices_count: int, /) -> None:
+        super().__init__(f'too few vertices: {vertices_count}')
+        self.vertices_count = vertices_count
+
+
+class UntouchedNeighbourSegmentsError(ValidityError):
+    def __init__(self, touches_count: int, segments_count: int, /) -> None:
+        super().__init__(f'only {touches_count} of {segments_count} '
+                         'neighbour segments touch at vertices')
+        self.touches_count, self.segments_count = (touches_count,
+                                                   segments_count)
+
+
+def _intersection_to_description(relation: Relation,
+                                 start: t.Any,
+                                 end: t.Any) -> str:
+    if relation == Relation.CROSS:
+        return f' cross at {start}'
+    elif relation == Relation.TOUCH:
+        return f' touch at {start}'
+    else:
+        return f' overlap from {start} to {end}'
@@ -11,7 +11,10 @@ except ImportError:
                          Point,
                          Polygon,
                          Segment,
-                         Trapezoidation)
+                         Trapezoidation,
+                         VoronoiDiagram,
+                         convex_hull,
+                         segments_intersections)
 else:
     import random as _random
     import typing as _t
@@ -35,7 +38,10 @@ else:
     Point = _cexact.Point
     Polygon = _cexact.Polygon
     Segment = _cexact.Segment
+    VoronoiDiagram = _cexact.VoronoiDiagram
     _RawTrapezoidation = _cexact.Trapezoidation
+    convex_hull = _cexact.convex_hull
+    segments_intersections = _cexact.segments_intersections
 
 
     @_te.final
@@ -6,15 +6,21 @@ from numbers import Rational as _Rational
 import typing_extensions as _te
 from rithm.fraction import Fraction as _Fraction
 
-from rene import (Location as _Location,
+from rene import (FillRule as _FillRule,
+                  Location as _Location,
                   Orientation as _Orientation,
-                  Relation as _Relation)
+                  Relation as _Relation,
+                  ValidityError as _ValidityError)
 from rene.hints import Seeder as _Seeder
 
 _ScalarT = _t.Union[_Fraction, _Rational, float, int]
 
 
 class Box:
+    @property
+    def area(self) -> _Fraction:
+        ...
+
     @property
     def max_x(self) -> _Fraction:
         ...
@@ -31,6 +37,9 @@ class Box:
     def min_y(self) -> _Fraction:
         ...
 
+    def convex_hull(self) -> _ConvexHull:
+        ...
+
     def covers(self, other: _te.Self, /) -> bool:
         ...
 
@@ -88,10 +97,18 @@ class Box:
 
 
 class Contour:
+    @property
+    def area(self) -> _Fraction:
+        ...
+
     @property
     def bounding_box(self) -> Box:
         ...
 
+    @property
+    def centroid(self) -> Point:
+        ...
+
     @property
     def orientation(self) -> _Orientation:
         ...
@@ -104,15 +121,40 @@ class Contour:
     def vertices(self) -> _t.Sequence[Point]:
         ...
 
+    def convex_hull(self) -> _ConvexHull:
+        ...
+
+    def explain_validity(self) -> _t.List[_ValidityError]:
+        ...
+
     def is_valid(self) -> bool:
         ...
 
+    def is_within_squared_distance(
+            self, other: _Geometry, squared_threshold: _ScalarT, /
+    ) -> bool:
+        ...
+
     def locate(self, point: Point, /) -> _Location:
         ...
 
+    def make_valid(
+            self, fill_rule: _FillRule, /
+    ) -> _t.Union[Empty, Multipolygon, Polygon]:
+        ...
+
+    def nearest_points(self, other: _Geometry, /) -> _t.Tuple[Point, Point]:
+        ...
+
     def relate_to(self, other: _Compound, /) -> _Relation:
         ...
 
+    def squared_distance_to(self, other: _Geometry, /) -> _Fraction:
+        ...
+
+    def validate(self) -> None:
+        ...
+
     def __new__(cls, vertices: _t.Sequence[Point], /) -> _te.Self:
         ...
 
@@ -182,6 +224,13 @@ class Contour:
 
 
 class Empty:
+    @property
+    def area(self) -> _Fraction:
+        ...
+
+    def convex_hull(self) -> _ConvexHull:
+        ...
+
     def locate(self, point: Point, /) -> _Location:
         ...
 
@@ -279,20 +328,56 @@ class Empty:
 
 
 class Multipolygon:
+    @property
+    def area(self) -> _Fraction:
+        ...
+
     @property
     def bounding_box(self) -> Box:
         ...
 
+    @property
+    def centroid(self) -> Point:
+        ...
+
     @property
     def polygons(self) -> _t.Sequence[Polygon]:
         ...
 
+    def convex_hull(self) -> _ConvexHull:
+        ...
+
+    def explain_validity(self) -> _t.List[_ValidityError]:
+        ...
+
+    def is_valid(self) -> bool:
+        ...
+
+    def is_within_squared_distance(
+            self, other: _Geometry, squared_threshold: _ScalarT, /
+    ) -> bool:
+        ...
+
     def locate(self, point: Point, /) -> _Location:
         ...
 
+    def make_valid(
+            self, fill_rule: _FillRule, /
+    ) -> _t.Union[Empty, Multipolygon, Polygon]:
+        ...
+
+    def nearest_points(self, other: _Geometry, /) -> _t.Tuple[Point, Point]:
+        ...
+
     def relate_to(self, other: _Compound, /) -> _Relation:
         ...
 
+    def squared_distance_to(self, other: _Geometry, /) -> _Fraction:
+        ...
+
+    def validate(self) -> None:
+        ...
+
     def __new__(cls, polygons: _t.Sequence[Polygon], /) -> _te.Self:
         ...
 
@@ -368,19 +453,43 @@ class Multisegment:
     def bounding_box(self) -> Box:
         ...
 
+    @property
+    def centroid(self) -> Point:
+        ...
+
     @property
     def segments(self) -> _t.Sequence[Segment]:
         ...
 
+    def convex_hull(self) -> _ConvexHull:
+        ...
+
+    def explain_validity(self) -> _t.List[_ValidityError]:
+        ...
+
     def is_valid(self) -> bool:
         ...
 
+    def is_within_squared_distance(
+            self, other: _Geometry, squared_threshold: _ScalarT, /
+    ) -> bool:
+        ...
+
     def locate(self, point: Point, /) -> _Location:
         ...
 
+    def nearest_points(self, other: _Geometry, /) -> _t.Tuple[Point, Point]:
+        ...
+
     def relate_to(self, other: _Compound, /) -> _Relation:
         ...
 
+    def squared_distance_to(self, other: _Geometry, /) -> _Fraction:
+        ...
+
+    def validate(self) -> None:
+        ...
+
     def __new__(cls, segments: _t.Sequence[Segment], /) -> _te.Self:
         ...
 
@@ -456,6 +565,20 @@ class Point:
     def y(self) -> _Fraction:
         ...
 
+    def convex_hull(self) -> _ConvexHull:
+        ...
+
+    def is_within_squared_distance(
+            self, other: _Geometry, squared_threshold: _ScalarT, /
+    ) -> bool:
+        ...
+
+    def nearest_points(self, other: _Geometry, /) -> _t.Tuple[Point, Point]:
+        ...
+
+    def squared_distance_to(self, other: _Geometry, /) -> _Fraction:
+        ...
+
     def __new__(cls, x: _ScalarT, y: _ScalarT, /) -> _te.Self:
         ...
 
@@ -490,6 +613,10 @@ class Point:
 
 
 class Polygon:
+    @property
+    def area(self) -> _Fraction:
+        ...
+
     @property
     def border(self) -> Contour:
         ...
@@ -498,16 +625,48 @@ class Polygon:
     def bounding_box(self) -> Box:
         ...
 
+    @property
+    def centroid(self) -> Point:
+        ...
+
     @property
     def holes(self) -> _t.Sequence[Contour]:
         ...
 
+    def convex_hull(self) -> _ConvexHull:
+        ...
+
+    def explain_validity(self) -> _t.List[_ValidityError]:
+        ...
+
+    def is_valid(self) -> bool:
+        ...
+
+    def is_within_squared_distance(
+            self, other: _Geometry, squared_threshold: _ScalarT, /
+    ) -> bool:
+        ...
+
     def locate(self, point: Point, /) -> _Location:
         ...
 
+    def make_valid(
+            self, fill_rule: _FillRule, /
+    ) -> _t.Union[Empty, Multipolygon, Polygon]:
+        ...
+
+    def nearest_points(self, other: _Geometry, /) -> _t.Tuple[Point, Point]:
+        ...
+
     def relate_to(self, other: _Compound, /) -> _Relation:
         ...
 
+    def squared_distance_to(self, other: _Geometry, /) -> _Fraction:
+        ...
+
+    def validate(self) -> None:
+        ...
+
     def __new__(
             cls, border: Contour, holes: _t.Sequence[Contour], /
     ) -> _te.Self:
@@ -585,6 +744,10 @@ class Segment:
     def bounding_box(self) -> Box:
         ...
 
+    @property
+    def centroid(self) -> Point:
+        ...
+
     @property
     def end(self) -> Point:
         ...
@@ -593,12 +756,26 @@ class Segment:
     def start(self) -> Point:
         ...
 
+    def convex_hull(self) -> _ConvexHull:
+        ...
+
     def locate(self, point: Point, /) -> _Location:
         ...
 
+    def is_within_squared_distance(
+            self, other: _Geometry, squared_threshold: _ScalarT, /
+    ) -> bool:
+        ...
+
+    def nearest_points(self, other: _Geometry, /) -> _t.Tuple[Point, Point]:
+        ...
+
     def relate_to(self, other: _Compound, /) -> _Relation:
         ...
 
+    def squared_distance_to(self, other: _Geometry, /) -> _Fraction:
+        ...
+
     def __new__(cls, start: Point, end: Point, /) -> _te.Self:
         ...
 
@@ -671,6 +848,10 @@ class Segment:
 
 @_te.final
 class ConstrainedDelaunayTriangulation:
+    @classmethod
+    def from_multipolygon(cls, multipolygon: Multipolygon, /) -> _te.Self:
+        ...
+
     @classmethod
     def from_polygon(cls, polygon: Polygon, /) -> _te.Self:
         ...
@@ -693,6 +874,11 @@ class DelaunayTriangulation:
     def from_points(cls, points: _t.Sequence[Point], /) -> _te.Self:
         ...
 
+    def alpha_shape(
+            self, squared_alpha: _ScalarT, /
+    ) -> _t.Union[Empty, Multipolygon, Polygon]:
+        ...
+
     @property
     def border(self) -> Contour:
         ...
@@ -734,6 +920,42 @@ class Trapezoidation:
         ...
 
 
+@_te.final
+class VoronoiDiagram:
+    @classmethod
+    def from_points(cls,
+                    points: _t.Sequence[Point],
+                    box: Box,
+                    /) -> _te.Self:
+        ...
+
+    @property
+    def cells(self) -> _t.Sequence[_t.Optional[Contour]]:
+        ...
+
+    @property
+    def sites(self) -> _t.Sequence[Point]:
+        ...
+
+    @property
+    def vertices(self) -> _t.Sequence[Point]:
+        ...
+
+
 _Compound = _t.Union[
     Contour, Empty, Multisegment, Multipolygon, Polygon, Segment
 ]
+_ConvexHull = _t.Union[Contour, Empty, Point, Segment]
+_Geometry = _t.Union[
+    Contour, Multipolygon, Multisegment, Point, Polygon, Segment
+]
+
+
+def convex_hull(points: _t.Sequence[Point], /) -> _ConvexHull:
+    ...
+
+
+def segments_intersections(
+        segments: _t.Sequence[Segment], /
+) -> _t.List[_t.Tuple[int, int, _Relation, _t.Union[Point, Segment]]]:
+    ...
@@ -4,9 +4,11 @@ import typing as _t
 
 import typing_extensions as _te
 
-from rene import (Location as _Location,
+from rene import (FillRule as _FillRule,
+                  Location as _Location,
                   Orientation as _Orientation,
-                  Relation as _Relation)
+                  Relation as _Relation,
+                  ValidityError as _ValidityError)
 
 
 class _Scalar(_te.Protocol):
@@ -75,6 +77,11 @@ class _Scalar(_te.Protocol):
     def __sub__(self, other: _te.Self, /) -> _te.Self:
         ...
 
+    @_t.overload
+    def __truediv__(self, other: int, /) -> _te.Self:
+        ...
+
+    @_t.overload
     def __truediv__(self, other: _te.Self, /) -> _te.Self:
         ...
 
@@ -299,12 +306,25 @@ class Segment(_SelfComparable, _te.Protocol[Scalar]):
     def start(self) -> Point[Scalar]:
         ...
 
+    def is_within_squared_distance(self, other: Geometry[Scalar],
+                                   squared_threshold: Scalar,
+                                   /) -> bool:
+        ...
+
     def locate(self, point: Point[Scalar], /) -> _Location:
         ...
 
+    def nearest_points(
+            self, other: Geometry[Scalar], /
+    ) -> _t.Tuple[Point[Scalar], Point[Scalar]]:
+        ...
+
     def relate_to(self, other: Compound[Scalar], /) -> _Relation:
         ...
 
+    def squared_distance_to(self, other: Geometry[Scalar], /) -> Scalar:
+        ...
+
     def __new__(cls, start: Point[Scalar], end: Point[Scalar], /) -> _te.Self:
         ...
 
@@ -409,15 +429,39 @@ class Contour(_SelfComparable, Multisegmental[Segment[Scalar]],
     def vertices(self) -> _t.Sequence[Point[Scalar]]:
         ...
 
+    def explain_validity(self) -> _t.List[_ValidityError]:
+        ...
+
     def is_valid(self) -> bool:
         ...
 
+    def is_within_squared_distance(self, other: Geometry[Scalar],
+                                   squared_threshold: Scalar,
+                                   /) -> bool:
+        ...
+
     def locate(self, point: Point[Scalar], /) -> _Location:
         ...
 
+    def make_valid(self, fill_rule: _FillRule, /) -> _t.Union[
+            Empty[Scalar], Multipolygon[Scalar], Polygon[Scalar]
+    ]:
+        ...
+
+    def nearest_points(
+            self, other: Geometry[Scalar], /
+    ) -> _t.Tuple[Point[Scalar], Point[Scalar]]:
+        ...
+
     def relate_to(self, other: Compound[Scalar], /) -> _Relation:
         ...
 
+    def squared_distance_to(self, other: Geometry[Scalar], /) -> Scalar:
+        ...
+
+    def validate(self) -> None:
+        ...
+
     def __new__(cls, vertices: _t.Sequence[Point[Scalar]], /) -> _te.Self:
         ...
 
@@ -497,15 +541,34 @@ class Multisegment(_SelfComparable, Multisegmental[Segment[Scalar]],
     def bounding_box(self) -> Box[Scalar]:
         ...
 
+    def explain_validity(self) -> _t.List[_ValidityError]:
+        ...
+
     def is_valid(self) -> bool:
         ...
 
+    def is_within_squared_distance(self, other: Geometry[Scalar],
+                                   squared_threshold: Scalar,
+                                   /) -> bool:
+        ...
+
     def locate(self, point: Point[Scalar], /) -> _Location:
         ...
 
+    def nearest_points(
+            self, other: Geometry[Scalar], /
+    ) -> _t.Tuple[Point[Scalar], Point[Scalar]]:
+        ...
+
     def relate_to(self, other: Compound[Scalar], /) -> _Relation:
         ...
 
+    def squared_distance_to(self, other: Geometry[Scalar], /) -> Scalar:
+        ...
+
+    def validate(self) -> None:
+        ...
+
     def __new__(cls, segments: _t.Sequence[Segment[Scalar]], /) -> _te.Self:
         ...
 
@@ -591,12 +654,39 @@ class Polygon(_SelfComparable, _te.Protocol[Scalar]):
     def holes(self) -> _t.Sequence[Contour[Scalar]]:
         ...
 
+    def explain_validity(self) -> _t.List[_ValidityError]:
+        ...
+
+    def is_valid(self) -> bool:
+        ...
+
+    def is_within_squared_distance(self, other: Geometry[Scalar],
+                                   squared_threshold: Scalar,
+                                   /) -> bool:
+        ...
+
     def locate(self, point: Point[Scalar], /) -> _Location:
         ...
 
+    def make_valid(self, fill_rule: _FillRule, /) -> _t.Union[
+            Empty[Scalar], Multipolygon[Scalar], Polygon[Scalar]
+    ]:
+        ...
+
+    def nearest_points(
+            self, other: Geometry[Scalar], /
+    ) -> _t.Tuple[Point[Scalar], Point[Scalar]]:
+        ...
+
     def relate_to(self, other: Compound[Scalar], /) -> _Relation:
         ...
 
+    def squared_distance_to(self, other: Geometry[Scalar], /) -> Scalar:
+        ...
+
+    def validate(self) -> None:
+        ...
+
     def __new__(cls,
                 border: Contour[Scalar],
                 holes: _t.Sequence[Contour[Scalar]], /) -> _te.Self:
@@ -674,12 +764,39 @@ class Multipolygon(_SelfComparable, _te.Protocol[Scalar]):
     def polygons(self) -> _t.Sequence[Polygon[Scalar]]:
         ...
 
+    def explain_validity(self) -> _t.List[_ValidityError]:
+        ...
+
+    def is_valid(self) -> bool:
+        ...
+
+    def is_within_squared_distance(self, other: Geometry[Scalar],
+                                   squared_threshold: Scalar,
+                                   /) -> bool:
+        ...
+
     def locate(self, point: Point[Scalar], /) -> _Location:
         ...
 
+    def make_valid(self, fill_rule: _FillRule, /) -> _t.Union[
+            Empty[Scalar], Multipolygon[Scalar], Polygon[Scalar]
+    ]:
+        ...
+
+    def nearest_points(
+            self, other: Geometry[Scalar], /
+    ) -> _t.Tuple[Point[Scalar], Point[Scalar]]:
+        ...
+
     def relate_to(self, other: Compound[Scalar], /) -> _Relation:
         ...
 
+    def squared_distance_to(self, other: Geometry[Scalar], /) -> Scalar:
+        ...
+
+    def validate(self) -> None:
+        ...
+
     def __new__(
             cls, vertices: _t.Sequence[Polygon[Scalar]], /
     ) -> _te.Self:
@@ -754,3 +871,8 @@ Compound = _t.Union[
     Contour[Scalar], Empty[Scalar], Multisegment[Scalar], Multipolygon[Scalar],
     Polygon[Scalar], Segment[Scalar]
 ]
+
+Geometry = _t.Union[
+    Contour[Scalar], Multipolygon[Scalar], Multisegment[Scalar], Point[Scalar],
+    Polygon[Scalar], Segment[Scalar]
+]
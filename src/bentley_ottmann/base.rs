@@ -1,111 +1,66 @@
 use core::convert::From;
+use std::collections::{BTreeMap, BTreeSet};
 
-use crate::constants::MIN_MULTISEGMENT_SEGMENTS_COUNT;
-use crate::contracts::are_contour_vertices_non_degenerate;
-use crate::operations::Orient;
-use crate::relatable::Relation;
+use crate::bounded::{Bounded, Box};
+use crate::operations::{
+    to_union_find_root, IntersectCrossingSegments, Orient, ToReversedSegments,
+};
+use crate::oriented::Oriented;
+use crate::relatable::{Relatable, Relation};
+use crate::relating::shaped::{relate_regions, Operation};
+use crate::sweeping::traits::{EventsQueue, SweepLine};
 use crate::traits::{
-    Contoural, Elemental, Iterable, Lengthsome, Multisegmental, Multivertexal,
-    Segmental,
+    Contoural, Elemental, Iterable, Lengthsome, Multisegmental, Segmental,
 };
 
 use super::event::{is_event_left, Event};
 use super::events_registry::EventsRegistry;
 use super::sweep::{Intersection, Sweep};
 
-pub(crate) fn is_contour_valid<Contour, Point: Ord, Scalar, Segment>(
-    contour: &Contour,
-) -> bool
-where
-    Sweep<Point>: Iterator<Item = Intersection<Point>>
-        + for<'a, 'b> From<&'a <&'b Contour as Multisegmental>::Segments>,
-    for<'a> &'a Contour:
-        Contoural<IndexVertex = Point, IndexSegment = Segment>,
-    for<'a> &'a Point: Elemental<Coordinate = &'a Scalar> + Orient,
-    for<'a> &'a Segment: Segmental<Endpoint = &'a Point>,
-{
-    are_contour_vertices_non_degenerate(
-        &contour.vertices().iter().collect::<Vec<_>>(),
-    ) && {
-        let segments = contour.segments();
-        segments.iter().all(|segment| {
-            let (start, end) = segment.endpoints();
-            start != end
-        }) && {
-            let mut sweep = Sweep::from(&segments);
-            let mut neighbour_segments_touches_count = 0usize;
-            while let Some(intersection) = sweep.next() {
-                debug_assert_eq!(
-                    intersection.start == intersection.end,
-                    matches!(
-                        intersection.relation,
-                        Relation::Touch | Relation::Cross
-                    )
-                );
-                let touches_at_vertices =
-                    intersection.relation == Relation::Touch
-                        && (intersection.start.eq(sweep.get_segment_start(
-                            intersection.first_segment_id,
-                        )) || intersection.start.eq(sweep
-                            .get_segment_end(intersection.first_segment_id)))
-                        && (intersection.start.eq(sweep.get_segment_start(
-                            intersection.second_segment_id,
-                        )) || intersection.start.eq(sweep
-                            .get_segment_end(intersection.second_segment_id)));
-                let neighbour_segments_intersection = intersection
-                    .first_segment_id
-                    .abs_diff(intersection.second_segment_id)
-                    == 1
-                    || (intersection.first_segment_id == segments.len() - 1
-                        && intersection.second_segment_id == 0)
-                    || (intersection.second_segment_id == segments.len() - 1
-                        && intersection.first_segment_id == 0);
-                if !(touches_at_vertices && neighbour_segments_intersection) {
-                    return false;
-                }
-                neighbour_segments_touches_count += 1;
-            }
-            neighbour_segments_touches_count == segments.len()
-        }
-    }
-}
-
-pub(crate) fn is_multisegment_valid<
-    'a,
-    Multisegment,
-    Point: PartialEq,
-    Segment,
->(
-    multisegment: &'a Multisegment,
-) -> bool
-where
-    Sweep<Point>: for<'b, 'c> From<&'b <&'c Multisegment as Multisegmental>::Segments>
-        + Iterator<Item = Intersection<Point>>,
-    for<'b> &'b Multisegment: Multisegmental<IndexSegment = Segment>,
-    for<'b> &'b Segment: Segmental<Endpoint = &'b Point>,
-{
-    let segments = multisegment.segments();
-    segments.len() >= MIN_MULTISEGMENT_SEGMENTS_COUNT
-        && segments.iter().all(|segment| {
-            let (start, end) = segment.endpoints();
-            start != end
-        })
-        && Sweep::from(&segments)
-            .all(|intersection| intersection.relation == Relation::Touch)
+/// Checks if given intersection of contour segments
+/// is a touch of neighbour segments at their common vertex.
+pub(super) fn is_neighbour_segments_touch<Point: PartialEq>(
+    sweep: &Sweep<Point>,
+    intersection: &Intersection<Point>,
+    segments_count: usize,
+) -> bool {
+    let touches_at_vertices = intersection.relation == Relation::Touch
+        && (intersection
+            .start
+            .eq(sweep.get_segment_start(intersection.first_segment_id))
+            || intersection
+                .start
+                .eq(sweep.get_segment_end(intersection.first_segment_id)))
+        && (intersection
+            .start
+            .eq(sweep.get_segment_start(intersection.second_segment_id))
+            || intersection
+                .start
+                .eq(sweep.get_segment_end(intersection.second_segment_id)));
+    let neighbour_segments_intersection = intersection
+        .first_segment_id
+        .abs_diff(intersection.second_segment_id)
+        == 1
+        || (intersection.first_segment_id == segments_count - 1
+            && intersection.second_segment_id == 0)
+        || (intersection.second_segment_id == segments_count - 1
+            && intersection.first_segment_id == 0);
+    touches_at_vertices && neighbour_segments_intersection
 }
 
+/// Splits segments into unique non-crossing & non-overlapping pieces.
 pub(crate) fn to_unique_non_crossing_or_overlapping_segments<
     Point: Clone,
     Segment: From<(Point, Point)>,
+    Segments,
 >(
-    segments: &[Segment],
+    segments: &Segments,
 ) -> Vec<Segment>
 where
-    Segment: Segmental<Endpoint = Point>,
     for<'a> EventsRegistry<Point, true>:
-        From<&'a [Segment]> + Iterator<Item = Event>,
+        From<&'a Segments> + Iterator<Item = Event>,
 {
-    let mut result = Vec::with_capacity(segments.len());
+    let mut result = Vec::new();
     let mut events_registry = EventsRegistry::<Point, true>::from(segments);
     while let Some(event) = events_registry.next() {
         if !is_event_left(event) {
@@ -117,3 +72,153 @@ where
     }
     result
 }
+
+/// Checks if interiors of polygons with given components do not intersect,
+/// assuming that their contours do not cross or overlap.
+pub(super) fn are_polygons_interiors_disjoint<
+    Border,
+    Point: Clone + Ord,
+    Scalar: Ord,
+    Segment: Clone + Segmental<Endpoint = Point>,
+>(
+    (first_border, first_holes): (&Border, &[&Border]),
+    (second_border, second_holes): (&Border, &[&Border]),
+) -> bool
+where
+    Operation<Point>: EventsQueue<Event = Event> + SweepLine<Event = Event>,
+    for<'a> &'a Border: Bounded<&'a Scalar>
+        + Contoural<IndexSegment = Segment, IndexVertex = Point>
+        + Oriented
+        + ToReversedSegments<Output = Vec<Segment>>,
+    for<'a> &'a Point: Elemental<Coordinate = &'a Scalar>
+        + IntersectCrossingSegments<Output = Point>
+        + Orient,
+    for<'a> &'a Segment: Segmental<Endpoint = &'a Point>,
+{
+    match relate_regions(first_border, second_border) {
+        Relation::Disjoint | Relation::Touch => true,
+        Relation::Within | Relation::Enclosed => {
+            is_region_inside_some_hole(first_border, second_holes)
+        }
+        Relation::Cover | Relation::Encloses => {
+            is_region_inside_some_hole(second_border, first_holes)
+        }
+        _ => false,
+    }
+}
+
+/// Lazily yields intersections of segments from different borders
+/// along with ids of these borders.
+pub(super) fn to_borders_intersections<
+    Border,
+    Point: Clone + Ord,
+    Segment: Clone,
+>(
+    borders: &[&Border],
+) -> impl Iterator<Item = (usize, usize, Intersection<Point>)>
+where
+    Sweep<Point>:
+        Iterator<Item = Intersection<Point>> + for<'a> From<&'a Vec<Segment>>,
+    for<'a> &'a Border: Multisegmental<IndexSegment = Segment>,
+    for<'a> &'a Segment: Segmental<Endpoint = &'a Point>,
+{
+    let mut segments = Vec::<Segment>::new();
+    let mut segments_borders_ids = Vec::<usize>::new();
+    for (border_id, border) in borders.iter().enumerate() {
+        let border_segments = border.segments();
+        segments.extend(border_segments.iter().cloned());
+        segments_borders_ids
+            .extend(std::iter::repeat(border_id).take(border_segments.len()));
+    }
+    Sweep::from(&segments).filter_map(move |intersection| {
+        let first_border_id =
+            segments_borders_ids[intersection.first_segment_id];
+        let second_border_id =
+            segments_borders_ids[intersection.second_segment_id];
+        (first_border_id != second_border_id).then_some((
+            first_border_id,
+            second_border_id,
+            intersection,
+        ))
+    })
+}
+
+/// Returns a touch which closes a cycle in the graph
+/// with borders and their touch points as nodes, if any,
+/// such a cycle means that the interior bounded by borders is disconnected.
+pub(super) fn find_cycle_closing_touch<Point: Clone + Ord>(
+    borders_count: usize,
+    touches: Vec<(usize, usize, Point)>,
+) -> Option<(usize, usize, Point)> {
+    let mut parents = (0..borders_count).collect::<Vec<_>>();
+    let mut points_nodes = BTreeMap::<Point, usize>::new();
+    let mut edges = BTreeSet::<(usize, usize)>::new();
+    for (first_border_id, second_border_id, point) in touches {
+        let point_node =
+            *points_nodes.entry(point.clone()).or_insert_with(|| {
+                parents.push(parents.len());
+                parents.len() - 1
+            });
+        for border_id in [first_border_id, second_border_id] {
+            if edges.insert((border_id, point_node)) {
+                let border_root = to_union_find_root(&mut parents, border_id);
+                let point_root = to_union_find_root(&mut parents, point_node);
+                if border_root == point_root {
+                    return Some((first_border_id, second_border_id, point));
+                }
+                parents[border_root] = point_root;
+            }
+        }
+    }
+    None
+}
+
+pub(super) fn to_boxes_ids_pairs_with_intersection<Scalar: Ord>(
+    boxes: &[Box<&Scalar>],
+) -> Vec<(usize, usize)> {
+    let mut ids = (0..boxes.len()).collect::<Vec<_>>();
+    ids.sort_by_key(|&id| boxes[id].get_min_x());
+    let mut result = Vec::new();
+    for (position, &id) in ids.iter().enumerate() {
+        for &other_id in &ids[position + 1..] {
+            if boxes[other_id].get_min_x() > boxes[id].get_max_x() {
+                break;
+            }
+            if !boxes[id].disjoint_with(&boxes[other_id]) {
+                result.push((id, other_id));
+            }
+        }
+    }
+    result
+}
+
+/// Checks if the region bounded by given border lies inside one of holes,
+/// assuming that border does not cross holes.
+fn is_region_inside_some_hole<
+    Border,
+    Point: Clone + Ord,
+    Scalar: Ord,
+    Segment,
+>(
+    border: &Border,
+    holes: &[&Border],
+) -> bool
+where
+    Operation<Point>: EventsQueue<Event = Event> + SweepLine<Event = Event>,
+    Segment: Clone + Segmental<Endpoint = Point>,
+    for<'a> &'a Border: Bounded<&'a Scalar>
+        + Contoural<IndexSegment = Segment, IndexVertex = Point>
+        + Oriented
+        + ToReversedSegments<Output = Vec<Segment>>,
+    for<'a> &'a Point: Elemental<Coordinate = &'a Scalar>
+        + IntersectCrossingSegments<Output = Point>
+        + Orient,
+    for<'a> &'a Segment: Segmental<Endpoint = &'a Point>,
+{
+    holes.iter().any(|hole| {
+        matches!(
+            relate_regions(border, *hole),
+            Relation::Within | Relation::Enclosed
+        )
+    })
+}
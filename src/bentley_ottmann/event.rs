@@ -1,6 +1,6 @@
 use traiter::numbers::Parity;
 
-pub(super) type Event = usize;
+pub(crate) type Event = usize;
 
 pub(super) fn is_event_left(event: Event) -> bool {
     event.is_even()
@@ -130,14 +130,14 @@ impl<Point, const UNIQUE: bool> EventsRegistry<Point, UNIQUE> {
         self.segments_ids[event / 2]
     }
 
-    fn to_min_collinear_segment_id(&self, segment_id: usize) -> usize {
+    pub(super) fn to_min_collinear_segment_id(
+        &self,
+        segment_id: usize,
+    ) -> usize {
         let mut candidate = segment_id;
-        let mut iterations_count = 0;
         while self.min_collinear_segments_ids[candidate] != candidate {
             candidate = self.min_collinear_segments_ids[candidate];
-            iterations_count += 1;
         }
-        debug_assert!(iterations_count < 3);
         candidate
     }
 
@@ -336,9 +336,9 @@ where
         let first_segment_id = self.to_left_event_segment_id(first);
         let second_segment_id = self.to_left_event_segment_id(second);
         let first_min_collinear_segment_id =
-            self.min_collinear_segments_ids[first_segment_id];
+            self.to_min_collinear_segment_id(first_segment_id);
         let second_min_collinear_segment_id =
-            self.min_collinear_segments_ids[second_segment_id];
+            self.to_min_collinear_segment_id(second_segment_id);
         let min_collinear_segment_id = first_min_collinear_segment_id
             .min(second_min_collinear_segment_id);
         self.min_collinear_segments_ids[first_segment_id] =
@@ -0,0 +1,169 @@
+use std::collections::BTreeMap;
+
+use crate::operations::{to_sorted_pair, Orient};
+use crate::relating::segment_endpoints;
+
+use super::sweep::{Intersection, Sweep};
+
+/// Returns intersections of each pair of distinct intersecting segments
+/// ordered by segments ids.
+/// Sweep reports segments with events at a common point,
+/// but overlapping parts of segments share a single entry
+/// in the sweep line and get divided only once,
+/// so pairs with members of collinear groups are completed afterwards.
+pub(crate) fn to_segments_intersections<Point: Clone + Ord, Segment>(
+    segments: &Vec<Segment>,
+) -> Vec<Intersection<Point>>
+where
+    Sweep<Point>:
+        for<'a> From<&'a Vec<Segment>> + Iterator<Item = Intersection<Point>>,
+    for<'a> &'a Point: Orient,
+{
+    let mut sweep = Sweep::from(segments);
+    let mut result = BTreeMap::new();
+    for intersection in sweep.by_ref() {
+        if intersection.first_segment_id < intersection.second_segment_id {
+            result
+                .entry((
+                    intersection.first_segment_id,
+                    intersection.second_segment_id,
+                ))
+                .or_insert(intersection);
+        } else if intersection.first_segment_id
+            > intersection.second_segment_id
+        {
+            result
+                .entry((
+                    intersection.second_segment_id,
+                    intersection.first_segment_id,
+                ))
+                .or_insert(Intersection {
+                    first_segment_id: intersection.second_segment_id,
+                    second_segment_id: intersection.first_segment_id,
+                    relation: intersection.relation.to_complement(),
+                    start: intersection.start,
+                    end: intersection.end,
+                });
+        }
+    }
+    let mut collinear_groups = BTreeMap::<usize, Vec<usize>>::new();
+    for segment_id in 0..segments.len() {
+        collinear_groups
+            .entry(sweep.to_min_collinear_segment_id(segment_id))
+            .or_default()
+            .push(segment_id);
+    }
+    for group in collinear_groups.values() {
+        for (index, &first_segment_id) in group.iter().enumerate() {
+            for &second_segment_id in &group[index + 1..] {
+                let start = sweep
+                    .get_segment_start(first_segment_id)
+                    .max(sweep.get_segment_start(second_segment_id));
+                let end = sweep
+                    .get_segment_end(first_segment_id)
+                    .min(sweep.get_segment_end(second_segment_id));
+                if start <= end {
+                    let (start, end) = (start.clone(), end.clone());
+                    result
+                        .entry((first_segment_id, second_segment_id))
+                        .or_insert_with(|| {
+                            to_intersection(
+                                &sweep,
+                                first_segment_id,
+                                second_segment_id,
+                                start,
+                                end,
+                            )
+                        });
+                }
+            }
+        }
+    }
+    let points_intersections = result
+        .values()
+        .filter(|intersection| {
+            intersection.start == intersection.end
+                && (collinear_groups[&sweep.to_min_collinear_segment_id(
+                    intersection.first_segment_id,
+                )]
+                    .len()
+                    > 1
+                    || collinear_groups[&sweep.to_min_collinear_segment_id(
+                        intersection.second_segment_id,
+                    )]
+                        .len()
+                        > 1)
+        })
+        .map(|intersection| {
+            (
+                intersection.first_segment_id,
+                intersection.second_segment_id,
+                intersection.start.clone(),
+            )
+        })
+        .collect::<Vec<_>>();
+    for (first_segment_id, second_segment_id, point) in points_intersections {
+        let to_group_segments_ids_containing_point = |segment_id| {
+            collinear_groups[&sweep.to_min_collinear_segment_id(segment_id)]
+                .iter()
+                .copied()
+                .filter(|&member_id| {
+                    sweep.get_segment_start(member_id).le(&point)
+                        && point.le(sweep.get_segment_end(member_id))
+                })
+                .collect::<Vec<_>>()
+        };
+        let first_segments_ids =
+            to_group_segments_ids_containing_point(first_segment_id);
+        let second_segments_ids =
+            to_group_segments_ids_containing_point(second_segment_id);
+        for &first_member_id in &first_segments_ids {
+            for &second_member_id in &second_segments_ids {
+                if first_member_id != second_member_id {
+                    let (first_member_id, second_member_id) =
+                        to_sorted_pair((first_member_id, second_member_id));
+                    result
+                        .entry((first_member_id, second_member_id))
+                        .or_insert_with(|| {
+                            to_intersection(
+                                &sweep,
+                                first_member_id,
+                                second_member_id,
+                                point.clone(),
+                                point.clone(),
+                            )
+                        });
+                }
+            }
+        }
+    }
+    result.into_values().collect()
+}
+
+fn to_intersection<Point: Ord>(
+    sweep: &Sweep<Point>,
+    first_segment_id: usize,
+    second_segment_id: usize,
+    start: Point,
+    end: Point,
+) -> Intersection<Point>
+where
+    for<'a> &'a Point: Orient,
+{
+    Intersection {
+        first_segment_id,
+        second_segment_id,
+        relation: segment_endpoints::relate_to_segment_endpoints(
+            (
+                sweep.get_segment_start(first_segment_id),
+                sweep.get_segment_end(first_segment_id),
+            ),
+            (
+                sweep.get_segment_start(second_segment_id),
+                sweep.get_segment_end(second_segment_id),
+            ),
+        ),
+        start,
+        end,
+    }
+}
@@ -1,11 +1,20 @@
-pub(crate) use base::{
-    is_contour_valid, is_multisegment_valid,
-    to_unique_non_crossing_or_overlapping_segments,
+pub(crate) use base::to_unique_non_crossing_or_overlapping_segments;
+pub(crate) use event::Event;
+pub(crate) use events_registry::EventsRegistry;
+pub(crate) use intersections::to_segments_intersections;
+pub(crate) use sweep::{Intersection, Sweep};
+pub(crate) use validation::{
+    is_contour_valid, is_multipolygon_valid, is_multisegment_valid,
+    is_polygon_valid, to_contour_validity_errors,
+    to_multipolygon_validity_errors, to_multisegment_validity_errors,
+    to_polygon_validity_errors,
 };
 
 mod base;
 mod event;
 mod events_queue_key;
 mod events_registry;
+mod intersections;
 mod sweep;
 mod sweep_line_key;
+mod validation;
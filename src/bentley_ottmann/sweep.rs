@@ -40,14 +40,21 @@ impl<Point> Sweep<Point> {
     pub(super) fn get_segment_start(&self, segment_id: usize) -> &Point {
         self.events_registry.get_segment_start(segment_id)
     }
+
+    pub(super) fn to_min_collinear_segment_id(
+        &self,
+        segment_id: usize,
+    ) -> usize {
+        self.events_registry.to_min_collinear_segment_id(segment_id)
+    }
 }
 
 pub(crate) struct Intersection<Point> {
-    pub(super) first_segment_id: usize,
-    pub(super) second_segment_id: usize,
-    pub(super) relation: Relation,
-    pub(super) start: Point,
-    pub(super) end: Point,
+    pub(crate) first_segment_id: usize,
+    pub(crate) second_segment_id: usize,
+    pub(crate) relation: Relation,
+    pub(crate) start: Point,
+    pub(crate) end: Point,
 }
 
 impl<Point: Clone + Ord> Iterator for Sweep<Point>
@@ -0,0 +1,634 @@
+use std::collections::BTreeSet;
+
+use crate::bounded::Bounded;
+use crate::constants::{
+    MIN_CONTOUR_VERTICES_COUNT, MIN_MULTIPOLYGON_POLYGONS_COUNT,
+    MIN_MULTISEGMENT_SEGMENTS_COUNT,
+};
+use crate::operations::{
+    IntersectCrossingSegments, Orient, ToReversedSegments,
+};
+use crate::oriented::{Orientation, Oriented};
+use crate::relatable::Relation;
+use crate::relating::shaped::{relate_regions, Operation};
+use crate::sweeping::traits::{EventsQueue, SweepLine};
+use crate::traits::{
+    Contoural, Elemental, Iterable, Lengthsome, Multisegmental, Multivertexal,
+    Segmental,
+};
+use crate::validatable::ValidityError;
+
+use super::base::{
+    are_polygons_interiors_disjoint, find_cycle_closing_touch,
+    is_neighbour_segments_touch, to_borders_intersections,
+    to_boxes_ids_pairs_with_intersection,
+};
+use super::event::Event;
+use super::sweep::{Intersection, Sweep};
+
+pub(crate) fn is_contour_valid<Contour, Point: Clone + Ord, Scalar, Segment>(
+    contour: &Contour,
+) -> bool
+where
+    Sweep<Point>: Iterator<Item = Intersection<Point>>
+        + for<'a, 'b> From<&'a <&'b Contour as Multisegmental>::Segments>,
+    for<'a> &'a Contour:
+        Contoural<IndexVertex = Point, IndexSegment = Segment>,
+    for<'a> &'a Point: Elemental<Coordinate = &'a Scalar> + Orient,
+    for<'a> &'a Segment: Segmental<Endpoint = &'a Point>,
+{
+    validate_contour::<_, _, _, _, false>(contour).is_empty()
+}
+
+pub(crate) fn to_contour_validity_errors<
+    Contour,
+    Point: Clone + Ord,
+    Scalar,
+    Segment,
+>(
+    contour: &Contour,
+) -> Vec<ValidityError<Point>>
+where
+    Sweep<Point>: Iterator<Item = Intersection<Point>>
+        + for<'a, 'b> From<&'a <&'b Contour as Multisegmental>::Segments>,
+    for<'a> &'a Contour:
+        Contoural<IndexVertex = Point, IndexSegment = Segment>,
+    for<'a> &'a Point: Elemental<Coordinate = &'a Scalar> + Orient,
+    for<'a> &'a Segment: Segmental<Endpoint = &'a Point>,
+{
+    validate_contour::<_, _, _, _, true>(contour)
+}
+
+pub(crate) fn is_multipolygon_valid<
+    Border,
+    Point: Clone + Ord,
+    Scalar: Ord,
+    Segment: Clone + Segmental<Endpoint = Point>,
+>(
+    polygons_components: &[(&Border, Vec<&Border>)],
+) -> bool
+where
+    Operation<Point>: EventsQueue<Event = Event> + SweepLine<Event = Event>,
+    Sweep<Point>: Iterator<Item = Intersection<Point>>
+        + for<'a> From<&'a Vec<Segment>>
+        + for<'a, 'b> From<&'a <&'b Border as Multisegmental>::Segments>,
+    for<'a> &'a Border: Bounded<&'a Scalar>
+        + Contoural<IndexSegment = Segment, IndexVertex = Point>
+        + Oriented
+        + ToReversedSegments<Output = Vec<Segment>>,
+    for<'a> &'a Point: Elemental<Coordinate = &'a Scalar>
+        + IntersectCrossingSegments<Output = Point>
+        + Orient,
+    for<'a> &'a Segment: Segmental<Endpoint = &'a Point>,
+{
+    validate_multipolygon::<_, _, _, _, false>(polygons_components).is_empty()
+}
+
+pub(crate) fn to_multipolygon_validity_errors<
+    Border,
+    Point: Clone + Ord,
+    Scalar: Ord,
+    Segment: Clone + Segmental<Endpoint = Point>,
+>(
+    polygons_components: &[(&Border, Vec<&Border>)],
+) -> Vec<ValidityError<Point>>
+where
+    Operation<Point>: EventsQueue<Event = Event> + SweepLine<Event = Event>,
+    Sweep<Point>: Iterator<Item = Intersection<Point>>
+        + for<'a> From<&'a Vec<Segment>>
+        + for<'a, 'b> From<&'a <&'b Border as Multisegmental>::Segments>,
+    for<'a> &'a Border: Bounded<&'a Scalar>
+        + Contoural<IndexSegment = Segment, IndexVertex = Point>
+        + Oriented
+        + ToReversedSegments<Output = Vec<Segment>>,
+    for<'a> &'a Point: Elemental<Coordinate = &'a Scalar>
+        + IntersectCrossingSegments<Output = Point>
+        + Orient,
+    for<'a> &'a Segment: Segmental<Endpoint = &'a Point>,
+{
+    validate_multipolygon::<_, _, _, _, true>(polygons_components)
+}
+
+pub(crate) fn is_multisegment_valid<
+    Multisegment,
+    Point: Clone + Ord,
+    Segment,
+>(
+    multisegment: &Multisegment,
+) -> bool
+where
+    Sweep<Point>: for<'a, 'b> From<&'a <&'b Multisegment as Multisegmental>::Segments>
+        + Iterator<Item = Intersection<Point>>,
+    for<'a> &'a Multisegment: Multisegmental<IndexSegment = Segment>,
+    for<'a> &'a Segment: Segmental<Endpoint = &'a Point>,
+{
+    validate_multisegment::<_, _, _, false>(multisegment).is_empty()
+}
+
+pub(crate) fn to_multisegment_validity_errors<
+    Multisegment,
+    Point: Clone + Ord,
+    Segment,
+>(
+    multisegment: &Multisegment,
+) -> Vec<ValidityError<Point>>
+where
+    Sweep<Point>: for<'a, 'b> From<&'a <&'b Multisegment as Multisegmental>::Segments>
+        + Iterator<Item = Intersection<Point>>,
+    for<'a> &'a Multisegment: Multisegmental<IndexSegment = Segment>,
+    for<'a> &'a Segment: Segmental<Endpoint = &'a Point>,
+{
+    validate_multisegment::<_, _, _, true>(multisegment)
+}
+
+pub(crate) fn is_polygon_valid<
+    Border,
+    Point: Clone + Ord,
+    Scalar: Ord,
+    Segment: Clone + Segmental<Endpoint = Point>,
+>(
+    border: &Border,
+    holes: &[&Border],
+) -> bool
+where
+    Operation<Point>: EventsQueue<Event = Event> + SweepLine<Event = Event>,
+    Sweep<Point>: Iterator<Item = Intersection<Point>>
+        + for<'a> From<&'a Vec<Segment>>
+        + for<'a, 'b> From<&'a <&'b Border as Multisegmental>::Segments>,
+    for<'a> &'a Border: Bounded<&'a Scalar>
+        + Contoural<IndexSegment = Segment, IndexVertex = Point>
+        + Oriented
+        + ToReversedSegments<Output = Vec<Segment>>,
+    for<'a> &'a Point: Elemental<Coordinate = &'a Scalar>
+        + IntersectCrossingSegments<Output = Point>
+        + Orient,
+    for<'a> &'a Segment: Segmental<Endpoint = &'a Point>,
+{
+    validate_polygon::<_, _, _, _, false>(border, holes).is_empty()
+}
+
+pub(crate) fn to_polygon_validity_errors<
+    Border,
+    Point: Clone + Ord,
+    Scalar: Ord,
+    Segment: Clone + Segmental<Endpoint = Point>,
+>(
+    border: &Border,
+    holes: &[&Border],
+) -> Vec<ValidityError<Point>>
+where
+    Operation<Point>: EventsQueue<Event = Event> + SweepLine<Event = Event>,
+    Sweep<Point>: Iterator<Item = Intersection<Point>>
+        + for<'a> From<&'a Vec<Segment>>
+        + for<'a, 'b> From<&'a <&'b Border as Multisegmental>::Segments>,
+    for<'a> &'a Border: Bounded<&'a Scalar>
+        + Contoural<IndexSegment = Segment, IndexVertex = Point>
+        + Oriented
+        + ToReversedSegments<Output = Vec<Segment>>,
+    for<'a> &'a Point: Elemental<Coordinate = &'a Scalar>
+        + IntersectCrossingSegments<Output = Point>
+        + Orient,
+    for<'a> &'a Segment: Segmental<Endpoint = &'a Point>,
+{
+    validate_polygon::<_, _, _, _, true>(border, holes)
+}
+
+/// Collects validity errors of the contour,
+/// stopping at the first one unless exhaustive.
+fn validate_contour<
+    Contour,
+    Point: Clone + Ord,
+    Scalar,
+    Segment,
+    const IS_EXHAUSTIVE: bool,
+>(
+    contour: &Contour,
+) -> Vec<ValidityError<Point>>
+where
+    Sweep<Point>: Iterator<Item = Intersection<Point>>
+        + for<'a, 'b> From<&'a <&'b Contour as Multisegmental>::Segments>,
+    for<'a> &'a Contour:
+        Contoural<IndexVertex = Point, IndexSegment = Segment>,
+    for<'a> &'a Point: Elemental<Coordinate = &'a Scalar> + Orient,
+    for<'a> &'a Segment: Segmental<Endpoint = &'a Point>,
+{
+    let vertices = contour.vertices();
+    let vertices = vertices.iter().collect::<Vec<_>>();
+    if vertices.len() < MIN_CONTOUR_VERTICES_COUNT {
+        return vec![ValidityError::TooFewVertices {
+            vertices_count: vertices.len(),
+        }];
+    }
+    let segments = contour.segments();
+    let mut result = to_degenerate_segments_errors(&segments);
+    if !IS_EXHAUSTIVE && !result.is_empty() {
+        return result;
+    }
+    for vertex_id in 0..vertices.len() {
+        let vertices_ids = [
+            (vertex_id + vertices.len() - 2) % vertices.len(),
+            (vertex_id + vertices.len() - 1) % vertices.len(),
+            vertex_id,
+        ];
+        let [first_vertex, second_vertex, third_vertex] =
+            vertices_ids.map(|vertex_id| vertices[vertex_id]);
+        if first_vertex != second_vertex
+            && second_vertex != third_vertex
+            && first_vertex.orient(second_vertex, third_vertex)
+                == Orientation::Collinear
+        {
+            result.push(ValidityError::CollinearVertices {
+                vertices_ids,
+                vertices: [
+                    first_vertex.clone(),
+                    second_vertex.clone(),
+                    third_vertex.clone(),
+                ],
+            });
+            if !IS_EXHAUSTIVE {
+                return result;
+            }
+        }
+    }
+    if !result.is_empty() {
+        return result;
+    }
+    let mut intersections_keys = BTreeSet::new();
+    let mut neighbour_segments_touches_count = 0usize;
+    let mut sweep = Sweep::from(&segments);
+    while let Some(intersection) = sweep.next() {
+        if intersection.first_segment_id == intersection.second_segment_id {
+            continue;
+        }
+        if is_neighbour_segments_touch(&sweep, &intersection, segments.len()) {
+            neighbour_segments_touches_count += 1;
+        } else if intersections_keys.insert(to_intersection_key(
+            intersection.first_segment_id,
+            intersection.second_segment_id,
+            &intersection,
+        )) {
+            result.push(intersection_to_segments_error(intersection));
+            if !IS_EXHAUSTIVE {
+                return result;
+            }
+        }
+    }
+    if neighbour_segments_touches_count != segments.len() {
+        result.push(ValidityError::UntouchedNeighbourSegments {
+            touches_count: neighbour_segments_touches_count,
+            segments_count: segments.len(),
+        });
+    }
+    result
+}
+
+/// Collects validity errors of the multipolygon,
+/// stopping at the first one unless exhaustive.
+fn validate_multipolygon<
+    Border,
+    Point: Clone + Ord,
+    Scalar: Ord,
+    Segment: Clone + Segmental<Endpoint = Point>,
+    const IS_EXHAUSTIVE: bool,
+>(
+    polygons_components: &[(&Border, Vec<&Border>)],
+) -> Vec<ValidityError<Point>>
+where
+    Operation<Point>: EventsQueue<Event = Event> + SweepLine<Event = Event>,
+    Sweep<Point>: Iterator<Item = Intersection<Point>>
+        + for<'a> From<&'a Vec<Segment>>
+        + for<'a, 'b> From<&'a <&'b Border as Multisegmental>::Segments>,
+    for<'a> &'a Border: Bounded<&'a Scalar>
+        + Contoural<IndexSegment = Segment, IndexVertex = Point>
+        + Oriented
+        + ToReversedSegments<Output = Vec<Segment>>,
+    for<'a> &'a Point: Elemental<Coordinate = &'a Scalar>
+        + IntersectCrossingSegments<Output = Point>
+        + Orient,
+    for<'a> &'a Segment: Segmental<Endpoint = &'a Point>,
+{
+    if polygons_components.len() < MIN_MULTIPOLYGON_POLYGONS_COUNT {
+        return vec![ValidityError::TooFewPolygons {
+            polygons_count: polygons_components.len(),
+        }];
+    }
+    let mut result = Vec::new();
+    for (polygon_id, (border, holes)) in polygons_components.iter().enumerate()
+    {
+        result.extend(
+            validate_polygon::<_, _, _, _, IS_EXHAUSTIVE>(*border, holes)
+                .into_iter()
+                .map(|error| ValidityError::Polygon {
+                    polygon_id,
+                    error: Box::new(error),
+                }),
+        );
+        if !IS_EXHAUSTIVE && !result.is_empty() {
+            return result;
+        }
+    }
+    if !result.is_empty() {
+        return result;
+    }
+    let mut borders = Vec::<&Border>::new();
+    let mut borders_polygons_ids = Vec::<usize>::new();
+    for (polygon_id, (border, holes)) in polygons_components.iter().enumerate()
+    {
+        borders.push(border);
+        borders.extend(holes);
+        borders_polygons_ids
+            .extend(std::iter::repeat(polygon_id).take(holes.len() + 1));
+    }
+    let mut intersections_keys = BTreeSet::new();
+    for (first_border_id, second_border_id, intersection) in
+        to_borders_intersections(&borders)
+    {
+        let first_polygon_id = borders_polygons_ids[first_border_id];
+        let second_polygon_id = borders_polygons_ids[second_border_id];
+        if first_polygon_id != second_polygon_id
+            && intersection.relation != Relation::Touch
+            && intersections_keys.insert(to_intersection_key(
+                first_polygon_id,
+                second_polygon_id,
+                &intersection,
+            ))
+        {
+            result.push(ValidityError::PolygonsIntersection {
+                first_polygon_id: first_polygon_id.min(second_polygon_id),
+                second_polygon_id: first_polygon_id.max(second_polygon_id),
+                relation: intersection.relation,
+                start: intersection.start,
+                end: intersection.end,
+            });
+            if !IS_EXHAUSTIVE {
+                return result;
+            }
+        }
+    }
+    if !result.is_empty() {
+        return result;
+    }
+    let bounding_boxes = polygons_components
+        .iter()
+        .map(|(border, _)| border.to_bounding_box())
+        .collect::<Vec<_>>();
+    for (first_polygon_id, second_polygon_id) in
+        to_boxes_ids_pairs_with_intersection(&bounding_boxes)
+    {
+        let (first_border, first_holes) =
+            &polygons_components[first_polygon_id];
+        let (second_border, second_holes) =
+            &polygons_components[second_polygon_id];
+        if !are_polygons_interiors_disjoint(
+            (first_border, first_holes),
+            (second_border, second_holes),
+        ) {
+            result.push(ValidityError::PolygonsOverlap {
+                first_polygon_id: first_polygon_id.min(second_polygon_id),
+                second_polygon_id: first_polygon_id.max(second_polygon_id),
+            });
+            if !IS_EXHAUSTIVE {
+                break;
+            }
+        }
+    }
+    result
+}
+
+/// Collects validity errors of the multisegment,
+/// stopping at the first one unless exhaustive.
+fn validate_multisegment<
+    Multisegment,
+    Point: Clone + Ord,
+    Segment,
+    const IS_EXHAUSTIVE: bool,
+>(
+    multisegment: &Multisegment,
+) -> Vec<ValidityError<Point>>
+where
+    Sweep<Point>: for<'a, 'b> From<&'a <&'b Multisegment as Multisegmental>::Segments>
+        + Iterator<Item = Intersection<Point>>,
+    for<'a> &'a Multisegment: Multisegmental<IndexSegment = Segment>,
+    for<'a> &'a Segment: Segmental<Endpoint = &'a Point>,
+{
+    let segments = multisegment.segments();
+    if segments.len() < MIN_MULTISEGMENT_SEGMENTS_COUNT {
+        return vec![ValidityError::TooFewSegments {
+            segments_count: segments.len(),
+        }];
+    }
+    let mut result = to_degenerate_segments_errors(&segments);
+    if !result.is_empty() {
+        return result;
+    }
+    let mut intersections_keys = BTreeSet::new();
+    for intersection in Sweep::from(&segments) {
+        if intersection.first_segment_id != intersection.second_segment_id
+            && intersection.relation != Relation::Touch
+            && intersections_keys.insert(to_intersection_key(
+                intersection.first_segment_id,
+                intersection.second_segment_id,
+                &intersection,
+            ))
+        {
+            result.push(intersection_to_segments_error(intersection));
+            if !IS_EXHAUSTIVE {
+                break;
+            }
+        }
+    }
+    result
+}
+
+/// Collects validity errors of the polygon,
+/// stopping at the first one unless exhaustive.
+fn validate_polygon<
+    Border,
+    Point: Clone + Ord,
+    Scalar: Ord,
+    Segment: Clone + Segmental<Endpoint = Point>,
+    const IS_EXHAUSTIVE: bool,
+>(
+    border: &Border,
+    holes: &[&Border],
+) -> Vec<ValidityError<Point>>
+where
+    Operation<Point>: EventsQueue<Event = Event> + SweepLine<Event = Event>,
+    Sweep<Point>: Iterator<Item = Intersection<Point>>
+        + for<'a> From<&'a Vec<Segment>>
+        + for<'a, 'b> From<&'a <&'b Border as Multisegmental>::Segments>,
+    for<'a> &'a Border: Bounded<&'a Scalar>
+        + Contoural<IndexSegment = Segment, IndexVertex = Point>
+        + Oriented
+        + ToReversedSegments<Output = Vec<Segment>>,
+    for<'a> &'a Point: Elemental<Coordinate = &'a Scalar>
+        + IntersectCrossingSegments<Output = Point>
+        + Orient,
+    for<'a> &'a Segment: Segmental<Endpoint = &'a Point>,
+{
+    let mut result = validate_contour::<_, _, _, _, IS_EXHAUSTIVE>(border)
+        .into_iter()
+        .map(|error| ValidityError::Border(Box::new(error)))
+        .collect::<Vec<_>>();
+    if !IS_EXHAUSTIVE && !result.is_empty() {
+        return result;
+    }
+    for (hole_id, hole) in holes.iter().enumerate() {
+        result.extend(
+            validate_contour::<_, _, _, _, IS_EXHAUSTIVE>(*hole)
+                .into_iter()
+                .map(|error| ValidityError::Hole {
+                    hole_id,
+                    error: Box::new(error),
+                }),
+        );
+        if !IS_EXHAUSTIVE && !result.is_empty() {
+            return result;
+        }
+    }
+    if !result.is_empty() {
+        return result;
+    }
+    let mut borders = Vec::with_capacity(holes.len() + 1);
+    borders.push(border);
+    borders.extend(holes);
+    let mut intersections_keys = BTreeSet::new();
+    let mut touches = Vec::new();
+    for (first_border_id, second_border_id, intersection) in
+        to_borders_intersections(&borders)
+    {
+        if intersection.relation == Relation::Touch {
+            touches.push((
+                first_border_id,
+                second_border_id,
+                intersection.start,
+            ));
+            continue;
+        } else if !intersections_keys.insert(to_intersection_key(
+            first_border_id,
+            second_border_id,
+            &intersection,
+        )) {
+            continue;
+        } else if first_border_id == 0 || second_border_id == 0 {
+            result.push(ValidityError::HoleBorderIntersection {
+                hole_id: first_border_id.max(second_border_id) - 1,
+                relation: intersection.relation,
+                start: intersection.start,
+                end: intersection.end,
+            });
+        } else {
+            result.push(ValidityError::HolesIntersection {
+                first_hole_id: first_border_id.min(second_border_id) - 1,
+                second_hole_id: first_border_id.max(second_border_id) - 1,
+                relation: intersection.relation,
+                start: intersection.start,
+                end: intersection.end,
+            });
+        }
+        if !IS_EXHAUSTIVE {
+            return result;
+        }
+    }
+    if !result.is_empty() {
+        return result;
+    }
+    for (hole_id, hole) in holes.iter().enumerate() {
+        if !matches!(
+            relate_regions(*hole, border),
+            Relation::Within | Relation::Enclosed
+        ) {
+            result.push(ValidityError::HoleOutsideBorder { hole_id });
+            if !IS_EXHAUSTIVE {
+                return result;
+            }
+        }
+    }
+    let holes_bounding_boxes = holes
+        .iter()
+        .map(|hole| hole.to_bounding_box())
+        .collect::<Vec<_>>();
+    for (first_hole_id, second_hole_id) in
+        to_boxes_ids_pairs_with_intersection(&holes_bounding_boxes)
+    {
+        if !matches!(
+            relate_regions(holes[first_hole_id], holes[second_hole_id]),
+            Relation::Disjoint | Relation::Touch
+        ) {
+            result.push(ValidityError::HolesOverlap {
+                first_hole_id: first_hole_id.min(second_hole_id),
+                second_hole_id: first_hole_id.max(second_hole_id),
+            });
+            if !IS_EXHAUSTIVE {
+                return result;
+            }
+        }
+    }
+    if !result.is_empty() {
+        return result;
+    }
+    if let Some((first_border_id, second_border_id, point)) =
+        find_cycle_closing_touch(borders.len(), touches)
+    {
+        result.push(ValidityError::DisconnectedInterior {
+            hole_id: first_border_id.max(second_border_id) - 1,
+            point,
+        });
+    }
+    result
+}
+
+fn intersection_to_segments_error<Point>(
+    intersection: Intersection<Point>,
+) -> ValidityError<Point> {
+    ValidityError::SegmentsIntersection {
+        first_segment_id: intersection
+            .first_segment_id
+            .min(intersection.second_segment_id),
+        second_segment_id: intersection
+            .first_segment_id
+            .max(intersection.second_segment_id),
+        relation: intersection.relation,
+        start: intersection.start,
+        end: intersection.end,
+    }
+}
+
+fn to_degenerate_segments_errors<
+    Point: Clone + PartialEq,
+    Segment,
+    Segments: Iterable<Item = Segment>,
+>(
+    segments: &Segments,
+) -> Vec<ValidityError<Point>>
+where
+    for<'a> &'a Segment: Segmental<Endpoint = &'a Point>,
+{
+    segments
+        .iter()
+        .enumerate()
+        .filter_map(|(segment_id, segment)| {
+            let (start, end) = segment.endpoints();
+            (start == end).then(|| ValidityError::DegenerateSegment {
+                segment_id,
+                point: start.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Identifies intersection between components with given ids
+/// regardless of their order,
+/// since sweep can report the same intersection several times.
+fn to_intersection_key<Point: Clone>(
+    first_id: usize,
+    second_id: usize,
+    intersection: &Intersection<Point>,
+) -> (usize, usize, Point, Point) {
+    (
+        first_id.min(second_id),
+        first_id.max(second_id),
+        intersection.start.clone(),
+        intersection.end.clone(),
+    )
+}
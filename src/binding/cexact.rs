@@ -5,11 +5,16 @@ use std::ops::{Add, Mul, Sub};
 use rithm::{big_int, fraction};
 use traiter::numbers::{Endianness, FromBytes, Sign, Signed, ToBytes, Zero};
 
+use crate::distance::{
+    IsWithinSquaredDistance, NearestPoints, SquaredDistance,
+};
+use crate::intersections::SegmentsIntersection;
 use crate::locatable::Location;
 use crate::operations::{
     CrossMultiply, DotMultiply, LocatePointInPointPointPointCircle, Square,
     SquaredMetric,
 };
+use crate::relatable::Relation;
 use crate::traits::{
     Elemental, Multipolygonal, Multisegmental, Multivertexal, Polygonal,
 };
@@ -26,6 +31,7 @@ use super::impl_polygon_wrapper::impl_polygon_wrapper;
 use super::impl_py_sequence::impl_py_sequence;
 use super::impl_segment_wrapper::impl_segment_wrapper;
 use super::impl_trapezoidation_wrapper::impl_trapezoidation_wrapper;
+use super::impl_voronoi_diagram_wrapper::impl_voronoi_diagram_wrapper;
 use super::reference;
 use super::traits::{TryFromPyAny, TryToPyAny};
 
@@ -45,6 +51,12 @@ fn _cexact(
     module.add_class::<PyPoint>()?;
     module.add_class::<PyPolygon>()?;
     module.add_class::<PySegment>()?;
+    module.add_class::<PyVoronoiDiagram>()?;
+    module.add_function(pyo3::wrap_pyfunction!(convex_hull, module)?)?;
+    module.add_function(pyo3::wrap_pyfunction!(
+        segments_intersections,
+        module
+    )?)?;
     pyo3::types::PySequence::register::<PyContourSegments>(py)?;
     pyo3::types::PySequence::register::<PyContourVertices>(py)?;
     pyo3::types::PySequence::register::<PyMultipolygonPolygons>(py)?;
@@ -77,12 +89,6 @@ impl From<Vec<Polygon>> for PyMultipolygon {
     }
 }
 
-impl From<Vec<Point>> for PyContour {
-    fn from(value: Vec<Point>) -> Self {
-        Self(Contour::new(value))
-    }
-}
-
 impl From<Vec<Segment>> for PyMultisegment {
     fn from(value: Vec<Segment>) -> Self {
         Self(Multisegment::new(value))
@@ -270,6 +276,124 @@ impl TryToPyAny for &Fraction {
     }
 }
 
+impl TryToPyAny for ValidityError {
+    fn try_to_py_any(
+        self,
+        py: pyo3::Python<'_>,
+    ) -> pyo3::PyResult<&'_ pyo3::PyAny> {
+        let module = py.import("rene")?;
+        match self {
+            ValidityError::Border(error) => module
+                .getattr(pyo3::intern!(py, "BorderError"))?
+                .call1((error.try_to_py_any(py)?,)),
+            ValidityError::CollinearVertices {
+                vertices_ids,
+                vertices,
+            } => module
+                .getattr(pyo3::intern!(py, "CollinearVerticesError"))?
+                .call1((
+                    pyo3::types::PyTuple::new(py, vertices_ids),
+                    pyo3::types::PyTuple::new(py, vertices),
+                )),
+            ValidityError::DegenerateSegment { segment_id, point } => module
+                .getattr(pyo3::intern!(py, "DegenerateSegmentError"))?
+                .call1((segment_id, point)),
+            ValidityError::DisconnectedInterior { hole_id, point } => module
+                .getattr(pyo3::intern!(py, "DisconnectedInteriorError"))?
+                .call1((hole_id, point)),
+            ValidityError::Hole { hole_id, error } => module
+                .getattr(pyo3::intern!(py, "HoleError"))?
+                .call1((hole_id, error.try_to_py_any(py)?)),
+            ValidityError::HoleBorderIntersection {
+                hole_id,
+                relation,
+                start,
+                end,
+            } => module
+                .getattr(pyo3::intern!(py, "HoleBorderIntersectionError"))?
+                .call1((hole_id, relation.try_to_py_any(py)?, start, end)),
+            ValidityError::HoleOutsideBorder { hole_id } => module
+                .getattr(pyo3::intern!(py, "HoleOutsideBorderError"))?
+                .call1((hole_id,)),
+            ValidityError::HolesIntersection {
+                first_hole_id,
+                second_hole_id,
+                relation,
+                start,
+                end,
+            } => module
+                .getattr(pyo3::intern!(py, "HolesIntersectionError"))?
+                .call1((
+                    first_hole_id,
+                    second_hole_id,
+                    relation.try_to_py_any(py)?,
+                    start,
+                    end,
+                )),
+            ValidityError::HolesOverlap {
+                first_hole_id,
+                second_hole_id,
+            } => module
+                .getattr(pyo3::intern!(py, "HolesOverlapError"))?
+                .call1((first_hole_id, second_hole_id)),
+            ValidityError::Polygon { polygon_id, error } => module
+                .getattr(pyo3::intern!(py, "PolygonError"))?
+                .call1((polygon_id, error.try_to_py_any(py)?)),
+            ValidityError::PolygonsIntersection {
+                first_polygon_id,
+                second_polygon_id,
+                relation,
+                start,
+                end,
+            } => module
+                .getattr(pyo3::intern!(py, "PolygonsIntersectionError"))?
+                .call1((
+                    first_polygon_id,
+                    second_polygon_id,
+                    relation.try_to_py_any(py)?,
+                    start,
+                    end,
+                )),
+            ValidityError::PolygonsOverlap {
+                first_polygon_id,
+                second_polygon_id,
+            } => module
+                .getattr(pyo3::intern!(py, "PolygonsOverlapError"))?
+                .call1((first_polygon_id, second_polygon_id)),
+            ValidityError::SegmentsIntersection {
+                first_segment_id,
+                second_segment_id,
+                relation,
+                start,
+                end,
+            } => module
+                .getattr(pyo3::intern!(py, "SegmentsIntersectionError"))?
+                .call1((
+                    first_segment_id,
+                    second_segment_id,
+                    relation.try_to_py_any(py)?,
+                    start,
+                    end,
+                )),
+            ValidityError::TooFewPolygons { polygons_count } => module
+                .getattr(pyo3::intern!(py, "TooFewPolygonsError"))?
+                .call1((polygons_count,)),
+            ValidityError::TooFewSegments { segments_count } => module
+                .getattr(pyo3::intern!(py, "TooFewSegmentsError"))?
+                .call1((segments_count,)),
+            ValidityError::TooFewVertices { vertices_count } => module
+                .getattr(pyo3::intern!(py, "TooFewVerticesError"))?
+                .call1((vertices_count,)),
+            ValidityError::UntouchedNeighbourSegments {
+                touches_count,
+                segments_count,
+            } => module
+                .getattr(pyo3::intern!(py, "UntouchedNeighbourSegmentsError"))?
+                .call1((touches_count, segments_count)),
+        }
+    }
+}
+
 impl pyo3::ToPyObject for Contour {
     fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
         pyo3::IntoPy::into_py(self.clone(), py)
@@ -330,6 +454,19 @@ impl pyo3::IntoPy<pyo3::PyObject> for Contour {
     }
 }
 
+impl pyo3::IntoPy<pyo3::PyObject> for ConvexHull {
+    fn into_py(self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        match self {
+            ConvexHull::Contour(contour) => pyo3::IntoPy::into_py(contour, py),
+            ConvexHull::Empty(empty) => {
+                pyo3::IntoPy::into_py(PyEmpty(empty), py)
+            }
+            ConvexHull::Point(point) => pyo3::IntoPy::into_py(point, py),
+            ConvexHull::Segment(segment) => pyo3::IntoPy::into_py(segment, py),
+        }
+    }
+}
+
 impl pyo3::IntoPy<pyo3::PyObject> for Multipolygon {
     fn into_py(self, py: pyo3::Python<'_>) -> pyo3::PyObject {
         pyo3::IntoPy::into_py(PyMultipolygon(self), py)
@@ -358,6 +495,7 @@ type Box = crate::bounded::Box<Fraction>;
 type ConstrainedDelaunayTriangulation =
     crate::triangulation::ConstrainedDelaunayTriangulation<Point>;
 type Contour = crate::geometries::Contour<Fraction>;
+type ConvexHull = crate::convex_hull::ConvexHull<Fraction>;
 type DelaunayTriangulation =
     crate::triangulation::DelaunayTriangulation<Point>;
 type Empty = crate::geometries::Empty;
@@ -367,6 +505,8 @@ type Point = crate::geometries::Point<Fraction>;
 type Polygon = crate::geometries::Polygon<Fraction>;
 type Segment = crate::geometries::Segment<Fraction>;
 type Trapezoidation = crate::seidel::Trapezoidation<Point>;
+type ValidityError = crate::validatable::ValidityError<Point>;
+type VoronoiDiagram = crate::triangulation::VoronoiDiagram<Fraction>;
 
 #[pyo3::prelude::pyclass(name = "Box", module = "rene.exact")]
 #[derive(Clone)]
@@ -418,6 +558,10 @@ struct PySegment(Segment);
 #[derive(Clone)]
 struct PyTrapezoidation(Trapezoidation);
 
+#[pyo3::prelude::pyclass(name = "VoronoiDiagram", module = "rene.exact")]
+#[derive(Clone)]
+struct PyVoronoiDiagram(VoronoiDiagram);
+
 impl_box_wrapper!();
 impl_constrained_delaunay_triangulation_wrapper!();
 impl_contour_wrapper!();
@@ -429,6 +573,7 @@ impl_point_wrapper!();
 impl_polygon_wrapper!();
 impl_segment_wrapper!();
 impl_trapezoidation_wrapper!();
+impl_voronoi_diagram_wrapper!();
 
 type PyContourReference = reference::Reference<PyContour>;
 type PyMultisegmentReference = reference::Reference<PyMultisegment>;
@@ -526,6 +671,226 @@ impl_py_sequence!(
 
 impl_py_sequence!(PyPolygonHoles, polygon, contour, holes, PyContour, Contour);
 
+#[pyo3::prelude::pyfunction]
+#[pyo3(signature = (points, /))]
+fn convex_hull(
+    points: &pyo3::types::PySequence,
+    py: pyo3::Python,
+) -> pyo3::PyResult<pyo3::PyObject> {
+    Ok(pyo3::IntoPy::into_py(
+        crate::convex_hull::ToConvexHull::<Fraction>::to_convex_hull(
+            super::conversion::extract_from_py_sequence::<Point, PyPoint>(
+                points,
+            )?
+            .as_slice(),
+        ),
+        py,
+    ))
+}
+
+#[pyo3::prelude::pyfunction]
+#[pyo3(signature = (segments, /))]
+fn segments_intersections(
+    segments: &pyo3::types::PySequence,
+    py: pyo3::Python,
+) -> pyo3::PyResult<Vec<pyo3::PyObject>> {
+    crate::intersections::SegmentsIntersections::<Fraction>::segments_intersections(
+        super::conversion::extract_from_py_sequence::<Segment, PySegment>(
+            segments,
+        )?
+        .as_slice(),
+    )
+    .map(|(first_segment_id, second_segment_id, intersection)| {
+        let (relation, intersection) = match intersection {
+            SegmentsIntersection::Cross(point) => {
+                (Relation::Cross, pyo3::IntoPy::into_py(point, py))
+            }
+            SegmentsIntersection::Overlap(segment) => {
+                (Relation::Overlap, pyo3::IntoPy::into_py(segment, py))
+            }
+            SegmentsIntersection::Touch(point) => {
+                (Relation::Touch, pyo3::IntoPy::into_py(point, py))
+            }
+        };
+        Ok(pyo3::IntoPy::into_py(
+            (
+                first_segment_id,
+                second_segment_id,
+                relation.try_to_py_any(py)?,
+                intersection,
+            ),
+            py,
+        ))
+    })
+    .collect()
+}
+
+fn is_within_squared_distance<Geometry>(
+    geometry: &Geometry,
+    other: &pyo3::PyAny,
+    squared_threshold: &pyo3::PyAny,
+    py: pyo3::Python,
+) -> pyo3::PyResult<bool>
+where
+    for<'a> &'a Geometry: IsWithinSquaredDistance<Fraction, &'a Contour>
+        + IsWithinSquaredDistance<Fraction, &'a Multipolygon>
+        + IsWithinSquaredDistance<Fraction, &'a Multisegment>
+        + IsWithinSquaredDistance<Fraction, &'a Point>
+        + IsWithinSquaredDistance<Fraction, &'a Polygon>
+        + IsWithinSquaredDistance<Fraction, &'a Segment>,
+{
+    let squared_threshold = Fraction::try_from_py_any(squared_threshold, py)?;
+    if other.is_instance_of::<PyContour>() {
+        Ok(geometry.is_within_squared_distance(
+            &other.extract::<pyo3::PyRef<PyContour>>()?.0,
+            squared_threshold,
+        ))
+    } else if other.is_instance_of::<PyMultipolygon>() {
+        Ok(geometry.is_within_squared_distance(
+            &other.extract::<pyo3::PyRef<PyMultipolygon>>()?.0,
+            squared_threshold,
+        ))
+    } else if other.is_instance_of::<PyMultisegment>() {
+        Ok(geometry.is_within_squared_distance(
+            &other.extract::<pyo3::PyRef<PyMultisegment>>()?.0,
+            squared_threshold,
+        ))
+    } else if other.is_instance_of::<PyPoint>() {
+        Ok(geometry.is_within_squared_distance(
+            &other.extract::<pyo3::PyRef<PyPoint>>()?.0,
+            squared_threshold,
+        ))
+    } else if other.is_instance_of::<PyPolygon>() {
+        Ok(geometry.is_within_squared_distance(
+            &other.extract::<pyo3::PyRef<PyPolygon>>()?.0,
+            squared_threshold,
+        ))
+    } else if other.is_instance_of::<PySegment>() {
+        Ok(geometry.is_within_squared_distance(
+            &other.extract::<pyo3::PyRef<PySegment>>()?.0,
+            squared_threshold,
+        ))
+    } else {
+        Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "Expected non-empty geometry, but got {}.",
+            other.get_type().repr()?
+        )))
+    }
+}
+
+fn to_nearest_points<Geometry>(
+    geometry: &Geometry,
+    other: &pyo3::PyAny,
+) -> pyo3::PyResult<(Point, Point)>
+where
+    for<'a> &'a Geometry: NearestPoints<&'a Contour, Output = (Point, Point)>
+        + NearestPoints<&'a Multipolygon, Output = (Point, Point)>
+        + NearestPoints<&'a Multisegment, Output = (Point, Point)>
+        + NearestPoints<&'a Point, Output = (Point, Point)>
+        + NearestPoints<&'a Polygon, Output = (Point, Point)>
+        + NearestPoints<&'a Segment, Output = (Point, Point)>,
+{
+    if other.is_instance_of::<PyContour>() {
+        Ok(geometry
+            .nearest_points(&other.extract::<pyo3::PyRef<PyContour>>()?.0))
+    } else if other.is_instance_of::<PyMultipolygon>() {
+        Ok(geometry.nearest_points(
+            &other.extract::<pyo3::PyRef<PyMultipolygon>>()?.0,
+        ))
+    } else if other.is_instance_of::<PyMultisegment>() {
+        Ok(geometry.nearest_points(
+            &other.extract::<pyo3::PyRef<PyMultisegment>>()?.0,
+        ))
+    } else if other.is_instance_of::<PyPoint>() {
+        Ok(geometry
+            .nearest_points(&other.extract::<pyo3::PyRef<PyPoint>>()?.0))
+    } else if other.is_instance_of::<PyPolygon>() {
+        Ok(geometry
+            .nearest_points(&other.extract::<pyo3::PyRef<PyPolygon>>()?.0))
+    } else if other.is_instance_of::<PySegment>() {
+        Ok(geometry
+            .nearest_points(&other.extract::<pyo3::PyRef<PySegment>>()?.0))
+    } else {
+        Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "Expected non-empty geometry, but got {}.",
+            other.get_type().repr()?
+        )))
+    }
+}
+
+fn to_squared_distance<'a, Geometry>(
+    geometry: &Geometry,
+    other: &pyo3::PyAny,
+    py: pyo3::Python<'a>,
+) -> pyo3::PyResult<&'a pyo3::PyAny>
+where
+    for<'b> &'b Geometry: SquaredDistance<&'b Contour, Output = Fraction>
+        + SquaredDistance<&'b Multipolygon, Output = Fraction>
+        + SquaredDistance<&'b Multisegment, Output = Fraction>
+        + SquaredDistance<&'b Point, Output = Fraction>
+        + SquaredDistance<&'b Polygon, Output = Fraction>
+        + SquaredDistance<&'b Segment, Output = Fraction>,
+{
+    let squared_distance = if other.is_instance_of::<PyContour>() {
+        geometry
+            .squared_distance_to(&other.extract::<pyo3::PyRef<PyContour>>()?.0)
+    } else if other.is_instance_of::<PyMultipolygon>() {
+        geometry.squared_distance_to(
+            &other.extract::<pyo3::PyRef<PyMultipolygon>>()?.0,
+        )
+    } else if other.is_instance_of::<PyMultisegment>() {
+        geometry.squared_distance_to(
+            &other.extract::<pyo3::PyRef<PyMultisegment>>()?.0,
+        )
+    } else if other.is_instance_of::<PyPoint>() {
+        geometry
+            .squared_distance_to(&other.extract::<pyo3::PyRef<PyPoint>>()?.0)
+    } else if other.is_instance_of::<PyPolygon>() {
+        geometry
+            .squared_distance_to(&other.extract::<pyo3::PyRef<PyPolygon>>()?.0)
+    } else if other.is_instance_of::<PySegment>() {
+        geometry
+            .squared_distance_to(&other.extract::<pyo3::PyRef<PySegment>>()?.0)
+    } else {
+        return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "Expected non-empty geometry, but got {}.",
+            other.get_type().repr()?
+        )));
+    };
+    squared_distance.try_to_py_any(py)
+}
+
+fn try_validity_to_py_errors<'a>(
+    validity: Result<(), Vec<ValidityError>>,
+    py: pyo3::Python<'a>,
+) -> pyo3::PyResult<Vec<&'a pyo3::PyAny>> {
+    validity.map_or_else(
+        |errors| {
+            errors
+                .into_iter()
+                .map(|error| error.try_to_py_any(py))
+                .collect()
+        },
+        |()| Ok(Vec::new()),
+    )
+}
+
+fn try_validity_to_py_result(
+    validity: Result<(), Vec<ValidityError>>,
+    py: pyo3::Python,
+) -> pyo3::PyResult<()> {
+    match validity {
+        Ok(()) => Ok(()),
+        Err(errors) => Err(pyo3::PyErr::from_value(
+            errors
+                .into_iter()
+                .next()
+                .expect("Invalid geometry should have at least one error.")
+                .try_to_py_any(py)?,
+        )),
+    }
+}
+
 fn big_int_to_py_long(value: &BigInt) -> pyo3::PyObject {
     let buffer = value.to_bytes(Endianness::Little);
     pyo3::Python::with_gil(|py| unsafe {
@@ -1,4 +1,4 @@
-use pyo3::exceptions::PyValueError;
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::sync::GILOnceCell;
 use pyo3::type_object::PyTypeInfo;
 use pyo3::types::{PyModule, PyTuple};
@@ -14,11 +14,13 @@ use crate::constants::{
 use crate::locatable::Location;
 use crate::oriented::Orientation;
 use crate::relatable::Relation;
+use crate::validatable::FillRule;
 
-use super::traits::TryToPyAny;
+use super::traits::{TryFromPyAny, TryToPyAny};
 
 #[pymodule]
 fn _crene(_py: Python, module: &PyModule) -> PyResult<()> {
+    module.add_class::<PyFillRule>()?;
     module.add_class::<PyLocation>()?;
     module.add_class::<PyOrientation>()?;
     module.add_class::<PyRelation>()?;
@@ -46,6 +48,29 @@ impl ToPyObject for Relation {
     }
 }
 
+impl TryFromPyAny for FillRule {
+    fn try_from_py_any(value: &PyAny, py: Python) -> PyResult<Self> {
+        static FILL_RULE_CLS: GILOnceCell<PyObject> = GILOnceCell::new();
+        let cls = FILL_RULE_CLS.get_or_try_init(py, || {
+            py.import("rene")?
+                .getattr(intern!(py, "FillRule"))
+                .map(|value| IntoPy::into_py(value, py))
+        })?;
+        if value.is(cls.getattr(py, intern!(py, "EVEN_ODD"))?.as_ref(py)) {
+            Ok(FillRule::EvenOdd)
+        } else if value
+            .is(cls.getattr(py, intern!(py, "NON_ZERO"))?.as_ref(py))
+        {
+            Ok(FillRule::NonZero)
+        } else {
+            Err(PyTypeError::new_err(format!(
+                "Expected fill rule, but got {}.",
+                value.repr()?
+            )))
+        }
+    }
+}
+
 impl TryToPyAny for Location {
     fn try_to_py_any(self, py: Python) -> PyResult<&PyAny> {
         static LOCATION_CLS: GILOnceCell<PyObject> = GILOnceCell::new();
@@ -123,6 +148,9 @@ impl TryToPyAny for Relation {
     }
 }
 
+#[pyclass(name = "FillRule", module = "rene")]
+struct PyFillRule(FillRule);
+
 #[pyclass(name = "Location", module = "rene")]
 struct PyLocation(Location);
 
@@ -132,6 +160,26 @@ struct PyOrientation(Orientation);
 #[pyclass(name = "Relation", module = "rene")]
 struct PyRelation(Relation);
 
+#[pymethods]
+impl PyFillRule {
+    #[classattr]
+    const EVEN_ODD: PyFillRule = PyFillRule(FillRule::EvenOdd);
+
+    #[classattr]
+    const NON_ZERO: PyFillRule = PyFillRule(FillRule::NonZero);
+
+    fn __repr__(&self) -> String {
+        format!(
+            "{}.{}",
+            Self::NAME,
+            match self.0 {
+                FillRule::EvenOdd => "EVEN_ODD",
+                FillRule::NonZero => "NON_ZERO",
+            }
+        )
+    }
+}
+
 #[pymethods]
 impl PyLocation {
     #[classattr]
@@ -19,6 +19,17 @@ macro_rules! impl_box_wrapper {
                 )))
             }
 
+            #[getter]
+            fn area<'a>(
+                &self,
+                py: pyo3::Python<'a>,
+            ) -> pyo3::PyResult<&'a pyo3::PyAny> {
+                TryToPyAny::try_to_py_any(
+                    &crate::traits::Area::<Fraction>::area(&self.0),
+                    py,
+                )
+            }
+
             #[getter]
             fn max_x<'a>(
                 &self,
@@ -51,6 +62,15 @@ macro_rules! impl_box_wrapper {
                 TryToPyAny::try_to_py_any(self.0.get_min_y(), py)
             }
 
+            fn convex_hull(&self, py: pyo3::Python) -> pyo3::PyObject {
+                pyo3::IntoPy::into_py(
+                    crate::convex_hull::ToConvexHull::<Fraction>::to_convex_hull(
+                        &self.0,
+                    ),
+                    py,
+                )
+            }
+
             #[pyo3(signature = (other, /))]
             fn covers(&self, other: &Self) -> bool {
                 crate::relatable::Relatable::covers(&self.0, &other.0)
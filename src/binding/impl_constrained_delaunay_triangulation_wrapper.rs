@@ -2,6 +2,17 @@ macro_rules! impl_constrained_delaunay_triangulation_wrapper {
     () => {
         #[pyo3::prelude::pymethods]
         impl PyConstrainedDelaunayTriangulation {
+            #[classmethod]
+            #[pyo3(signature = (multipolygon, /))]
+            fn from_multipolygon(
+                _: &pyo3::types::PyType,
+                multipolygon: &PyMultipolygon,
+            ) -> Self {
+                PyConstrainedDelaunayTriangulation(
+                    ConstrainedDelaunayTriangulation::from(&multipolygon.0),
+                )
+            }
+
             #[classmethod]
             #[pyo3(signature = (polygon, /))]
             fn from_polygon(
@@ -23,20 +34,12 @@ macro_rules! impl_constrained_delaunay_triangulation_wrapper {
             .cloned()
             .collect(),
         )
+        .map(PyContour)
             }
 
             #[getter]
             fn triangles(&self) -> Vec<Contour> {
-                self.0
-                    .to_triangles_vertices()
-                    .map(|(first, second, third)| {
-                        Contour::from([
-                            first.clone(),
-                            second.clone(),
-                            third.clone(),
-                        ])
-                    })
-                    .collect()
+                self.0.to_triangles()
             }
 
             fn __bool__(&self) -> bool {
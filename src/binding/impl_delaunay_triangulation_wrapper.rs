@@ -16,6 +16,25 @@ macro_rules! impl_delaunay_triangulation_wrapper {
                 )))
             }
 
+            #[pyo3(signature = (squared_alpha, /))]
+            fn alpha_shape(
+                &self,
+                squared_alpha: &pyo3::PyAny,
+                py: pyo3::Python,
+            ) -> pyo3::PyResult<pyo3::PyObject> {
+                let polygons = self
+                    .0
+                    .to_alpha_shape(&TryFromPyAny::try_from_py_any(
+                        squared_alpha,
+                        py,
+                    )?);
+                Ok(super::unpacking::unpack_maybe_empty_polygons::<
+                    PyEmpty,
+                    PyMultipolygon,
+                    Polygon,
+                >(polygons, py))
+            }
+
             #[getter]
             fn border(&self) -> pyo3::PyResult<PyContour> {
                 super::packing::try_pack_vertices(
@@ -26,20 +45,12 @@ macro_rules! impl_delaunay_triangulation_wrapper {
             .cloned()
             .collect(),
         )
+        .map(PyContour)
             }
 
             #[getter]
             fn triangles(&self) -> Vec<Contour> {
-                self.0
-                    .iter_triangles_vertices()
-                    .map(|(first, second, third)| {
-                        Contour::from([
-                            first.clone(),
-                            second.clone(),
-                            third.clone(),
-                        ])
-                    })
-                    .collect()
+                self.0.to_triangles()
             }
 
             fn __bool__(&self) -> bool {
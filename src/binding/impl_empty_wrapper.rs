@@ -7,6 +7,26 @@ macro_rules! impl_empty_wrapper {
                 PyEmpty(Empty::new())
             }
 
+            #[getter]
+            fn area<'a>(
+                &self,
+                py: pyo3::Python<'a>,
+            ) -> pyo3::PyResult<&'a pyo3::PyAny> {
+                TryToPyAny::try_to_py_any(
+                    &crate::traits::Area::<Fraction>::area(&self.0),
+                    py,
+                )
+            }
+
+            fn convex_hull(&self, py: pyo3::Python) -> pyo3::PyObject {
+                pyo3::IntoPy::into_py(
+                    crate::convex_hull::ToConvexHull::<Fraction>::to_convex_hull(
+                        &self.0,
+                    ),
+                    py,
+                )
+            }
+
             #[pyo3(signature = (point, /))]
             fn locate<'a>(
                 &self,
@@ -13,6 +13,18 @@ macro_rules! impl_multipolygon_wrapper {
                         PyPolygon,
                     >(polygons)?,
                 )
+                .map(PyMultipolygon)
+            }
+
+            #[getter]
+            fn area<'a>(
+                &self,
+                py: pyo3::Python<'a>,
+            ) -> pyo3::PyResult<&'a pyo3::PyAny> {
+                TryToPyAny::try_to_py_any(
+                    &crate::traits::Area::<Fraction>::area(&self.0),
+                    py,
+                )
             }
 
             #[getter]
@@ -20,6 +32,11 @@ macro_rules! impl_multipolygon_wrapper {
                 crate::bounded::Bounded::to_bounding_box(&self.0).cloned()
             }
 
+            #[getter]
+            fn centroid(&self) -> PyPoint {
+                PyPoint(crate::traits::Centroid::<Point>::centroid(&self.0))
+            }
+
             #[getter]
             fn polygons(slf: pyo3::PyRef<Self>) -> PyMultipolygonPolygons {
                 let polygons_count =
@@ -32,6 +49,48 @@ macro_rules! impl_multipolygon_wrapper {
                 }
             }
 
+            fn convex_hull(&self, py: pyo3::Python) -> pyo3::PyObject {
+                pyo3::IntoPy::into_py(
+                    crate::convex_hull::ToConvexHull::<Fraction>::to_convex_hull(
+                        &self.0,
+                    ),
+                    py,
+                )
+            }
+
+            fn explain_validity<'a>(
+                &self,
+                py: pyo3::Python<'a>,
+            ) -> pyo3::PyResult<Vec<&'a pyo3::PyAny>> {
+                try_validity_to_py_errors(
+                    crate::validatable::Validatable::validate(&self.0),
+                    py,
+                )
+            }
+
+            fn is_valid(&self) -> bool {
+                let polygons = (&self.0).polygons();
+                crate::bentley_ottmann::is_multipolygon_valid(
+                    &polygons
+                        .into_iter()
+                        .map(|polygon| {
+                            let (border, holes) = polygon.components();
+                            (border, holes.into_iter().collect::<Vec<_>>())
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            }
+
+            #[pyo3(signature = (other, squared_threshold, /))]
+            fn is_within_squared_distance(
+                &self,
+                other: &pyo3::PyAny,
+                squared_threshold: &pyo3::PyAny,
+                py: pyo3::Python,
+            ) -> pyo3::PyResult<bool> {
+                is_within_squared_distance(&self.0, other, squared_threshold, py)
+            }
+
             #[pyo3(signature = (point, /))]
             fn locate<'a>(
                 &self,
@@ -44,6 +103,31 @@ macro_rules! impl_multipolygon_wrapper {
                 )
             }
 
+            #[pyo3(signature = (fill_rule, /))]
+            fn make_valid(
+                &self,
+                fill_rule: &pyo3::PyAny,
+                py: pyo3::Python,
+            ) -> pyo3::PyResult<pyo3::PyObject> {
+                let polygons = crate::validatable::MakeValid::make_valid(
+                    &self.0,
+                    TryFromPyAny::try_from_py_any(fill_rule, py)?,
+                );
+                Ok(super::unpacking::unpack_maybe_empty_polygons::<
+                    PyEmpty,
+                    PyMultipolygon,
+                    Polygon,
+                >(polygons, py))
+            }
+
+            #[pyo3(signature = (other, /))]
+            fn nearest_points(
+                &self,
+                other: &pyo3::PyAny,
+            ) -> pyo3::PyResult<(Point, Point)> {
+                to_nearest_points(&self.0, other)
+            }
+
             #[pyo3(signature = (other, /))]
             fn relate_to<'a>(
                 &self,
@@ -106,6 +190,22 @@ macro_rules! impl_multipolygon_wrapper {
                 }
             }
 
+            #[pyo3(signature = (other, /))]
+            fn squared_distance_to<'a>(
+                &self,
+                other: &pyo3::PyAny,
+                py: pyo3::Python<'a>,
+            ) -> pyo3::PyResult<&'a pyo3::PyAny> {
+                to_squared_distance(&self.0, other, py)
+            }
+
+            fn validate(&self, py: pyo3::Python) -> pyo3::PyResult<()> {
+                try_validity_to_py_result(
+                    crate::validatable::Validatable::validate(&self.0),
+                    py,
+                )
+            }
+
             fn __and__(
                 &self,
                 other: &pyo3::PyAny,
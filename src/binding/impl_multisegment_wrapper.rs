@@ -13,6 +13,7 @@ macro_rules! impl_multisegment_wrapper {
                         PySegment,
                     >(segments)?,
                 )
+                .map(PyMultisegment)
             }
 
             #[getter]
@@ -20,6 +21,11 @@ macro_rules! impl_multisegment_wrapper {
                 crate::bounded::Bounded::to_bounding_box(&self.0).cloned()
             }
 
+            #[getter]
+            fn centroid(&self) -> PyPoint {
+                PyPoint(crate::traits::Centroid::<Point>::centroid(&self.0))
+            }
+
             #[getter]
             fn segments(slf: pyo3::PyRef<Self>) -> PyMultisegmentSegments {
                 let segments_count =
@@ -32,10 +38,39 @@ macro_rules! impl_multisegment_wrapper {
                 }
             }
 
+            fn convex_hull(&self, py: pyo3::Python) -> pyo3::PyObject {
+                pyo3::IntoPy::into_py(
+                    crate::convex_hull::ToConvexHull::<Fraction>::to_convex_hull(
+                        &self.0,
+                    ),
+                    py,
+                )
+            }
+
+            fn explain_validity<'a>(
+                &self,
+                py: pyo3::Python<'a>,
+            ) -> pyo3::PyResult<Vec<&'a pyo3::PyAny>> {
+                try_validity_to_py_errors(
+                    crate::validatable::Validatable::validate(&self.0),
+                    py,
+                )
+            }
+
             fn is_valid(&self) -> bool {
                 crate::bentley_ottmann::is_multisegment_valid(&self.0)
             }
 
+            #[pyo3(signature = (other, squared_threshold, /))]
+            fn is_within_squared_distance(
+                &self,
+                other: &pyo3::PyAny,
+                squared_threshold: &pyo3::PyAny,
+                py: pyo3::Python,
+            ) -> pyo3::PyResult<bool> {
+                is_within_squared_distance(&self.0, other, squared_threshold, py)
+            }
+
             #[pyo3(signature = (point, /))]
             fn locate<'a>(
                 &self,
@@ -48,6 +83,14 @@ macro_rules! impl_multisegment_wrapper {
                 )
             }
 
+            #[pyo3(signature = (other, /))]
+            fn nearest_points(
+                &self,
+                other: &pyo3::PyAny,
+            ) -> pyo3::PyResult<(Point, Point)> {
+                to_nearest_points(&self.0, other)
+            }
+
             #[pyo3(signature = (other, /))]
             fn relate_to<'a>(
                 &self,
@@ -110,6 +153,22 @@ macro_rules! impl_multisegment_wrapper {
                 }
             }
 
+            #[pyo3(signature = (other, /))]
+            fn squared_distance_to<'a>(
+                &self,
+                other: &pyo3::PyAny,
+                py: pyo3::Python<'a>,
+            ) -> pyo3::PyResult<&'a pyo3::PyAny> {
+                to_squared_distance(&self.0, other, py)
+            }
+
+            fn validate(&self, py: pyo3::Python) -> pyo3::PyResult<()> {
+                try_validity_to_py_result(
+                    crate::validatable::Validatable::validate(&self.0),
+                    py,
+                )
+            }
+
             fn __and__(
                 &self,
                 other: &pyo3::PyAny,
@@ -31,6 +31,42 @@ macro_rules! impl_point_wrapper {
                 crate::traits::Elemental::y(&self.0).try_to_py_any(py)
             }
 
+            fn convex_hull(&self, py: pyo3::Python) -> pyo3::PyObject {
+                pyo3::IntoPy::into_py(
+                    crate::convex_hull::ToConvexHull::<Fraction>::to_convex_hull(
+                        &self.0,
+                    ),
+                    py,
+                )
+            }
+
+            #[pyo3(signature = (other, squared_threshold, /))]
+            fn is_within_squared_distance(
+                &self,
+                other: &pyo3::PyAny,
+                squared_threshold: &pyo3::PyAny,
+                py: pyo3::Python,
+            ) -> pyo3::PyResult<bool> {
+                is_within_squared_distance(&self.0, other, squared_threshold, py)
+            }
+
+            #[pyo3(signature = (other, /))]
+            fn nearest_points(
+                &self,
+                other: &pyo3::PyAny,
+            ) -> pyo3::PyResult<(Point, Point)> {
+                to_nearest_points(&self.0, other)
+            }
+
+            #[pyo3(signature = (other, /))]
+            fn squared_distance_to<'a>(
+                &self,
+                other: &pyo3::PyAny,
+                py: pyo3::Python<'a>,
+            ) -> pyo3::PyResult<&'a pyo3::PyAny> {
+                to_squared_distance(&self.0, other, py)
+            }
+
             fn __hash__(
                 &self,
                 py: pyo3::Python,
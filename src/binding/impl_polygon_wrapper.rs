@@ -8,13 +8,25 @@ macro_rules! impl_polygon_wrapper {
                 border: &PyContour,
                 holes: &pyo3::types::PySequence,
             ) -> pyo3::PyResult<Self> {
-                Ok(PyPolygon(Polygon::new(
+                super::packing::try_pack_contours(
                     border.0.clone(),
                     super::conversion::extract_from_py_sequence::<
                         Contour,
                         PyContour,
                     >(holes)?,
-                )))
+                )
+                .map(PyPolygon)
+            }
+
+            #[getter]
+            fn area<'a>(
+                &self,
+                py: pyo3::Python<'a>,
+            ) -> pyo3::PyResult<&'a pyo3::PyAny> {
+                TryToPyAny::try_to_py_any(
+                    &crate::traits::Area::<Fraction>::area(&self.0),
+                    py,
+                )
             }
 
             #[getter]
@@ -27,6 +39,11 @@ macro_rules! impl_polygon_wrapper {
                 crate::bounded::Bounded::to_bounding_box(&self.0).cloned()
             }
 
+            #[getter]
+            fn centroid(&self) -> PyPoint {
+                PyPoint(crate::traits::Centroid::<Point>::centroid(&self.0))
+            }
+
             #[getter]
             fn holes(slf: pyo3::PyRef<Self>) -> PyPolygonHoles {
                 let holes_count =
@@ -39,6 +56,43 @@ macro_rules! impl_polygon_wrapper {
                 }
             }
 
+            fn convex_hull(&self, py: pyo3::Python) -> pyo3::PyObject {
+                pyo3::IntoPy::into_py(
+                    crate::convex_hull::ToConvexHull::<Fraction>::to_convex_hull(
+                        &self.0,
+                    ),
+                    py,
+                )
+            }
+
+            fn explain_validity<'a>(
+                &self,
+                py: pyo3::Python<'a>,
+            ) -> pyo3::PyResult<Vec<&'a pyo3::PyAny>> {
+                try_validity_to_py_errors(
+                    crate::validatable::Validatable::validate(&self.0),
+                    py,
+                )
+            }
+
+            fn is_valid(&self) -> bool {
+                let (border, holes) = (&self.0).components();
+                crate::bentley_ottmann::is_polygon_valid(
+                    border,
+                    &holes.into_iter().collect::<Vec<_>>(),
+                )
+            }
+
+            #[pyo3(signature = (other, squared_threshold, /))]
+            fn is_within_squared_distance(
+                &self,
+                other: &pyo3::PyAny,
+                squared_threshold: &pyo3::PyAny,
+                py: pyo3::Python,
+            ) -> pyo3::PyResult<bool> {
+                is_within_squared_distance(&self.0, other, squared_threshold, py)
+            }
+
             #[pyo3(signature = (point, /))]
             fn locate<'a>(
                 &self,
@@ -51,6 +105,31 @@ macro_rules! impl_polygon_wrapper {
                 )
             }
 
+            #[pyo3(signature = (fill_rule, /))]
+            fn make_valid(
+                &self,
+                fill_rule: &pyo3::PyAny,
+                py: pyo3::Python,
+            ) -> pyo3::PyResult<pyo3::PyObject> {
+                let polygons = crate::validatable::MakeValid::make_valid(
+                    &self.0,
+                    TryFromPyAny::try_from_py_any(fill_rule, py)?,
+                );
+                Ok(super::unpacking::unpack_maybe_empty_polygons::<
+                    PyEmpty,
+                    PyMultipolygon,
+                    Polygon,
+                >(polygons, py))
+            }
+
+            #[pyo3(signature = (other, /))]
+            fn nearest_points(
+                &self,
+                other: &pyo3::PyAny,
+            ) -> pyo3::PyResult<(Point, Point)> {
+                to_nearest_points(&self.0, other)
+            }
+
             #[pyo3(signature = (other, /))]
             fn relate_to<'a>(
                 &self,
@@ -113,6 +192,22 @@ macro_rules! impl_polygon_wrapper {
                 }
             }
 
+            #[pyo3(signature = (other, /))]
+            fn squared_distance_to<'a>(
+                &self,
+                other: &pyo3::PyAny,
+                py: pyo3::Python<'a>,
+            ) -> pyo3::PyResult<&'a pyo3::PyAny> {
+                to_squared_distance(&self.0, other, py)
+            }
+
+            fn validate(&self, py: pyo3::Python) -> pyo3::PyResult<()> {
+                try_validity_to_py_result(
+                    crate::validatable::Validatable::validate(&self.0),
+                    py,
+                )
+            }
+
             fn __and__(
                 &self,
                 other: &pyo3::PyAny,
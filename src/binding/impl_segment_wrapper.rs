@@ -13,6 +13,11 @@ macro_rules! impl_segment_wrapper {
                 crate::bounded::Bounded::to_bounding_box(&self.0).cloned()
             }
 
+            #[getter]
+            fn centroid(&self) -> PyPoint {
+                PyPoint(crate::traits::Centroid::<Point>::centroid(&self.0))
+            }
+
             #[getter]
             fn end(&self) -> PyPoint {
                 PyPoint(crate::traits::Segmental::end(&self.0).clone())
@@ -23,6 +28,33 @@ macro_rules! impl_segment_wrapper {
                 PyPoint(crate::traits::Segmental::start(&self.0).clone())
             }
 
+            fn convex_hull(&self, py: pyo3::Python) -> pyo3::PyObject {
+                pyo3::IntoPy::into_py(
+                    crate::convex_hull::ToConvexHull::<Fraction>::to_convex_hull(
+                        &self.0,
+                    ),
+                    py,
+                )
+            }
+
+            #[pyo3(signature = (other, squared_threshold, /))]
+            fn is_within_squared_distance(
+                &self,
+                other: &pyo3::PyAny,
+                squared_threshold: &pyo3::PyAny,
+                py: pyo3::Python,
+            ) -> pyo3::PyResult<bool> {
+                is_within_squared_distance(&self.0, other, squared_threshold, py)
+            }
+
+            #[pyo3(signature = (other, /))]
+            fn nearest_points(
+                &self,
+                other: &pyo3::PyAny,
+            ) -> pyo3::PyResult<(Point, Point)> {
+                to_nearest_points(&self.0, other)
+            }
+
             #[pyo3(signature = (other, /))]
             fn relate_to<'a>(
                 &self,
@@ -97,6 +129,15 @@ macro_rules! impl_segment_wrapper {
                 )
             }
 
+            #[pyo3(signature = (other, /))]
+            fn squared_distance_to<'a>(
+                &self,
+                other: &pyo3::PyAny,
+                py: pyo3::Python<'a>,
+            ) -> pyo3::PyResult<&'a pyo3::PyAny> {
+                to_squared_distance(&self.0, other, py)
+            }
+
             fn __and__(
                 &self,
                 other: &pyo3::PyAny,
@@ -9,10 +9,10 @@ macro_rules! impl_trapezoidation_wrapper {
                 multisegment: &PyMultisegment,
                 seed: usize,
             ) -> Self {
-                PyTrapezoidation(Trapezoidation::from_multisegment(
-                    &multisegment.0,
-                    |values| crate::operations::permute(values, seed),
-                ))
+                PyTrapezoidation(
+                    crate::seidel::TrapezoidationBuilder::new(seed)
+                        .build_from_multisegment(&multisegment.0),
+                )
             }
 
             #[classmethod]
@@ -22,12 +22,10 @@ macro_rules! impl_trapezoidation_wrapper {
                 polygon: &PyPolygon,
                 seed: usize,
             ) -> Self {
-                PyTrapezoidation(Trapezoidation::from_polygon(
-                    &polygon.0,
-                    |values| {
-                        crate::operations::permute(values, seed);
-                    },
-                ))
+                PyTrapezoidation(
+                    crate::seidel::TrapezoidationBuilder::new(seed)
+                        .build_from_polygon(&polygon.0),
+                )
             }
 
             #[getter]
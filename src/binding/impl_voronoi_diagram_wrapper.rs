@@ -0,0 +1,41 @@
+macro_rules! impl_voronoi_diagram_wrapper {
+    () => {
+        #[pyo3::prelude::pymethods]
+        impl PyVoronoiDiagram {
+            #[classmethod]
+            #[pyo3(signature = (points, box_, /))]
+            fn from_points(
+                _: &pyo3::types::PyType,
+                points: &pyo3::types::PySequence,
+                box_: &PyBox,
+            ) -> pyo3::PyResult<Self> {
+                Ok(PyVoronoiDiagram(VoronoiDiagram::from((
+                    &DelaunayTriangulation::from(
+                        super::conversion::extract_from_py_sequence::<
+                            Point,
+                            PyPoint,
+                        >(points)?,
+                    ),
+                    &box_.0,
+                ))))
+            }
+
+            #[getter]
+            fn cells(&self) -> Vec<Option<Contour>> {
+                self.0.get_cells().to_vec()
+            }
+
+            #[getter]
+            fn sites(&self) -> Vec<Point> {
+                self.0.get_sites().to_vec()
+            }
+
+            #[getter]
+            fn vertices(&self) -> Vec<Point> {
+                self.0.get_vertices().to_vec()
+            }
+        }
+    };
+}
+
+pub(super) use impl_voronoi_diagram_wrapper;
@@ -14,6 +14,7 @@ mod impl_polygon_wrapper;
 mod impl_py_sequence;
 mod impl_segment_wrapper;
 mod impl_trapezoidation_wrapper;
+mod impl_voronoi_diagram_wrapper;
 mod packing;
 mod reference;
 mod slicing;
@@ -0,0 +1,15 @@
+use std::ops::{Mul, Sub};
+
+use crate::traits::Area;
+
+use super::types::Box;
+
+impl<'a, Scalar> Area<Scalar> for &'a Box<Scalar>
+where
+    Scalar: Mul<Output = Scalar>,
+    &'a Scalar: Sub<Output = Scalar>,
+{
+    fn area(self) -> Scalar {
+        (&self.max_x - &self.min_x) * (&self.max_y - &self.min_y)
+    }
+}
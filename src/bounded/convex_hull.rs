@@ -0,0 +1,21 @@
+use crate::convex_hull::{points_to_convex_hull, ConvexHull, ToConvexHull};
+use crate::geometries::Point;
+use crate::operations::Orient;
+
+use super::types::Box;
+
+impl<Scalar: Clone> ToConvexHull<Scalar> for &Box<Scalar>
+where
+    Point<Scalar>: Clone + Ord,
+    for<'a> &'a Point<Scalar>: Orient,
+{
+    fn to_convex_hull(self) -> ConvexHull<Scalar> {
+        let corners = [
+            Point::new(self.min_x.clone(), self.min_y.clone()),
+            Point::new(self.max_x.clone(), self.min_y.clone()),
+            Point::new(self.max_x.clone(), self.max_y.clone()),
+            Point::new(self.min_x.clone(), self.max_y.clone()),
+        ];
+        points_to_convex_hull(corners.iter().collect())
+    }
+}
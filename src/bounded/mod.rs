@@ -1,6 +1,8 @@
 pub use self::traits::Bounded;
 pub use self::types::Box;
 
+mod area;
+mod convex_hull;
 mod eq;
 mod hash;
 mod partial_eq;
@@ -1,6 +1,7 @@
 pub(crate) use self::event::{is_event_left, is_event_right, Event};
 pub(crate) use self::operation_kind::{
-    DIFFERENCE, INTERSECTION, SYMMETRIC_DIFFERENCE, UNION,
+    DIFFERENCE, EVEN_ODD_FILL, INTERSECTION, NON_ZERO_FILL,
+    SYMMETRIC_DIFFERENCE, UNION,
 };
 
 mod constants;
@@ -2,3 +2,5 @@ pub(crate) const INTERSECTION: u8 = 0;
 pub(crate) const DIFFERENCE: u8 = 1;
 pub(crate) const SYMMETRIC_DIFFERENCE: u8 = 2;
 pub(crate) const UNION: u8 = 3;
+pub(crate) const EVEN_ODD_FILL: u8 = 4;
+pub(crate) const NON_ZERO_FILL: u8 = 5;
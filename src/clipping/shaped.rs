@@ -22,7 +22,8 @@ use super::event::{
 };
 use super::events_queue_key::EventsQueueKey;
 use super::operation_kind::{
-    DIFFERENCE, INTERSECTION, SYMMETRIC_DIFFERENCE, UNION,
+    DIFFERENCE, EVEN_ODD_FILL, INTERSECTION, NON_ZERO_FILL,
+    SYMMETRIC_DIFFERENCE, UNION,
 };
 use super::sweep_line_key::SweepLineKey;
 use super::traits::ReduceEvents;
@@ -31,6 +32,7 @@ pub(crate) struct Operation<Point, const KIND: u8> {
     first_segments_count: usize,
     are_from_result: Vec<bool>,
     below_event_from_result: Vec<Event>,
+    below_windings: Vec<isize>,
     current_endpoint_first_event: Event,
     current_endpoint_id: usize,
     endpoints: Box<Vec<Point>>,
@@ -42,6 +44,7 @@ pub(crate) struct Operation<Point, const KIND: u8> {
     segments_ids: Vec<usize>,
     starts_ids: Vec<usize>,
     sweep_line_data: BTreeSet<SweepLineKey<Point>>,
+    windings: Vec<isize>,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -175,6 +178,33 @@ where
     }
 }
 
+impl<Point: Ord, const KIND: u8> From<Vec<(Point, Point, isize)>>
+    for Operation<Point, KIND>
+where
+    for<'a> &'a Point: Orient,
+{
+    fn from(segments: Vec<(Point, Point, isize)>) -> Self {
+        let mut result = Self::with_capacity(segments.len(), 0);
+        for (segment_id, (start, end, winding)) in
+            segments.into_iter().enumerate()
+        {
+            debug_assert!(start < end);
+            result.windings[segment_id] = winding;
+            let left_event = segment_id_to_left_event(segment_id);
+            let right_event = segment_id_to_right_event(segment_id);
+            result.endpoints.push(start);
+            result.endpoints.push(end);
+            result.opposites.push(right_event);
+            result.opposites.push(left_event);
+            result.push(left_event);
+            result.push(right_event);
+        }
+        let first_event = unsafe { result.peek().unwrap_unchecked() };
+        result.current_endpoint_first_event = first_event;
+        result
+    }
+}
+
 trait DetectIfLeftEventFromResult {
     fn detect_if_left_event_from_result(&self, event: Event) -> bool;
 }
@@ -190,6 +220,14 @@ impl<Point> DetectIfLeftEventFromResult for Operation<Point, DIFFERENCE> {
     }
 }
 
+impl<Point> DetectIfLeftEventFromResult for Operation<Point, EVEN_ODD_FILL> {
+    fn detect_if_left_event_from_result(&self, event: Event) -> bool {
+        let below_winding = self.below_windings[left_event_to_position(event)];
+        below_winding.is_odd()
+            != (below_winding + self.to_left_event_winding(event)).is_odd()
+    }
+}
+
 impl<Point> DetectIfLeftEventFromResult for Operation<Point, INTERSECTION> {
     fn detect_if_left_event_from_result(&self, event: Event) -> bool {
         self.is_left_event_inside(event)
@@ -198,6 +236,14 @@ impl<Point> DetectIfLeftEventFromResult for Operation<Point, INTERSECTION> {
     }
 }
 
+impl<Point> DetectIfLeftEventFromResult for Operation<Point, NON_ZERO_FILL> {
+    fn detect_if_left_event_from_result(&self, event: Event) -> bool {
+        let below_winding = self.below_windings[left_event_to_position(event)];
+        (below_winding != 0)
+            != (below_winding + self.to_left_event_winding(event) != 0)
+    }
+}
+
 impl<Point> DetectIfLeftEventFromResult
     for Operation<Point, SYMMETRIC_DIFFERENCE>
 {
@@ -447,6 +493,11 @@ impl<Point, const KIND: u8> Operation<Point, KIND> {
                         [self.left_event_to_segment_id(below_event)]
                 }
             };
+            if KIND == EVEN_ODD_FILL || KIND == NON_ZERO_FILL {
+                self.below_windings[event_position] = self.below_windings
+                    [below_event_position]
+                    + self.to_left_event_winding(below_event);
+            }
             self.below_event_from_result[event_position] = if !self
                 .detect_if_left_event_from_result(below_event)
                 || self.is_left_event_vertical(below_event)
@@ -728,6 +779,15 @@ impl<Point, const KIND: u8> Operation<Point, KIND> {
         }
     }
 
+    fn to_left_event_winding(&self, event: Event) -> isize {
+        let segment_id = self.left_event_to_segment_id(event);
+        if self.have_interior_to_left[segment_id] {
+            self.windings[segment_id]
+        } else {
+            -self.windings[segment_id]
+        }
+    }
+
     fn to_opposite_event(&self, event: Event) -> Event {
         self.opposites[event]
     }
@@ -986,6 +1046,7 @@ impl<Point: Clone, const KIND: u8> Operation<Point, KIND> {
         self.other_have_interior_to_left.push(false);
         self.are_from_result.push(false);
         self.below_event_from_result.push(UNDEFINED_EVENT);
+        self.below_windings.push(0);
         self.overlap_kinds.push(OverlapKind::None);
         self.starts_ids.push(UNDEFINED_INDEX);
         let mid_point_to_event_start_event: Event = self.endpoints.len();
@@ -1101,6 +1162,7 @@ where
             first_segments_count,
             are_from_result: vec![false; segments_count],
             below_event_from_result: vec![UNDEFINED_EVENT; segments_count],
+            below_windings: vec![0; segments_count],
             current_endpoint_first_event: UNDEFINED_EVENT,
             current_endpoint_id: 0,
             endpoints: Box::new(Vec::with_capacity(initial_events_count)),
@@ -1112,6 +1174,7 @@ where
             segments_ids: (0..segments_count).collect(),
             starts_ids: vec![UNDEFINED_INDEX; initial_events_count],
             sweep_line_data: BTreeSet::new(),
+            windings: vec![1; segments_count],
         }
     }
 }
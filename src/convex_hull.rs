@@ -0,0 +1,88 @@
+use crate::geometries::{Contour, Empty, Point, Segment};
+use crate::operations::Orient;
+use crate::oriented::Orientation;
+
+#[derive(Clone)]
+pub enum ConvexHull<Scalar> {
+    /// hull of points which are not collinear,
+    /// counterclockwise and without collinear consecutive vertices
+    Contour(Contour<Scalar>),
+    /// hull of an empty set of points
+    Empty(Empty),
+    /// hull of a single distinct point
+    Point(Point<Scalar>),
+    /// hull of at least two distinct collinear points
+    Segment(Segment<Scalar>),
+}
+
+pub trait ToConvexHull<Scalar> {
+    fn to_convex_hull(self) -> ConvexHull<Scalar>;
+}
+
+impl<Scalar> ToConvexHull<Scalar> for &[Point<Scalar>]
+where
+    Point<Scalar>: Clone + Ord,
+    for<'a> &'a Point<Scalar>: Orient,
+{
+    fn to_convex_hull(self) -> ConvexHull<Scalar> {
+        points_to_convex_hull(self.iter().collect())
+    }
+}
+
+pub(crate) fn points_to_convex_hull<Scalar>(
+    points: Vec<&Point<Scalar>>,
+) -> ConvexHull<Scalar>
+where
+    Point<Scalar>: Clone + Ord,
+    for<'a> &'a Point<Scalar>: Orient,
+{
+    let vertices = to_convex_hull_vertices(points);
+    match vertices.len() {
+        0 => ConvexHull::Empty(Empty::new()),
+        1 => ConvexHull::Point(vertices[0].clone()),
+        2 => ConvexHull::Segment(Segment::new(
+            vertices[0].clone(),
+            vertices[1].clone(),
+        )),
+        _ => ConvexHull::Contour(Contour::new(
+            vertices.into_iter().cloned().collect(),
+        )),
+    }
+}
+
+/// Based on Andrew's monotone chain algorithm,
+/// returns distinct points for degenerate cases.
+fn to_convex_hull_vertices<'a, Point: Ord>(
+    mut points: Vec<&'a Point>,
+) -> Vec<&'a Point>
+where
+    for<'b> &'b Point: Orient,
+{
+    points.sort();
+    points.dedup();
+    if points.len() < 3 {
+        return points;
+    }
+    let mut result = Vec::<&'a Point>::with_capacity(points.len() + 1);
+    for &point in points.iter() {
+        while result.len() >= 2
+            && result[result.len() - 2].orient(result[result.len() - 1], point)
+                != Orientation::Counterclockwise
+        {
+            result.pop();
+        }
+        result.push(point);
+    }
+    let lower_hull_size = result.len();
+    for &point in points.iter().rev().skip(1) {
+        while result.len() > lower_hull_size
+            && result[result.len() - 2].orient(result[result.len() - 1], point)
+                != Orientation::Counterclockwise
+        {
+            result.pop();
+        }
+        result.push(point);
+    }
+    result.pop();
+    result
+}
@@ -0,0 +1,537 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use traiter::numbers::Zero;
+
+use crate::bounded;
+use crate::geometries::Point;
+use crate::operations::{
+    merge_bounds, to_boxes_ids_within_squared_distance,
+    to_boxes_squared_distance, to_sorted_pair, CrossMultiply, DotMultiply,
+    IntersectCrossingSegments, Orient, Square, SquaredMetric,
+};
+use crate::oriented::Orientation;
+use crate::traits::Elemental;
+
+/// Segment given by its endpoints, points become degenerate ones.
+pub(crate) type DistanceSegment<'a, Scalar> =
+    (&'a Point<Scalar>, &'a Point<Scalar>);
+
+pub trait IsWithinSquaredDistance<Scalar, Other = Self>: Copy {
+    fn is_within_squared_distance(
+        self,
+        other: Other,
+        squared_threshold: Scalar,
+    ) -> bool;
+}
+
+pub trait NearestPoints<Other = Self> {
+    type Output;
+
+    fn nearest_points(self, other: Other) -> Self::Output;
+}
+
+pub trait SquaredDistance<Other = Self> {
+    type Output;
+
+    fn squared_distance_to(self, other: Other) -> Self::Output;
+}
+
+/// Splits geometry into parts used by distance computations,
+/// which are implemented once for all pairs of such geometries.
+pub(crate) trait ToDistanceParts {
+    type Scalar;
+
+    /// Returns segments of the geometry.
+    fn to_distance_segments(&self) -> Vec<DistanceSegment<'_, Self::Scalar>>;
+
+    /// Returns a point per connected component of the geometry.
+    fn to_distance_probes(&self) -> Vec<&Point<Self::Scalar>>;
+
+    /// Checks if the point lies in the interior or on the boundary
+    /// of the shaped geometry,
+    /// linear geometries cover nothing since segments of other geometries
+    /// can reach them only by intersecting.
+    fn covers_distance_probe(&self, point: &Point<Self::Scalar>) -> bool;
+}
+
+impl<'a, First, Scalar, Second> IsWithinSquaredDistance<Scalar, &'a Second>
+    for &'a First
+where
+    First: ToDistanceParts<Scalar = Scalar>,
+    Second: ToDistanceParts<Scalar = Scalar>,
+    Scalar: Add<Output = Scalar>
+        + Div<Output = Scalar>
+        + Ord
+        + Square<Output = Scalar>
+        + Zero,
+    for<'b> &'b Scalar: Sub<Output = Scalar>,
+    for<'b> &'b Point<Scalar>: CrossMultiply<Output = Scalar>
+        + DotMultiply<Output = Scalar>
+        + Elemental<Coordinate = &'b Scalar>
+        + Orient
+        + SquaredMetric<Output = Scalar>,
+{
+    fn is_within_squared_distance(
+        self,
+        other: &'a Second,
+        squared_threshold: Scalar,
+    ) -> bool {
+        let first_segments = self.to_distance_segments();
+        let second_segments = other.to_distance_segments();
+        are_segments_boxes_within_squared_distance(
+            &first_segments,
+            &second_segments,
+            &squared_threshold,
+        ) && (find_covered_probe(self, other).is_some()
+            || are_segments_within_squared_distance(
+                &first_segments,
+                &second_segments,
+                &squared_threshold,
+            ))
+    }
+}
+
+impl<'a, First, Scalar, Second> NearestPoints<&'a Second> for &'a First
+where
+    First: ToDistanceParts<Scalar = Scalar>,
+    Second: ToDistanceParts<Scalar = Scalar>,
+    Point<Scalar>: Clone,
+    Scalar: Add<Output = Scalar>
+        + Div<Output = Scalar>
+        + Mul<Output = Scalar>
+        + Ord
+        + Square<Output = Scalar>
+        + Zero
+        + for<'b> Mul<&'b Scalar, Output = Scalar>,
+    for<'b> &'b Scalar: Add<Scalar, Output = Scalar> + Sub<Output = Scalar>,
+    for<'b> &'b Point<Scalar>: CrossMultiply<Output = Scalar>
+        + DotMultiply<Output = Scalar>
+        + Elemental<Coordinate = &'b Scalar>
+        + IntersectCrossingSegments<Output = Point<Scalar>>
+        + Orient
+        + SquaredMetric<Output = Scalar>,
+{
+    type Output = (Point<Scalar>, Point<Scalar>);
+
+    fn nearest_points(self, other: &'a Second) -> Self::Output {
+        if let Some(probe) = find_covered_probe(self, other) {
+            return (probe.clone(), probe.clone());
+        }
+        let first_segments = self.to_distance_segments();
+        let second_segments = other.to_distance_segments();
+        let (first_id, second_id, _) =
+            to_closest_segments_ids(&first_segments, &second_segments);
+        let (first_start, first_end) = first_segments[first_id];
+        let (second_start, second_end) = second_segments[second_id];
+        to_segments_pair_nearest_points(
+            first_start,
+            first_end,
+            second_start,
+            second_end,
+        )
+    }
+}
+
+impl<'a, First, Scalar, Second> SquaredDistance<&'a Second> for &'a First
+where
+    First: ToDistanceParts<Scalar = Scalar>,
+    Second: ToDistanceParts<Scalar = Scalar>,
+    Scalar: Add<Output = Scalar>
+        + Div<Output = Scalar>
+        + Ord
+        + Square<Output = Scalar>
+        + Zero,
+    for<'b> &'b Scalar: Sub<Output = Scalar>,
+    for<'b> &'b Point<Scalar>: CrossMultiply<Output = Scalar>
+        + DotMultiply<Output = Scalar>
+        + Elemental<Coordinate = &'b Scalar>
+        + Orient
+        + SquaredMetric<Output = Scalar>,
+{
+    type Output = Scalar;
+
+    fn squared_distance_to(self, other: &'a Second) -> Self::Output {
+        if find_covered_probe(self, other).is_some() {
+            Scalar::zero()
+        } else {
+            let (_, _, result) = to_closest_segments_ids(
+                &self.to_distance_segments(),
+                &other.to_distance_segments(),
+            );
+            result
+        }
+    }
+}
+
+fn are_segments_boxes_within_squared_distance<'a, Scalar>(
+    first: &[(&'a Point<Scalar>, &'a Point<Scalar>)],
+    second: &[(&'a Point<Scalar>, &'a Point<Scalar>)],
+    squared_threshold: &Scalar,
+) -> bool
+where
+    Scalar: Add<Output = Scalar> + Ord + Square<Output = Scalar> + Zero,
+    &'a Scalar: Sub<Output = Scalar>,
+    &'a Point<Scalar>: Elemental<Coordinate = &'a Scalar>,
+{
+    &to_boxes_squared_distance(
+        &to_segments_bounding_box(first),
+        &to_segments_bounding_box(second),
+    ) <= squared_threshold
+}
+
+fn are_segments_within_squared_distance<'a, Scalar>(
+    first: &[(&'a Point<Scalar>, &'a Point<Scalar>)],
+    second: &[(&'a Point<Scalar>, &'a Point<Scalar>)],
+    squared_threshold: &Scalar,
+) -> bool
+where
+    Scalar: 'a
+        + Add<Output = Scalar>
+        + Div<Output = Scalar>
+        + Ord
+        + Square<Output = Scalar>
+        + Zero,
+    &'a Scalar: Sub<Output = Scalar>,
+    for<'b> &'b Point<Scalar>: CrossMultiply<Output = Scalar>
+        + DotMultiply<Output = Scalar>
+        + Elemental<Coordinate = &'b Scalar>
+        + Orient
+        + SquaredMetric<Output = Scalar>,
+{
+    let second_boxes = second
+        .iter()
+        .map(|&(start, end)| to_segment_bounding_box(start, end))
+        .collect::<Vec<_>>();
+    first.iter().any(|&(first_start, first_end)| {
+        to_boxes_ids_within_squared_distance(
+            &second_boxes,
+            &to_segment_bounding_box(first_start, first_end),
+            squared_threshold,
+        )
+        .into_iter()
+        .any(|second_id| {
+            let (second_start, second_end) = second[second_id];
+            &to_segments_pair_squared_distance(
+                first_start,
+                first_end,
+                second_start,
+                second_end,
+            ) <= squared_threshold
+        })
+    })
+}
+
+/// Searches for a point of some connected component of one geometry
+/// covered by the other one, components which are not covered that way
+/// either lie outside or cross the boundary of the shaped geometry.
+fn find_covered_probe<'a, First, Second>(
+    first: &'a First,
+    second: &'a Second,
+) -> Option<&'a Point<First::Scalar>>
+where
+    First: ToDistanceParts,
+    Second: ToDistanceParts<Scalar = First::Scalar>,
+{
+    second
+        .to_distance_probes()
+        .into_iter()
+        .find(|probe| first.covers_distance_probe(probe))
+        .or_else(|| {
+            first
+                .to_distance_probes()
+                .into_iter()
+                .find(|probe| second.covers_distance_probe(probe))
+        })
+}
+
+fn to_point_segment_nearest_point<Scalar>(
+    point: &Point<Scalar>,
+    start: &Point<Scalar>,
+    end: &Point<Scalar>,
+) -> Point<Scalar>
+where
+    Point<Scalar>: Clone,
+    Scalar: Div<Output = Scalar>
+        + Ord
+        + Zero
+        + for<'a> Mul<&'a Scalar, Output = Scalar>,
+    for<'a> &'a Scalar: Add<Scalar, Output = Scalar> + Sub<Output = Scalar>,
+    for<'a> &'a Point<Scalar>: DotMultiply<Output = Scalar>
+        + Elemental<Coordinate = &'a Scalar>
+        + SquaredMetric<Output = Scalar>,
+{
+    let projection = DotMultiply::dot_multiply(start, end, start, point);
+    if projection <= Scalar::zero() {
+        return start.clone();
+    }
+    let squared_length = SquaredMetric::squared_distance_to(start, end);
+    if projection >= squared_length {
+        end.clone()
+    } else {
+        let scale = projection / squared_length;
+        Point::new(
+            start.x() + (end.x() - start.x()) * &scale,
+            start.y() + (end.y() - start.y()) * &scale,
+        )
+    }
+}
+
+fn to_point_segment_squared_distance<Scalar>(
+    point: &Point<Scalar>,
+    start: &Point<Scalar>,
+    end: &Point<Scalar>,
+) -> Scalar
+where
+    Scalar: Div<Output = Scalar> + Ord + Square<Output = Scalar> + Zero,
+    for<'a> &'a Point<Scalar>: CrossMultiply<Output = Scalar>
+        + DotMultiply<Output = Scalar>
+        + SquaredMetric<Output = Scalar>,
+{
+    let projection = DotMultiply::dot_multiply(start, end, start, point);
+    if projection <= Scalar::zero() {
+        return SquaredMetric::squared_distance_to(point, start);
+    }
+    let squared_length = SquaredMetric::squared_distance_to(start, end);
+    if projection >= squared_length {
+        SquaredMetric::squared_distance_to(point, end)
+    } else {
+        CrossMultiply::cross_multiply(start, end, start, point).square()
+            / squared_length
+    }
+}
+
+fn to_segments_pair_nearest_points<Scalar>(
+    first_start: &Point<Scalar>,
+    first_end: &Point<Scalar>,
+    second_start: &Point<Scalar>,
+    second_end: &Point<Scalar>,
+) -> (Point<Scalar>, Point<Scalar>)
+where
+    Point<Scalar>: Clone,
+    Scalar: Div<Output = Scalar>
+        + Ord
+        + Square<Output = Scalar>
+        + Zero
+        + for<'a> Mul<&'a Scalar, Output = Scalar>,
+    for<'a> &'a Scalar: Add<Scalar, Output = Scalar> + Sub<Output = Scalar>,
+    for<'a> &'a Point<Scalar>: CrossMultiply<Output = Scalar>
+        + DotMultiply<Output = Scalar>
+        + Elemental<Coordinate = &'a Scalar>
+        + IntersectCrossingSegments<Output = Point<Scalar>>
+        + Orient
+        + SquaredMetric<Output = Scalar>,
+{
+    if do_segments_cross(first_start, first_end, second_start, second_end) {
+        let cross_point =
+            IntersectCrossingSegments::intersect_crossing_segments(
+                first_start,
+                first_end,
+                second_start,
+                second_end,
+            );
+        return (cross_point.clone(), cross_point);
+    }
+    let candidates = [
+        (first_start, second_start, second_end, false),
+        (first_end, second_start, second_end, false),
+        (second_start, first_start, first_end, true),
+        (second_end, first_start, first_end, true),
+    ];
+    let &(point, start, end, is_point_from_second) = unsafe {
+        candidates
+            .iter()
+            .min_by_key(|&&(point, start, end, _)| {
+                to_point_segment_squared_distance(point, start, end)
+            })
+            .unwrap_unchecked()
+    };
+    let nearest_point = to_point_segment_nearest_point(point, start, end);
+    if is_point_from_second {
+        (nearest_point, point.clone())
+    } else {
+        (point.clone(), nearest_point)
+    }
+}
+
+fn to_segments_pair_squared_distance<Scalar>(
+    first_start: &Point<Scalar>,
+    first_end: &Point<Scalar>,
+    second_start: &Point<Scalar>,
+    second_end: &Point<Scalar>,
+) -> Scalar
+where
+    Scalar: Div<Output = Scalar> + Ord + Square<Output = Scalar> + Zero,
+    for<'a> &'a Point<Scalar>: CrossMultiply<Output = Scalar>
+        + DotMultiply<Output = Scalar>
+        + Orient
+        + SquaredMetric<Output = Scalar>,
+{
+    if do_segments_cross(first_start, first_end, second_start, second_end) {
+        return Scalar::zero();
+    }
+    to_point_segment_squared_distance(first_start, second_start, second_end)
+        .min(to_point_segment_squared_distance(
+            first_end,
+            second_start,
+            second_end,
+        ))
+        .min(to_point_segment_squared_distance(
+            second_start,
+            first_start,
+            first_end,
+        ))
+        .min(to_point_segment_squared_distance(
+            second_end,
+            first_start,
+            first_end,
+        ))
+}
+
+fn to_closest_segments_ids<'a, Scalar>(
+    first: &[(&'a Point<Scalar>, &'a Point<Scalar>)],
+    second: &[(&'a Point<Scalar>, &'a Point<Scalar>)],
+) -> (usize, usize, Scalar)
+where
+    Scalar: 'a
+        + Add<Output = Scalar>
+        + Div<Output = Scalar>
+        + Ord
+        + Square<Output = Scalar>
+        + Zero,
+    &'a Scalar: Sub<Output = Scalar>,
+    for<'b> &'b Point<Scalar>: CrossMultiply<Output = Scalar>
+        + DotMultiply<Output = Scalar>
+        + Elemental<Coordinate = &'b Scalar>
+        + Orient
+        + SquaredMetric<Output = Scalar>,
+{
+    debug_assert!(!first.is_empty() && !second.is_empty());
+    let boxes = [first, second].map(|segments| {
+        segments
+            .iter()
+            .map(|&(start, end)| to_segment_bounding_box(start, end))
+            .collect::<Vec<_>>()
+    });
+    let mut events = (0..first.len())
+        .map(|first_id| (0usize, first_id))
+        .chain((0..second.len()).map(|second_id| (1usize, second_id)))
+        .collect::<Vec<_>>();
+    events.sort_by(|&(side, id), &(other_side, other_id)| {
+        boxes[side][id]
+            .get_min_x()
+            .cmp(boxes[other_side][other_id].get_min_x())
+    });
+    // sweeping by minimal abscissas, so each pair of segments is checked
+    // when the one which starts rightmost is reached,
+    // segments ending too far to the left of the sweep line are dropped
+    // since they cannot get closer to the following ones
+    let mut active_ids: [Vec<usize>; 2] = [vec![], vec![]];
+    let mut result: Option<(usize, usize, Scalar)> = None;
+    for (side, id) in events {
+        let other_side = 1 - side;
+        let segment_box = &boxes[side][id];
+        if let Some((_, _, min_squared_distance)) = &result {
+            let min_x = *segment_box.get_min_x();
+            active_ids[other_side].retain(|&other_id| {
+                let other_max_x = *boxes[other_side][other_id].get_max_x();
+                other_max_x >= min_x
+                    || &(min_x - other_max_x).square() < min_squared_distance
+            });
+        }
+        for &other_id in &active_ids[other_side] {
+            if let Some((_, _, min_squared_distance)) = &result {
+                if &to_boxes_squared_distance(
+                    segment_box,
+                    &boxes[other_side][other_id],
+                ) >= min_squared_distance
+                {
+                    continue;
+                }
+            }
+            let (first_id, second_id) = if side == 0 {
+                (id, other_id)
+            } else {
+                (other_id, id)
+            };
+            let (first_start, first_end) = first[first_id];
+            let (second_start, second_end) = second[second_id];
+            let squared_distance = to_segments_pair_squared_distance(
+                first_start,
+                first_end,
+                second_start,
+                second_end,
+            );
+            if squared_distance == Scalar::zero() {
+                return (first_id, second_id, squared_distance);
+            }
+            if result
+                .as_ref()
+                .map_or(true, |(_, _, result)| &squared_distance < result)
+            {
+                result = Some((first_id, second_id, squared_distance));
+            }
+        }
+        active_ids[side].push(id);
+    }
+    unsafe { result.unwrap_unchecked() }
+}
+
+fn do_segments_cross<Point>(
+    first_start: &Point,
+    first_end: &Point,
+    second_start: &Point,
+    second_end: &Point,
+) -> bool
+where
+    for<'a> &'a Point: Orient,
+{
+    let first_start_orientation = second_start.orient(second_end, first_start);
+    let first_end_orientation = second_start.orient(second_end, first_end);
+    let second_start_orientation = first_start.orient(first_end, second_start);
+    let second_end_orientation = first_start.orient(first_end, second_end);
+    first_start_orientation != Orientation::Collinear
+        && first_end_orientation != Orientation::Collinear
+        && first_start_orientation != first_end_orientation
+        && second_start_orientation != Orientation::Collinear
+        && second_end_orientation != Orientation::Collinear
+        && second_start_orientation != second_end_orientation
+}
+
+fn to_segment_bounding_box<'a, Scalar: PartialOrd>(
+    start: &'a Point<Scalar>,
+    end: &'a Point<Scalar>,
+) -> bounded::Box<&'a Scalar>
+where
+    &'a Point<Scalar>: Elemental<Coordinate = &'a Scalar>,
+{
+    let (min_x, max_x, min_y, max_y) = to_segment_bounds(start, end);
+    bounded::Box::new(min_x, max_x, min_y, max_y)
+}
+
+fn to_segment_bounds<'a, Scalar: PartialOrd>(
+    start: &'a Point<Scalar>,
+    end: &'a Point<Scalar>,
+) -> (&'a Scalar, &'a Scalar, &'a Scalar, &'a Scalar)
+where
+    &'a Point<Scalar>: Elemental<Coordinate = &'a Scalar>,
+{
+    let (start_x, start_y) = start.coordinates();
+    let (end_x, end_y) = end.coordinates();
+    let (min_x, max_x) = to_sorted_pair((start_x, end_x));
+    let (min_y, max_y) = to_sorted_pair((start_y, end_y));
+    (min_x, max_x, min_y, max_y)
+}
+
+fn to_segments_bounding_box<'a, Scalar: PartialOrd>(
+    segments: &[(&'a Point<Scalar>, &'a Point<Scalar>)],
+) -> bounded::Box<&'a Scalar>
+where
+    &'a Point<Scalar>: Elemental<Coordinate = &'a Scalar>,
+{
+    let (min_x, max_x, min_y, max_y) = merge_bounds(
+        segments
+            .iter()
+            .map(|&(start, end)| to_segment_bounds(start, end)),
+    );
+    bounded::Box::new(min_x, max_x, min_y, max_y)
+}
@@ -0,0 +1,58 @@
+use std::fmt;
+
+use crate::constants::{
+    MIN_CONTOUR_VERTICES_COUNT, MIN_MULTIPOLYGON_POLYGONS_COUNT,
+    MIN_MULTISEGMENT_SEGMENTS_COUNT,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ConstructionError {
+    /// border of the polygon cannot be constructed
+    Border(Box<ConstructionError>),
+    /// hole of the polygon cannot be constructed
+    Hole {
+        hole_id: usize,
+        error: Box<ConstructionError>,
+    },
+    /// multipolygon has fewer polygons than required
+    TooFewPolygons { polygons_count: usize },
+    /// multisegment has fewer segments than required
+    TooFewSegments { segments_count: usize },
+    /// contour has fewer vertices than required
+    TooFewVertices { vertices_count: usize },
+}
+
+impl fmt::Display for ConstructionError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Border(error) => {
+                formatter.write_fmt(format_args!("border: {}", error))
+            }
+            Self::Hole { hole_id, error } => formatter
+                .write_fmt(format_args!("hole {}: {}", hole_id, error)),
+            Self::TooFewPolygons { polygons_count } => {
+                formatter.write_fmt(format_args!(
+                    "Multipolygon should have at least {} polygons, \
+                     but found {}.",
+                    MIN_MULTIPOLYGON_POLYGONS_COUNT, polygons_count
+                ))
+            }
+            Self::TooFewSegments { segments_count } => {
+                formatter.write_fmt(format_args!(
+                    "Multisegment should have at least {} segments, \
+                     but found {}.",
+                    MIN_MULTISEGMENT_SEGMENTS_COUNT, segments_count
+                ))
+            }
+            Self::TooFewVertices { vertices_count } => {
+                formatter.write_fmt(format_args!(
+                    "Contour should have at least {} vertices, \
+                     but found {}.",
+                    MIN_CONTOUR_VERTICES_COUNT, vertices_count
+                ))
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConstructionError {}
@@ -0,0 +1,19 @@
+use std::ops::{Add, Div};
+
+use traiter::numbers::{One, Zero};
+
+use crate::geometries::Point;
+use crate::operations::{halve, to_doubled_signed_area, CrossMultiply};
+use crate::traits::Area;
+
+use super::types::Contour;
+
+impl<Scalar> Area<Scalar> for &Contour<Scalar>
+where
+    Scalar: Add<Output = Scalar> + Div<Output = Scalar> + One + Zero,
+    for<'a> &'a Point<Scalar>: CrossMultiply<Output = Scalar>,
+{
+    fn area(self) -> Scalar {
+        halve(to_doubled_signed_area(&self.vertices))
+    }
+}
@@ -0,0 +1,24 @@
+use std::ops::{Add, Div};
+
+use traiter::numbers::{One, Zero};
+
+use crate::geometries::Point;
+use crate::operations::vertices_to_centroid;
+use crate::traits::{Centroid, Elemental};
+
+use super::types::Contour;
+
+impl<Scalar> Centroid<Point<Scalar>> for &Contour<Scalar>
+where
+    Scalar: Add<Output = Scalar>
+        + for<'a> Add<&'a Scalar, Output = Scalar>
+        + Div<Output = Scalar>
+        + for<'a> Div<&'a Scalar, Output = Scalar>
+        + One
+        + Zero,
+    for<'a> &'a Point<Scalar>: Elemental<Coordinate = &'a Scalar>,
+{
+    fn centroid(self) -> Point<Scalar> {
+        vertices_to_centroid(self.vertices.iter())
+    }
+}
@@ -0,0 +1,15 @@
+use crate::convex_hull::{points_to_convex_hull, ConvexHull, ToConvexHull};
+use crate::geometries::Point;
+use crate::operations::Orient;
+
+use super::types::Contour;
+
+impl<Scalar> ToConvexHull<Scalar> for &Contour<Scalar>
+where
+    Point<Scalar>: Clone + Ord,
+    for<'a> &'a Point<Scalar>: Orient,
+{
+    fn to_convex_hull(self) -> ConvexHull<Scalar> {
+        points_to_convex_hull(self.vertices.iter().collect())
+    }
+}
@@ -1,19 +1,25 @@
 pub use self::types::Contour;
 
+mod area;
 mod bounded;
+mod centroid;
 mod contoural;
+mod convex_hull;
 mod difference;
 mod eq;
 mod from;
 mod hash;
 mod intersection;
 mod locatable;
+mod make_valid;
 mod multisegmental;
 mod multivertexal;
 mod oriented;
 mod partial_eq;
 mod relatable;
 mod symmetric_difference;
+mod to_distance_parts;
 mod to_reversed_segments;
 mod types;
 mod union;
+mod validatable;
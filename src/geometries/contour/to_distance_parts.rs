@@ -0,0 +1,21 @@
+use crate::distance::{DistanceSegment, ToDistanceParts};
+use crate::geometries::Point;
+use crate::traits::Segmental;
+
+use super::types::Contour;
+
+impl<Scalar> ToDistanceParts for Contour<Scalar> {
+    type Scalar = Scalar;
+
+    fn to_distance_segments(&self) -> Vec<DistanceSegment<'_, Scalar>> {
+        self.segments.iter().map(Segmental::endpoints).collect()
+    }
+
+    fn to_distance_probes(&self) -> Vec<&Point<Scalar>> {
+        vec![&self.vertices[0]]
+    }
+
+    fn covers_distance_probe(&self, _point: &Point<Scalar>) -> bool {
+        false
+    }
+}
@@ -1,4 +1,5 @@
-use crate::geometries::{Point, Segment};
+use crate::constants::MIN_CONTOUR_VERTICES_COUNT;
+use crate::geometries::{ConstructionError, Point, Segment};
 use crate::operations::to_arg_min;
 
 #[derive(Clone)]
@@ -11,6 +12,8 @@ impl<Scalar> Contour<Scalar>
 where
     Point<Scalar>: Clone,
 {
+    /// constructs contour without checking vertices count,
+    /// `try_new` should be used if it may be too small
     #[must_use]
     pub fn new(vertices: Vec<Point<Scalar>>) -> Self {
         let mut segments =
@@ -27,6 +30,19 @@ where
         ));
         Self { segments, vertices }
     }
+
+    /// constructs contour if it has enough vertices to be valid
+    pub fn try_new(
+        vertices: Vec<Point<Scalar>>,
+    ) -> Result<Self, ConstructionError> {
+        if vertices.len() < MIN_CONTOUR_VERTICES_COUNT {
+            Err(ConstructionError::TooFewVertices {
+                vertices_count: vertices.len(),
+            })
+        } else {
+            Ok(Self::new(vertices))
+        }
+    }
 }
 
 impl<Scalar: Ord> Contour<Scalar> {
@@ -0,0 +1,27 @@
+use crate::bentley_ottmann::{
+    to_contour_validity_errors, Event, EventsRegistry,
+};
+use crate::geometries::{Point, Segment};
+use crate::operations::Orient;
+use crate::slice_sequence::SliceSequence;
+use crate::traits::Elemental;
+use crate::validatable::{Validatable, ValidityError};
+
+use super::types::Contour;
+
+impl<Scalar> Validatable<Point<Scalar>> for &Contour<Scalar>
+where
+    Point<Scalar>: Clone + Ord,
+    EventsRegistry<Point<Scalar>, false>: Iterator<Item = Event>
+        + for<'a, 'b> From<&'a SliceSequence<'b, Segment<Scalar>>>,
+    for<'a> &'a Point<Scalar>: Elemental<Coordinate = &'a Scalar> + Orient,
+{
+    fn validate(self) -> Result<(), Vec<ValidityError<Point<Scalar>>>> {
+        let errors = to_contour_validity_errors(self);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
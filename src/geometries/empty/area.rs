@@ -0,0 +1,11 @@
+use traiter::numbers::Zero;
+
+use crate::traits::Area;
+
+use super::types::Empty;
+
+impl<Scalar: Zero> Area<Scalar> for &Empty {
+    fn area(self) -> Scalar {
+        Scalar::zero()
+    }
+}
@@ -0,0 +1,9 @@
+use crate::convex_hull::{ConvexHull, ToConvexHull};
+
+use super::types::Empty;
+
+impl<Scalar> ToConvexHull<Scalar> for &Empty {
+    fn to_convex_hull(self) -> ConvexHull<Scalar> {
+        ConvexHull::Empty(*self)
+    }
+}
@@ -1,5 +1,7 @@
 pub use self::types::Empty;
 
+mod area;
+mod convex_hull;
 mod default;
 mod difference;
 mod from;
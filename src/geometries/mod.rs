@@ -1,3 +1,4 @@
+pub use self::construction_error::ConstructionError;
 pub use self::contour::Contour;
 pub use self::empty::Empty;
 pub use self::multipolygon::Multipolygon;
@@ -6,6 +7,7 @@ pub use self::point::Point;
 pub use self::polygon::Polygon;
 pub use self::segment::Segment;
 
+mod construction_error;
 mod contour;
 mod contracts;
 mod empty;
@@ -0,0 +1,20 @@
+use std::ops::Add;
+
+use traiter::numbers::Zero;
+
+use crate::geometries::Polygon;
+use crate::traits::Area;
+
+use super::types::Multipolygon;
+
+impl<Scalar> Area<Scalar> for &Multipolygon<Scalar>
+where
+    Scalar: Add<Output = Scalar> + Zero,
+    for<'a> &'a Polygon<Scalar>: Area<Scalar>,
+{
+    fn area(self) -> Scalar {
+        self.polygons
+            .iter()
+            .fold(Scalar::zero(), |result, polygon| result + polygon.area())
+    }
+}
@@ -0,0 +1,56 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use traiter::numbers::{One, Signed, Zero};
+
+use crate::geometries::Point;
+use crate::operations::{
+    region_moments_to_centroid, vertices_to_centroid, CrossMultiply,
+    ToRegionMoments,
+};
+use crate::traits::{Centroid, Elemental, Multivertexal, Polygonal};
+
+use super::types::Multipolygon;
+
+impl<Scalar> Centroid<Point<Scalar>> for &Multipolygon<Scalar>
+where
+    Scalar: Add<Output = Scalar>
+        + for<'a> Add<&'a Scalar, Output = Scalar>
+        + Div<Output = Scalar>
+        + for<'a> Div<&'a Scalar, Output = Scalar>
+        + Mul<Output = Scalar>
+        + for<'a> Mul<&'a Scalar, Output = Scalar>
+        + Neg<Output = Scalar>
+        + One
+        + Sub<Output = Scalar>
+        + Zero,
+    for<'a> &'a Point<Scalar>:
+        CrossMultiply<Output = Scalar> + Elemental<Coordinate = &'a Scalar>,
+    for<'a> &'a Scalar: Add<Output = Scalar> + Signed,
+{
+    fn centroid(self) -> Point<Scalar> {
+        region_moments_to_centroid(
+            self.polygons.iter().fold(
+                (Scalar::zero(), Scalar::zero(), Scalar::zero()),
+                |(doubled_area, x_moment, y_moment), polygon| {
+                    let (
+                        polygon_doubled_area,
+                        polygon_x_moment,
+                        polygon_y_moment,
+                    ) = polygon.to_region_moments();
+                    (
+                        doubled_area + polygon_doubled_area,
+                        x_moment + polygon_x_moment,
+                        y_moment + polygon_y_moment,
+                    )
+                },
+            ),
+        )
+        .unwrap_or_else(|| {
+            vertices_to_centroid(
+                self.polygons
+                    .iter()
+                    .flat_map(|polygon| polygon.border().vertices()),
+            )
+        })
+    }
+}
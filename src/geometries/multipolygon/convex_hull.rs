@@ -0,0 +1,21 @@
+use crate::convex_hull::{points_to_convex_hull, ConvexHull, ToConvexHull};
+use crate::geometries::Point;
+use crate::operations::Orient;
+use crate::traits::{Multivertexal, Polygonal};
+
+use super::types::Multipolygon;
+
+impl<Scalar> ToConvexHull<Scalar> for &Multipolygon<Scalar>
+where
+    Point<Scalar>: Clone + Ord,
+    for<'a> &'a Point<Scalar>: Orient,
+{
+    fn to_convex_hull(self) -> ConvexHull<Scalar> {
+        points_to_convex_hull(
+            self.polygons
+                .iter()
+                .flat_map(|polygon| polygon.border().vertices().into_iter())
+                .collect(),
+        )
+    }
+}
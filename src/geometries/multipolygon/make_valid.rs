@@ -0,0 +1,40 @@
+use crate::bentley_ottmann::{Event, EventsRegistry};
+use crate::clipping::shaped::Operation;
+use crate::clipping::traits::ReduceEvents;
+use crate::clipping::{EVEN_ODD_FILL, NON_ZERO_FILL};
+use crate::geometries::{Point, Polygon, Segment};
+use crate::operations::{Orient, ToCorrectlyOrientedSegments};
+use crate::traits::Segmental;
+use crate::validatable::{segments_to_valid_polygons, FillRule, MakeValid};
+
+use super::types::Multipolygon;
+
+impl<Scalar> MakeValid for &Multipolygon<Scalar>
+where
+    EventsRegistry<Point<Scalar>, true>:
+        Iterator<Item = Event> + for<'a> From<&'a Vec<Segment<Scalar>>>,
+    Operation<Point<Scalar>, EVEN_ODD_FILL>: Iterator<Item = Event>
+        + ReduceEvents<Output = Vec<Polygon<Scalar>>>
+        + From<Vec<(Point<Scalar>, Point<Scalar>, isize)>>,
+    Operation<Point<Scalar>, NON_ZERO_FILL>: Iterator<Item = Event>
+        + ReduceEvents<Output = Vec<Polygon<Scalar>>>
+        + From<Vec<(Point<Scalar>, Point<Scalar>, isize)>>,
+    Point<Scalar>: Clone + Ord,
+    for<'a> &'a Point<Scalar>: Orient,
+    for<'a> &'a Segment<Scalar>: Segmental<Endpoint = &'a Point<Scalar>>,
+    for<'a> &'a Polygon<Scalar>: ToCorrectlyOrientedSegments<
+        Output = std::vec::IntoIter<Segment<Scalar>>,
+    >,
+{
+    type Output = Vec<Polygon<Scalar>>;
+
+    fn make_valid(self, fill_rule: FillRule) -> Self::Output {
+        segments_to_valid_polygons(
+            self.polygons
+                .iter()
+                .flat_map(ToCorrectlyOrientedSegments::to_correctly_oriented_segments)
+                .collect(),
+            fill_rule,
+        )
+    }
+}
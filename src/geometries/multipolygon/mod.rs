@@ -1,14 +1,20 @@
 pub use self::types::Multipolygon;
 
+mod area;
 mod bounded;
+mod centroid;
+mod convex_hull;
 mod difference;
 mod from;
 mod hash;
 mod intersection;
 mod locatable;
+mod make_valid;
 mod multipolygonal;
 mod partial_eq;
 mod relatable;
 mod symmetric_difference;
+mod to_distance_parts;
 mod types;
 mod union;
+mod validatable;
@@ -0,0 +1,31 @@
+use crate::distance::{DistanceSegment, ToDistanceParts};
+use crate::geometries::{Point, Polygon};
+use crate::locatable::{Locatable, Location};
+
+use super::types::Multipolygon;
+
+impl<Scalar> ToDistanceParts for Multipolygon<Scalar>
+where
+    Polygon<Scalar>: ToDistanceParts<Scalar = Scalar>,
+    for<'a> &'a Multipolygon<Scalar>: Locatable<&'a Point<Scalar>>,
+{
+    type Scalar = Scalar;
+
+    fn to_distance_segments(&self) -> Vec<DistanceSegment<'_, Scalar>> {
+        self.polygons
+            .iter()
+            .flat_map(ToDistanceParts::to_distance_segments)
+            .collect()
+    }
+
+    fn to_distance_probes(&self) -> Vec<&Point<Scalar>> {
+        self.polygons
+            .iter()
+            .flat_map(ToDistanceParts::to_distance_probes)
+            .collect()
+    }
+
+    fn covers_distance_probe(&self, point: &Point<Scalar>) -> bool {
+        self.locate(point) != Location::Exterior
+    }
+}
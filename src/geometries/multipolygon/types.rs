@@ -1,4 +1,5 @@
-use crate::geometries::Polygon;
+use crate::constants::MIN_MULTIPOLYGON_POLYGONS_COUNT;
+use crate::geometries::{ConstructionError, Polygon};
 
 #[derive(Clone)]
 pub struct Multipolygon<Scalar> {
@@ -10,4 +11,17 @@ impl<Scalar> Multipolygon<Scalar> {
     pub fn new(polygons: Vec<Polygon<Scalar>>) -> Self {
         Self { polygons }
     }
+
+    /// constructs multipolygon if it has enough polygons to be valid
+    pub fn try_new(
+        polygons: Vec<Polygon<Scalar>>,
+    ) -> Result<Self, ConstructionError> {
+        if polygons.len() < MIN_MULTIPOLYGON_POLYGONS_COUNT {
+            Err(ConstructionError::TooFewPolygons {
+                polygons_count: polygons.len(),
+            })
+        } else {
+            Ok(Self::new(polygons))
+        }
+    }
 }
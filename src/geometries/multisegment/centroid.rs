@@ -0,0 +1,39 @@
+use std::ops::{Add, Div};
+
+use traiter::numbers::{One, Zero};
+
+use crate::geometries::{Point, Segment};
+use crate::operations::halve;
+use crate::traits::{Centroid, Elemental, Segmental};
+
+use super::types::Multisegment;
+
+impl<Scalar> Centroid<Point<Scalar>> for &Multisegment<Scalar>
+where
+    Scalar: Add<Output = Scalar>
+        + for<'a> Add<&'a Scalar, Output = Scalar>
+        + Div<Output = Scalar>
+        + for<'a> Div<&'a Scalar, Output = Scalar>
+        + One
+        + Zero,
+    for<'a> &'a Point<Scalar>: Elemental<Coordinate = &'a Scalar>,
+    for<'a> &'a Segment<Scalar>: Segmental<Endpoint = &'a Point<Scalar>>,
+{
+    fn centroid(self) -> Point<Scalar> {
+        let (x_sum, y_sum, segments_count) = self.segments.iter().fold(
+            (Scalar::zero(), Scalar::zero(), Scalar::zero()),
+            |(x_sum, y_sum, segments_count), segment| {
+                let (start, end) = segment.endpoints();
+                (
+                    x_sum + start.x() + end.x(),
+                    y_sum + start.y() + end.y(),
+                    segments_count + Scalar::one(),
+                )
+            },
+        );
+        Point::new(
+            halve(x_sum / &segments_count),
+            halve(y_sum / segments_count),
+        )
+    }
+}
@@ -0,0 +1,25 @@
+use crate::convex_hull::{points_to_convex_hull, ConvexHull, ToConvexHull};
+use crate::geometries::{Point, Segment};
+use crate::operations::Orient;
+use crate::traits::Segmental;
+
+use super::types::Multisegment;
+
+impl<Scalar> ToConvexHull<Scalar> for &Multisegment<Scalar>
+where
+    Point<Scalar>: Clone + Ord,
+    for<'a> &'a Point<Scalar>: Orient,
+    for<'a> &'a Segment<Scalar>: Segmental<Endpoint = &'a Point<Scalar>>,
+{
+    fn to_convex_hull(self) -> ConvexHull<Scalar> {
+        points_to_convex_hull(
+            self.segments
+                .iter()
+                .flat_map(|segment| {
+                    let (start, end) = segment.endpoints();
+                    [start, end]
+                })
+                .collect(),
+        )
+    }
+}
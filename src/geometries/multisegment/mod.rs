@@ -1,6 +1,8 @@
 pub use self::types::Multisegment;
 
 mod bounded;
+mod centroid;
+mod convex_hull;
 mod difference;
 mod eq;
 mod from;
@@ -8,8 +10,12 @@ mod hash;
 mod intersection;
 mod locatable;
 mod multisegmental;
+mod noding;
 mod partial_eq;
+mod polygonization;
 mod relatable;
 mod symmetric_difference;
+mod to_distance_parts;
 mod types;
 mod union;
+mod validatable;
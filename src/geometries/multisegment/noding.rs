@@ -0,0 +1,19 @@
+use crate::bentley_ottmann::{Event, EventsRegistry};
+use crate::geometries::{Point, Segment};
+use crate::noding::ToNodedSegments;
+use crate::traits::Segmental;
+
+use super::types::Multisegment;
+
+impl<Scalar> ToNodedSegments<Scalar> for &Multisegment<Scalar>
+where
+    Point<Scalar>: Clone + PartialEq,
+    Segment<Scalar>: Clone,
+    for<'a> EventsRegistry<Point<Scalar>, true>:
+        From<&'a Vec<Segment<Scalar>>> + Iterator<Item = Event>,
+    for<'a> &'a Segment<Scalar>: Segmental<Endpoint = &'a Point<Scalar>>,
+{
+    fn to_noded_segments(self) -> Vec<Segment<Scalar>> {
+        self.segments.as_slice().to_noded_segments()
+    }
+}
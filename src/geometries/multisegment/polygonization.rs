@@ -0,0 +1,13 @@
+use crate::geometries::Segment;
+use crate::polygonization::{Polygonization, Polygonize};
+
+use super::types::Multisegment;
+
+impl<Scalar> Polygonize<Scalar> for &Multisegment<Scalar>
+where
+    for<'a> &'a [Segment<Scalar>]: Polygonize<Scalar>,
+{
+    fn polygonize(self) -> Polygonization<Scalar> {
+        self.segments.as_slice().polygonize()
+    }
+}
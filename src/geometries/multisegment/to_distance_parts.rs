@@ -0,0 +1,21 @@
+use crate::distance::{DistanceSegment, ToDistanceParts};
+use crate::geometries::Point;
+use crate::traits::Segmental;
+
+use super::types::Multisegment;
+
+impl<Scalar> ToDistanceParts for Multisegment<Scalar> {
+    type Scalar = Scalar;
+
+    fn to_distance_segments(&self) -> Vec<DistanceSegment<'_, Scalar>> {
+        self.segments.iter().map(Segmental::endpoints).collect()
+    }
+
+    fn to_distance_probes(&self) -> Vec<&Point<Scalar>> {
+        self.segments.iter().map(Segmental::start).collect()
+    }
+
+    fn covers_distance_probe(&self, _point: &Point<Scalar>) -> bool {
+        false
+    }
+}
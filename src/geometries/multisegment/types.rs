@@ -1,4 +1,5 @@
-use crate::geometries::Segment;
+use crate::constants::MIN_MULTISEGMENT_SEGMENTS_COUNT;
+use crate::geometries::{ConstructionError, Segment};
 
 #[derive(Clone)]
 pub struct Multisegment<Scalar> {
@@ -10,4 +11,17 @@ impl<Scalar> Multisegment<Scalar> {
     pub fn new(segments: Vec<Segment<Scalar>>) -> Self {
         Self { segments }
     }
+
+    /// constructs multisegment if it has enough segments to be valid
+    pub fn try_new(
+        segments: Vec<Segment<Scalar>>,
+    ) -> Result<Self, ConstructionError> {
+        if segments.len() < MIN_MULTISEGMENT_SEGMENTS_COUNT {
+            Err(ConstructionError::TooFewSegments {
+                segments_count: segments.len(),
+            })
+        } else {
+            Ok(Self::new(segments))
+        }
+    }
 }
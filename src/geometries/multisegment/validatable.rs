@@ -0,0 +1,26 @@
+use crate::bentley_ottmann::{
+    to_multisegment_validity_errors, Event, EventsRegistry,
+};
+use crate::geometries::{Point, Segment};
+use crate::operations::Orient;
+use crate::slice_sequence::SliceSequence;
+use crate::validatable::{Validatable, ValidityError};
+
+use super::types::Multisegment;
+
+impl<Scalar> Validatable<Point<Scalar>> for &Multisegment<Scalar>
+where
+    Point<Scalar>: Clone + Ord,
+    EventsRegistry<Point<Scalar>, false>: Iterator<Item = Event>
+        + for<'a, 'b> From<&'a SliceSequence<'b, Segment<Scalar>>>,
+    for<'a> &'a Point<Scalar>: Orient,
+{
+    fn validate(self) -> Result<(), Vec<ValidityError<Point<Scalar>>>> {
+        let errors = to_multisegment_validity_errors(self);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
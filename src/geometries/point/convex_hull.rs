@@ -0,0 +1,12 @@
+use crate::convex_hull::{ConvexHull, ToConvexHull};
+
+use super::types::Point;
+
+impl<Scalar> ToConvexHull<Scalar> for &Point<Scalar>
+where
+    Point<Scalar>: Clone,
+{
+    fn to_convex_hull(self) -> ConvexHull<Scalar> {
+        ConvexHull::Point(self.clone())
+    }
+}
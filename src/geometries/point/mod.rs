@@ -1,5 +1,6 @@
 pub use self::types::Point;
 
+mod convex_hull;
 mod display;
 mod elemental;
 mod eq;
@@ -8,4 +9,5 @@ mod hash;
 mod ord;
 mod partial_eq;
 mod partial_ord;
+mod to_distance_parts;
 mod types;
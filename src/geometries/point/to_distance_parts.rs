@@ -0,0 +1,19 @@
+use crate::distance::{DistanceSegment, ToDistanceParts};
+
+use super::types::Point;
+
+impl<Scalar> ToDistanceParts for Point<Scalar> {
+    type Scalar = Scalar;
+
+    fn to_distance_segments(&self) -> Vec<DistanceSegment<'_, Scalar>> {
+        vec![(self, self)]
+    }
+
+    fn to_distance_probes(&self) -> Vec<&Point<Scalar>> {
+        vec![self]
+    }
+
+    fn covers_distance_probe(&self, _point: &Point<Scalar>) -> bool {
+        false
+    }
+}
@@ -0,0 +1,22 @@
+use std::ops::Sub;
+
+use traiter::numbers::Abs;
+
+use crate::geometries::Contour;
+use crate::traits::Area;
+
+use super::types::Polygon;
+
+impl<Scalar> Area<Scalar> for &Polygon<Scalar>
+where
+    Scalar: Abs<Output = Scalar> + Sub<Output = Scalar>,
+    for<'a> &'a Contour<Scalar>: Area<Scalar>,
+{
+    fn area(self) -> Scalar {
+        self.holes
+            .iter()
+            .fold(self.border.area().abs(), |result, hole| {
+                result - hole.area().abs()
+            })
+    }
+}
@@ -0,0 +1,88 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use traiter::numbers::{One, Signed, Zero};
+
+use crate::geometries::{Contour, Point};
+use crate::operations::{
+    region_moments_to_centroid, to_region_signed_moments, CrossMultiply,
+    ToRegionMoments,
+};
+use crate::traits::{Centroid, Elemental, Multivertexal};
+
+use super::types::Polygon;
+
+impl<Scalar> Centroid<Point<Scalar>> for &Polygon<Scalar>
+where
+    Scalar: Add<Output = Scalar>
+        + for<'a> Add<&'a Scalar, Output = Scalar>
+        + Div<Output = Scalar>
+        + for<'a> Div<&'a Scalar, Output = Scalar>
+        + Mul<Output = Scalar>
+        + for<'a> Mul<&'a Scalar, Output = Scalar>
+        + Neg<Output = Scalar>
+        + One
+        + Sub<Output = Scalar>
+        + Zero,
+    for<'a> &'a Point<Scalar>:
+        CrossMultiply<Output = Scalar> + Elemental<Coordinate = &'a Scalar>,
+    for<'a> &'a Scalar: Add<Output = Scalar> + Signed,
+{
+    fn centroid(self) -> Point<Scalar> {
+        region_moments_to_centroid(self.to_region_moments())
+            .unwrap_or_else(|| self.border.centroid())
+    }
+}
+
+impl<Scalar> ToRegionMoments for &Polygon<Scalar>
+where
+    Scalar: Add<Output = Scalar>
+        + for<'a> Add<&'a Scalar, Output = Scalar>
+        + Mul<Output = Scalar>
+        + for<'a> Mul<&'a Scalar, Output = Scalar>
+        + Neg<Output = Scalar>
+        + Sub<Output = Scalar>
+        + Zero,
+    for<'a> &'a Point<Scalar>:
+        CrossMultiply<Output = Scalar> + Elemental<Coordinate = &'a Scalar>,
+    for<'a> &'a Scalar: Add<Output = Scalar> + Signed,
+{
+    type Output = (Scalar, Scalar, Scalar);
+
+    fn to_region_moments(self) -> Self::Output {
+        self.holes.iter().fold(
+            to_counterclockwise_contour_moments(&self.border),
+            |(doubled_area, x_moment, y_moment), hole| {
+                let (hole_doubled_area, hole_x_moment, hole_y_moment) =
+                    to_counterclockwise_contour_moments(hole);
+                (
+                    doubled_area - hole_doubled_area,
+                    x_moment - hole_x_moment,
+                    y_moment - hole_y_moment,
+                )
+            },
+        )
+    }
+}
+
+fn to_counterclockwise_contour_moments<Scalar>(
+    contour: &Contour<Scalar>,
+) -> (Scalar, Scalar, Scalar)
+where
+    Scalar: Add<Output = Scalar>
+        + for<'a> Add<&'a Scalar, Output = Scalar>
+        + Mul<Output = Scalar>
+        + for<'a> Mul<&'a Scalar, Output = Scalar>
+        + Neg<Output = Scalar>
+        + Zero,
+    for<'a> &'a Point<Scalar>:
+        CrossMultiply<Output = Scalar> + Elemental<Coordinate = &'a Scalar>,
+    for<'a> &'a Scalar: Add<Output = Scalar> + Signed,
+{
+    let (doubled_area, x_moment, y_moment) =
+        to_region_signed_moments(&contour.vertices());
+    if (&doubled_area).is_negative() {
+        (-doubled_area, -x_moment, -y_moment)
+    } else {
+        (doubled_area, x_moment, y_moment)
+    }
+}
@@ -0,0 +1,13 @@
+use crate::convex_hull::{ConvexHull, ToConvexHull};
+use crate::geometries::Contour;
+
+use super::types::Polygon;
+
+impl<Scalar> ToConvexHull<Scalar> for &Polygon<Scalar>
+where
+    for<'a> &'a Contour<Scalar>: ToConvexHull<Scalar>,
+{
+    fn to_convex_hull(self) -> ConvexHull<Scalar> {
+        self.border.to_convex_hull()
+    }
+}
@@ -1,16 +1,22 @@
 pub use self::types::Polygon;
 
+mod area;
 mod bounded;
+mod centroid;
+mod convex_hull;
 mod difference;
 mod eq;
 mod from;
 mod hash;
 mod intersection;
 mod locatable;
+mod make_valid;
 mod partial_eq;
 mod polygonal;
 mod relatable;
 mod symmetric_difference;
 mod to_correctly_oriented_segments;
+mod to_distance_parts;
 mod types;
 mod union;
+mod validatable;
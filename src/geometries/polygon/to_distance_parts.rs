@@ -0,0 +1,28 @@
+use crate::distance::{DistanceSegment, ToDistanceParts};
+use crate::geometries::Point;
+use crate::locatable::{Locatable, Location};
+
+use super::types::Polygon;
+
+impl<Scalar> ToDistanceParts for Polygon<Scalar>
+where
+    for<'a> &'a Polygon<Scalar>: Locatable<&'a Point<Scalar>>,
+{
+    type Scalar = Scalar;
+
+    fn to_distance_segments(&self) -> Vec<DistanceSegment<'_, Scalar>> {
+        let mut result = self.border.to_distance_segments();
+        for hole in &self.holes {
+            result.extend(hole.to_distance_segments());
+        }
+        result
+    }
+
+    fn to_distance_probes(&self) -> Vec<&Point<Scalar>> {
+        self.border.to_distance_probes()
+    }
+
+    fn covers_distance_probe(&self, point: &Point<Scalar>) -> bool {
+        self.locate(point) != Location::Exterior
+    }
+}
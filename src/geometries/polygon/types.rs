@@ -1,4 +1,6 @@
-use crate::geometries::Contour;
+use crate::constants::MIN_CONTOUR_VERTICES_COUNT;
+use crate::geometries::{ConstructionError, Contour};
+use crate::traits::{Lengthsome, Multivertexal};
 
 #[derive(Clone)]
 pub struct Polygon<Scalar> {
@@ -11,4 +13,34 @@ impl<Scalar> Polygon<Scalar> {
     pub fn new(border: Contour<Scalar>, holes: Vec<Contour<Scalar>>) -> Self {
         Self { border, holes }
     }
+
+    /// constructs polygon if its border & holes
+    /// have enough vertices to be valid
+    pub fn try_new(
+        border: Contour<Scalar>,
+        holes: Vec<Contour<Scalar>>,
+    ) -> Result<Self, ConstructionError> {
+        let to_vertices_count =
+            |contour: &Contour<Scalar>| contour.vertices().len();
+        let border_vertices_count = to_vertices_count(&border);
+        if border_vertices_count < MIN_CONTOUR_VERTICES_COUNT {
+            return Err(ConstructionError::Border(Box::new(
+                ConstructionError::TooFewVertices {
+                    vertices_count: border_vertices_count,
+                },
+            )));
+        }
+        for (hole_id, hole) in holes.iter().enumerate() {
+            let hole_vertices_count = to_vertices_count(hole);
+            if hole_vertices_count < MIN_CONTOUR_VERTICES_COUNT {
+                return Err(ConstructionError::Hole {
+                    hole_id,
+                    error: Box::new(ConstructionError::TooFewVertices {
+                        vertices_count: hole_vertices_count,
+                    }),
+                });
+            }
+        }
+        Ok(Self::new(border, holes))
+    }
 }
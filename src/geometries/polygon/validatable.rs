@@ -0,0 +1,45 @@
+use crate::bentley_ottmann::{
+    to_polygon_validity_errors, Event, EventsRegistry,
+};
+use crate::bounded::Bounded;
+use crate::geometries::{Contour, Point, Segment};
+use crate::operations::{
+    IntersectCrossingSegments, Orient, ToReversedSegments,
+};
+use crate::oriented::Oriented;
+use crate::relating::shaped;
+use crate::slice_sequence::SliceSequence;
+use crate::sweeping::traits::{EventsQueue, SweepLine};
+use crate::traits::Elemental;
+use crate::validatable::{Validatable, ValidityError};
+
+use super::types::Polygon;
+
+impl<Scalar: Ord> Validatable<Point<Scalar>> for &Polygon<Scalar>
+where
+    Point<Scalar>: Clone + Ord,
+    Segment<Scalar>: Clone,
+    EventsRegistry<Point<Scalar>, false>: Iterator<Item = Event>
+        + for<'a> From<&'a Vec<Segment<Scalar>>>
+        + for<'a, 'b> From<&'a SliceSequence<'b, Segment<Scalar>>>,
+    shaped::Operation<Point<Scalar>>:
+        EventsQueue<Event = Event> + SweepLine<Event = Event>,
+    for<'a> &'a Contour<Scalar>: Bounded<&'a Scalar>
+        + Oriented
+        + ToReversedSegments<Output = Vec<Segment<Scalar>>>,
+    for<'a> &'a Point<Scalar>: Elemental<Coordinate = &'a Scalar>
+        + IntersectCrossingSegments<Output = Point<Scalar>>
+        + Orient,
+{
+    fn validate(self) -> Result<(), Vec<ValidityError<Point<Scalar>>>> {
+        let errors = to_polygon_validity_errors(
+            &self.border,
+            &self.holes.iter().collect::<Vec<_>>(),
+        );
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
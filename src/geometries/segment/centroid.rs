@@ -0,0 +1,22 @@
+use std::ops::{Add, Div};
+
+use traiter::numbers::One;
+
+use crate::geometries::Point;
+use crate::operations::halve;
+use crate::traits::{Centroid, Elemental};
+
+use super::types::Segment;
+
+impl<Scalar> Centroid<Point<Scalar>> for &Segment<Scalar>
+where
+    Scalar: Add<Output = Scalar> + Div<Output = Scalar> + One,
+    for<'a> &'a Point<Scalar>: Elemental<Coordinate = &'a Scalar>,
+    for<'a> &'a Scalar: Add<Output = Scalar>,
+{
+    fn centroid(self) -> Point<Scalar> {
+        let (start_x, start_y) = (&self.start).coordinates();
+        let (end_x, end_y) = (&self.end).coordinates();
+        Point::new(halve(start_x + end_x), halve(start_y + end_y))
+    }
+}
@@ -1,6 +1,8 @@
 pub use types::Segment;
 
 mod bounded;
+mod centroid;
+mod convex_hull;
 mod difference;
 mod eq;
 mod from;
@@ -11,5 +13,6 @@ mod partial_eq;
 mod relatable;
 mod segmental;
 mod symmetric_difference;
+mod to_distance_parts;
 mod types;
 mod union;
@@ -0,0 +1,20 @@
+use crate::distance::{DistanceSegment, ToDistanceParts};
+use crate::geometries::Point;
+
+use super::types::Segment;
+
+impl<Scalar> ToDistanceParts for Segment<Scalar> {
+    type Scalar = Scalar;
+
+    fn to_distance_segments(&self) -> Vec<DistanceSegment<'_, Scalar>> {
+        vec![(&self.start, &self.end)]
+    }
+
+    fn to_distance_probes(&self) -> Vec<&Point<Scalar>> {
+        vec![&self.start]
+    }
+
+    fn covers_distance_probe(&self, _point: &Point<Scalar>) -> bool {
+        false
+    }
+}
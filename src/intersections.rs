@@ -0,0 +1,458 @@
+use crate::bentley_ottmann::{to_segments_intersections, Intersection, Sweep};
+use crate::geometries::{Contour, Multisegment, Point, Polygon, Segment};
+use crate::operations::{to_sorted_pair, IntersectCrossingSegments, Orient};
+use crate::oriented::Orientation;
+use crate::relatable::Relation;
+use crate::relating::segment_endpoints;
+use crate::traits::{Elemental, Multisegmental, Polygonal, Segmental};
+
+/// Intersection of a pair of segments.
+///
+/// ```
+/// use rene::geometries::{Point, Segment};
+/// use rene::intersections::{SegmentsIntersection, SegmentsIntersections};
+/// use rithm::big_int::BigInt;
+/// use rithm::fraction::Fraction;
+///
+/// type Scalar = Fraction<BigInt<u32, 31>>;
+///
+/// let to_point = |x: i32, y: i32| {
+///     Point::new(
+///         Scalar::from(BigInt::from(x)),
+///         Scalar::from(BigInt::from(y)),
+///     )
+/// };
+/// let segments = vec![
+///     Segment::new(to_point(0, 0), to_point(2, 2)),
+///     Segment::new(to_point(0, 2), to_point(2, 0)),
+///     Segment::new(to_point(1, 1), to_point(3, 3)),
+///     Segment::new(to_point(2, 0), to_point(3, 0)),
+/// ];
+/// let intersections = segments
+///     .as_slice()
+///     .segments_intersections()
+///     .collect::<Vec<_>>();
+/// assert_eq!(intersections.len(), 4);
+/// assert!(matches!(
+///     &intersections[0],
+///     (0, 1, SegmentsIntersection::Cross(point)) if point == &to_point(1, 1)
+/// ));
+/// assert!(matches!(
+///     &intersections[1],
+///     (0, 2, SegmentsIntersection::Overlap(segment))
+///     if segment == &Segment::new(to_point(1, 1), to_point(2, 2))
+/// ));
+/// assert!(matches!(
+///     &intersections[2],
+///     (1, 2, SegmentsIntersection::Touch(point)) if point == &to_point(1, 1)
+/// ));
+/// assert!(matches!(
+///     &intersections[3],
+///     (1, 3, SegmentsIntersection::Touch(point)) if point == &to_point(2, 0)
+/// ));
+/// ```
+#[derive(Clone)]
+pub enum SegmentsIntersection<Scalar> {
+    /// segments cross at a point interior to both of them
+    Cross(Point<Scalar>),
+    /// segments overlap along a segment
+    Overlap(Segment<Scalar>),
+    /// segments touch at an endpoint of at least one of them
+    Touch(Point<Scalar>),
+}
+
+pub trait SegmentsIntersections<Scalar> {
+    type Output: Iterator<Item = (usize, usize, SegmentsIntersection<Scalar>)>;
+
+    /// enumerates intersections of each pair of intersecting segments
+    /// along with their indices in ascending order,
+    /// pairs go in ascending order as well,
+    /// degenerate segments are skipped
+    fn segments_intersections(self) -> Self::Output;
+}
+
+impl<Scalar> SegmentsIntersections<Scalar> for &[Segment<Scalar>]
+where
+    Point<Scalar>: Clone + Ord,
+    Segment<Scalar>: Clone,
+    Sweep<Point<Scalar>>: for<'a> From<&'a Vec<Segment<Scalar>>>
+        + Iterator<Item = Intersection<Point<Scalar>>>,
+    for<'a> &'a Point<Scalar>: Orient,
+{
+    type Output =
+        std::vec::IntoIter<(usize, usize, SegmentsIntersection<Scalar>)>;
+
+    fn segments_intersections(self) -> Self::Output {
+        let segments_ids = (0..self.len())
+            .filter(|&segment_id| {
+                let (start, end) = (&self[segment_id]).endpoints();
+                start != end
+            })
+            .collect::<Vec<_>>();
+        let non_degenerate_segments = segments_ids
+            .iter()
+            .map(|&segment_id| self[segment_id].clone())
+            .collect::<Vec<_>>();
+        to_segments_intersections(&non_degenerate_segments)
+            .into_iter()
+            .map(|intersection| {
+                (
+                    segments_ids[intersection.first_segment_id],
+                    segments_ids[intersection.second_segment_id],
+                    match intersection.relation {
+                        Relation::Cross => {
+                            SegmentsIntersection::Cross(intersection.start)
+                        }
+                        Relation::Touch => {
+                            SegmentsIntersection::Touch(intersection.start)
+                        }
+                        _ => SegmentsIntersection::Overlap(Segment::new(
+                            intersection.start,
+                            intersection.end,
+                        )),
+                    },
+                )
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+/// Intersections between segments of two different sets.
+///
+/// ```
+/// use rene::geometries::{Contour, Multisegment, Point, Polygon, Segment};
+/// use rene::intersections::{
+///     SegmentsIntersection, SegmentsIntersectionsWith,
+/// };
+/// use rithm::big_int::BigInt;
+/// use rithm::fraction::Fraction;
+///
+/// type Scalar = Fraction<BigInt<u32, 31>>;
+///
+/// let to_point = |x: i32, y: i32| {
+///     Point::new(
+///         Scalar::from(BigInt::from(x)),
+///         Scalar::from(BigInt::from(y)),
+///     )
+/// };
+/// let multisegment = Multisegment::new(vec![
+///     Segment::new(to_point(0, 0), to_point(2, 2)),
+///     Segment::new(to_point(2, 2), to_point(4, 0)),
+/// ]);
+/// let polygon = Polygon::new(
+///     Contour::new(vec![
+///         to_point(1, 0),
+///         to_point(3, 0),
+///         to_point(3, 2),
+///         to_point(1, 2),
+///     ]),
+///     vec![],
+/// );
+/// let intersections = (&multisegment)
+///     .segments_intersections_with(&polygon)
+///     .collect::<Vec<_>>();
+/// assert_eq!(intersections.len(), 4);
+/// assert!(matches!(
+///     &intersections[0],
+///     (0, 2, SegmentsIntersection::Touch(point)) if point == &to_point(2, 2)
+/// ));
+/// assert!(matches!(
+///     &intersections[1],
+///     (0, 3, SegmentsIntersection::Cross(point)) if point == &to_point(1, 1)
+/// ));
+/// assert!(matches!(
+///     &intersections[2],
+///     (1, 1, SegmentsIntersection::Cross(point)) if point == &to_point(3, 1)
+/// ));
+/// assert!(matches!(
+///     &intersections[3],
+///     (1, 2, SegmentsIntersection::Touch(point)) if point == &to_point(2, 2)
+/// ));
+/// ```
+pub trait SegmentsIntersectionsWith<Other, Scalar> {
+    type Output: Iterator<Item = (usize, usize, SegmentsIntersection<Scalar>)>;
+
+    /// enumerates intersections of each pair of intersecting segments
+    /// with the first one from `self` and the second one from `other`
+    /// along with their indices within corresponding sets,
+    /// pairs go in ascending order,
+    /// intersections of segments within the same set
+    /// are neither reported nor computed,
+    /// so segments within each set may cross & overlap,
+    /// degenerate segments are skipped
+    fn segments_intersections_with(self, other: Other) -> Self::Output;
+}
+
+impl<Scalar> SegmentsIntersectionsWith<&[Segment<Scalar>], Scalar>
+    for &[Segment<Scalar>]
+where
+    Point<Scalar>: Clone + Ord,
+    Scalar: PartialOrd,
+    for<'a> &'a Point<Scalar>: Elemental<Coordinate = &'a Scalar>
+        + IntersectCrossingSegments<Output = Point<Scalar>>
+        + Orient,
+    for<'a> &'a Segment<Scalar>: Segmental<Endpoint = &'a Point<Scalar>>,
+{
+    type Output =
+        std::vec::IntoIter<(usize, usize, SegmentsIntersection<Scalar>)>;
+
+    fn segments_intersections_with(
+        self,
+        other: &[Segment<Scalar>],
+    ) -> Self::Output {
+        to_red_blue_segments_intersections_by_scan(
+            self.iter().collect(),
+            other.iter().collect(),
+        )
+    }
+}
+
+impl<Scalar> SegmentsIntersectionsWith<&Contour<Scalar>, Scalar>
+    for &Multisegment<Scalar>
+where
+    Point<Scalar>: Clone + Ord,
+    Scalar: PartialOrd,
+    for<'a> &'a Point<Scalar>: Elemental<Coordinate = &'a Scalar>
+        + IntersectCrossingSegments<Output = Point<Scalar>>
+        + Orient,
+    for<'a> &'a Segment<Scalar>: Segmental<Endpoint = &'a Point<Scalar>>,
+{
+    type Output =
+        std::vec::IntoIter<(usize, usize, SegmentsIntersection<Scalar>)>;
+
+    fn segments_intersections_with(
+        self,
+        other: &Contour<Scalar>,
+    ) -> Self::Output {
+        to_red_blue_segments_intersections_by_scan(
+            self.segments().into_iter().collect(),
+            other.segments().into_iter().collect(),
+        )
+    }
+}
+
+impl<Scalar> SegmentsIntersectionsWith<&Multisegment<Scalar>, Scalar>
+    for &Multisegment<Scalar>
+where
+    Point<Scalar>: Clone + Ord,
+    Scalar: PartialOrd,
+    for<'a> &'a Point<Scalar>: Elemental<Coordinate = &'a Scalar>
+        + IntersectCrossingSegments<Output = Point<Scalar>>
+        + Orient,
+    for<'a> &'a Segment<Scalar>: Segmental<Endpoint = &'a Point<Scalar>>,
+{
+    type Output =
+        std::vec::IntoIter<(usize, usize, SegmentsIntersection<Scalar>)>;
+
+    fn segments_intersections_with(
+        self,
+        other: &Multisegment<Scalar>,
+    ) -> Self::Output {
+        to_red_blue_segments_intersections_by_scan(
+            self.segments().into_iter().collect(),
+            other.segments().into_iter().collect(),
+        )
+    }
+}
+
+impl<Scalar> SegmentsIntersectionsWith<&Polygon<Scalar>, Scalar>
+    for &Multisegment<Scalar>
+where
+    Point<Scalar>: Clone + Ord,
+    Scalar: PartialOrd,
+    for<'a> &'a Point<Scalar>: Elemental<Coordinate = &'a Scalar>
+        + IntersectCrossingSegments<Output = Point<Scalar>>
+        + Orient,
+    for<'a> &'a Segment<Scalar>: Segmental<Endpoint = &'a Point<Scalar>>,
+{
+    type Output =
+        std::vec::IntoIter<(usize, usize, SegmentsIntersection<Scalar>)>;
+
+    /// polygon segments are indexed as border segments
+    /// followed by segments of holes in their order
+    fn segments_intersections_with(
+        self,
+        other: &Polygon<Scalar>,
+    ) -> Self::Output {
+        to_red_blue_segments_intersections_by_scan(
+            self.segments().into_iter().collect(),
+            other
+                .border()
+                .segments()
+                .into_iter()
+                .chain(
+                    other.holes().into_iter().flat_map(|hole| hole.segments()),
+                )
+                .collect(),
+        )
+    }
+}
+
+/// Brute-force scan of segments pairs pruned by their boxes:
+/// endpoints of segments from both sets are swept in lexicographic order
+/// keeping separate unordered lists of active segments for each set,
+/// so a segment is compared with every active segment of the other set
+/// whose vertical extent overlaps with its own one.
+/// No ordered status is kept since segments within each set
+/// may cross & overlap, so the worst case is quadratic
+/// (e.g. for long segments staying active during the whole sweep),
+/// but pairs from the same set are never compared
+/// & no segment is divided.
+fn to_red_blue_segments_intersections_by_scan<Scalar>(
+    first_segments: Vec<&Segment<Scalar>>,
+    second_segments: Vec<&Segment<Scalar>>,
+) -> std::vec::IntoIter<(usize, usize, SegmentsIntersection<Scalar>)>
+where
+    Point<Scalar>: Clone + Ord,
+    Scalar: PartialOrd,
+    for<'a> &'a Point<Scalar>: Elemental<Coordinate = &'a Scalar>
+        + IntersectCrossingSegments<Output = Point<Scalar>>
+        + Orient,
+    for<'a> &'a Segment<Scalar>: Segmental<Endpoint = &'a Point<Scalar>>,
+{
+    let first_segments_count = first_segments.len();
+    let segments_endpoints = first_segments
+        .into_iter()
+        .chain(second_segments)
+        .map(|segment| to_sorted_pair(segment.endpoints()))
+        .collect::<Vec<_>>();
+    let segments_vertical_extents = segments_endpoints
+        .iter()
+        .map(|(start, end)| to_sorted_pair((start.y(), end.y())))
+        .collect::<Vec<_>>();
+    let mut events = (0..segments_endpoints.len())
+        .filter(|&segment_id| {
+            let (start, end) = segments_endpoints[segment_id];
+            start != end
+        })
+        .flat_map(|segment_id| [(segment_id, false), (segment_id, true)])
+        .collect::<Vec<_>>();
+    let to_event_point = |(segment_id, is_right_event): (usize, bool)| {
+        let (start, end) = segments_endpoints[segment_id];
+        if is_right_event {
+            end
+        } else {
+            start
+        }
+    };
+    events.sort_unstable_by(|&first_event, &second_event| {
+        to_event_point(first_event)
+            .cmp(to_event_point(second_event))
+            .then(first_event.1.cmp(&second_event.1))
+    });
+    let mut operands_active_segments_ids = [Vec::new(), Vec::new()];
+    let mut active_segments_positions = vec![0; segments_endpoints.len()];
+    let mut result = Vec::new();
+    for (segment_id, is_right_event) in events {
+        let operand_id = usize::from(segment_id >= first_segments_count);
+        if is_right_event {
+            let active_segments_ids =
+                &mut operands_active_segments_ids[operand_id];
+            let position = active_segments_positions[segment_id];
+            active_segments_ids.swap_remove(position);
+            if let Some(&moved_segment_id) = active_segments_ids.get(position)
+            {
+                active_segments_positions[moved_segment_id] = position;
+            }
+        } else {
+            let (min_y, max_y) = segments_vertical_extents[segment_id];
+            for &other_segment_id in
+                &operands_active_segments_ids[1 - operand_id]
+            {
+                let (other_min_y, other_max_y) =
+                    segments_vertical_extents[other_segment_id];
+                if other_max_y < min_y || max_y < other_min_y {
+                    continue;
+                }
+                if let Some(intersection) = to_segments_intersection(
+                    segments_endpoints[segment_id],
+                    segments_endpoints[other_segment_id],
+                ) {
+                    let (first_segment_id, second_segment_id) =
+                        if operand_id == 0 {
+                            (segment_id, other_segment_id)
+                        } else {
+                            (other_segment_id, segment_id)
+                        };
+                    result.push((
+                        first_segment_id,
+                        second_segment_id - first_segments_count,
+                        intersection,
+                    ));
+                }
+            }
+            let active_segments_ids =
+                &mut operands_active_segments_ids[operand_id];
+            active_segments_positions[segment_id] = active_segments_ids.len();
+            active_segments_ids.push(segment_id);
+        }
+    }
+    result.sort_unstable_by_key(
+        |&(first_segment_id, second_segment_id, _)| {
+            (first_segment_id, second_segment_id)
+        },
+    );
+    result.into_iter()
+}
+
+fn to_segments_intersection<Scalar>(
+    (first_start, first_end): (&Point<Scalar>, &Point<Scalar>),
+    (second_start, second_end): (&Point<Scalar>, &Point<Scalar>),
+) -> Option<SegmentsIntersection<Scalar>>
+where
+    Point<Scalar>: Clone + Ord,
+    for<'a> &'a Point<Scalar>:
+        IntersectCrossingSegments<Output = Point<Scalar>> + Orient,
+{
+    match segment_endpoints::relate_to_segment_endpoints(
+        (first_start, first_end),
+        (second_start, second_end),
+    ) {
+        Relation::Disjoint => None,
+        Relation::Cross => Some(SegmentsIntersection::Cross(
+            IntersectCrossingSegments::intersect_crossing_segments(
+                first_start,
+                first_end,
+                second_start,
+                second_end,
+            ),
+        )),
+        Relation::Touch => Some(SegmentsIntersection::Touch(
+            [second_start, second_end]
+                .iter()
+                .copied()
+                .find(|point| {
+                    is_point_in_sorted_segment(point, first_start, first_end)
+                })
+                .or_else(|| {
+                    [first_start, first_end].iter().copied().find(|point| {
+                        is_point_in_sorted_segment(
+                            point,
+                            second_start,
+                            second_end,
+                        )
+                    })
+                })
+                .cloned()
+                .unwrap_or_else(|| unreachable!()),
+        )),
+        _ => Some(SegmentsIntersection::Overlap(Segment::new(
+            first_start.max(second_start).clone(),
+            first_end.min(second_end).clone(),
+        ))),
+    }
+}
+
+fn is_point_in_sorted_segment<Point: PartialOrd>(
+    point: &Point,
+    start: &Point,
+    end: &Point,
+) -> bool
+where
+    for<'a> &'a Point: Orient,
+{
+    start <= point
+        && point <= end
+        && end.orient(start, point) == Orientation::Collinear
+}
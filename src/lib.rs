@@ -3,16 +3,21 @@ mod binding;
 pub mod bounded;
 mod clipping;
 mod constants;
-mod contracts;
+pub mod convex_hull;
+pub mod distance;
 pub mod geometries;
+pub mod intersections;
 mod iteration;
 pub mod locatable;
+pub mod noding;
 mod operations;
 pub mod oriented;
+pub mod polygonization;
 pub mod relatable;
 mod relating;
-mod seidel;
+pub mod seidel;
 mod slice_sequence;
 mod sweeping;
 pub mod traits;
-mod triangulation;
+pub mod triangulation;
+pub mod validatable;
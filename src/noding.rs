@@ -0,0 +1,72 @@
+use crate::bentley_ottmann::{
+    to_unique_non_crossing_or_overlapping_segments, Event, EventsRegistry,
+};
+use crate::geometries::{Point, Segment};
+use crate::traits::Segmental;
+
+/// Noding of segments.
+///
+/// ```
+/// use rene::geometries::{Multisegment, Point, Segment};
+/// use rene::noding::ToNodedSegments;
+/// use rithm::big_int::BigInt;
+/// use rithm::fraction::Fraction;
+///
+/// type Scalar = Fraction<BigInt<u32, 31>>;
+///
+/// let to_point = |x: i32, y: i32| {
+///     Point::new(
+///         Scalar::from(BigInt::from(x)),
+///         Scalar::from(BigInt::from(y)),
+///     )
+/// };
+/// let multisegment = Multisegment::new(vec![
+///     Segment::new(to_point(0, 0), to_point(2, 2)),
+///     Segment::new(to_point(0, 2), to_point(2, 0)),
+///     Segment::new(to_point(1, 1), to_point(3, 3)),
+/// ]);
+/// let noded_segments = (&multisegment).to_noded_segments();
+/// assert_eq!(noded_segments.len(), 5);
+/// assert!([
+///     Segment::new(to_point(0, 0), to_point(1, 1)),
+///     Segment::new(to_point(0, 2), to_point(1, 1)),
+///     Segment::new(to_point(1, 1), to_point(2, 0)),
+///     Segment::new(to_point(1, 1), to_point(2, 2)),
+///     Segment::new(to_point(2, 2), to_point(3, 3)),
+/// ]
+/// .iter()
+/// .all(|segment| noded_segments.contains(segment)));
+/// ```
+pub trait ToNodedSegments<Scalar> {
+    /// splits segments at all their intersection points
+    /// merging overlapping pieces,
+    /// so resulting segments are unique & touch only at endpoints,
+    /// degenerate segments are skipped,
+    /// segments are returned instead of a multisegment
+    /// (like polygons are by unions of multipolygons)
+    /// since less than two of them may be left,
+    /// so `Multisegment::try_new` should be used to build one
+    fn to_noded_segments(self) -> Vec<Segment<Scalar>>;
+}
+
+impl<Scalar> ToNodedSegments<Scalar> for &[Segment<Scalar>]
+where
+    Point<Scalar>: Clone + PartialEq,
+    Segment<Scalar>: Clone,
+    for<'a> EventsRegistry<Point<Scalar>, true>:
+        From<&'a Vec<Segment<Scalar>>> + Iterator<Item = Event>,
+    for<'a> &'a Segment<Scalar>: Segmental<Endpoint = &'a Point<Scalar>>,
+{
+    fn to_noded_segments(self) -> Vec<Segment<Scalar>> {
+        to_unique_non_crossing_or_overlapping_segments(
+            &self
+                .iter()
+                .filter(|segment| {
+                    let (start, end) = segment.endpoints();
+                    start != end
+                })
+                .cloned()
+                .collect::<Vec<_>>(),
+        )
+    }
+}
@@ -1,6 +1,8 @@
 use std::ops::{Add, Div, Mul, Sub};
 
-use traiter::numbers::{BitLength, IsPowerOfTwo, One, Sign, Signed};
+use traiter::numbers::{
+    BitLength, IsPowerOfTwo, One, Sign, Signed, Zero, Zeroable,
+};
 
 use crate::bounded;
 use crate::constants::MIN_CONTOUR_VERTICES_COUNT;
@@ -11,10 +13,10 @@ use crate::traits::{
     Contoural, Elemental, Iterable, Lengthsome, Multisegmental,
     MultisegmentalIndexSegment, MultivertexalIndexVertex, Polygonal,
     PolygonalContour, PolygonalIndexHole, PolygonalIntoIteratorHole,
-    Segmental, SegmentalCoordinate,
+    Segmental, SegmentalCoordinate, Sequence,
 };
 
-pub(crate) trait CrossMultiply {
+pub trait CrossMultiply {
     type Output;
 
     fn cross_multiply(
@@ -25,7 +27,7 @@ pub(crate) trait CrossMultiply {
     ) -> Self::Output;
 }
 
-pub(crate) trait DotMultiply {
+pub trait DotMultiply {
     type Output;
 
     fn dot_multiply(
@@ -80,7 +82,7 @@ where
     }
 }
 
-pub(crate) trait LocatePointInPointPointPointCircle {
+pub trait LocatePointInPointPointPointCircle {
     fn locate_point_in_point_point_point_circle(
         self,
         first: Self,
@@ -89,7 +91,7 @@ pub(crate) trait LocatePointInPointPointPointCircle {
     ) -> Location;
 }
 
-pub(crate) trait Orient {
+pub trait Orient {
     fn orient(
         self,
         first_ray_point: Self,
@@ -128,6 +130,14 @@ pub(crate) trait ToCorrectlyOrientedSegments {
     fn to_correctly_oriented_segments(self) -> Self::Output;
 }
 
+pub(crate) trait ToRegionMoments {
+    type Output;
+
+    /// Returns doubled area of the region along with its first moments
+    /// multiplied by 6, holes are subtracted regardless of orientation.
+    fn to_region_moments(self) -> Self::Output;
+}
+
 pub(crate) trait ToReversedSegments {
     type Output;
 
@@ -140,7 +150,7 @@ pub(crate) trait Square {
     fn square(self) -> Self::Output;
 }
 
-pub(crate) trait SquaredMetric<Other = Self> {
+pub trait SquaredMetric<Other = Self> {
     type Output;
 
     fn squared_distance_to(self, other: Other) -> Self::Output;
@@ -227,6 +237,14 @@ pub(crate) fn flags_to_true_indices(flags: &[bool]) -> Vec<usize> {
         .collect::<Vec<_>>()
 }
 
+pub(crate) fn halve<
+    Scalar: Add<Output = Scalar> + Div<Output = Scalar> + One,
+>(
+    value: Scalar,
+) -> Scalar {
+    value / (Scalar::one() + Scalar::one())
+}
+
 pub(crate) fn is_point_in_segment<'a, Point: PartialEq>(
     point: &'a Point,
     start: &'a Point,
@@ -376,6 +394,26 @@ where
         == vertex.orient(point, second_ray_point)
 }
 
+pub(crate) fn region_moments_to_centroid<Point, Scalar>(
+    (doubled_area, x_moment, y_moment): (Scalar, Scalar, Scalar),
+) -> Option<Point>
+where
+    Point: From<(Scalar, Scalar)>,
+    Scalar: Add<Output = Scalar>
+        + Div<Output = Scalar>
+        + for<'a> Div<&'a Scalar, Output = Scalar>
+        + Mul<Output = Scalar>
+        + One,
+    for<'a> &'a Scalar: Zeroable,
+{
+    if (&doubled_area).is_zero() {
+        return None;
+    }
+    let divisor =
+        doubled_area * (Scalar::one() + Scalar::one() + Scalar::one());
+    Some(Point::from((x_moment / &divisor, y_moment / divisor)))
+}
+
 pub(crate) fn segmental_to_bounds<Segment: Segmental>(
     segment: Segment,
 ) -> (
@@ -450,6 +488,30 @@ pub(crate) fn to_arg_min<Value: Ord>(values: &[Value]) -> Option<usize> {
     (0..values.len()).min_by_key(|index| &values[*index])
 }
 
+pub(crate) fn to_boxes_squared_distance<'a, Scalar>(
+    first: &bounded::Box<&'a Scalar>,
+    second: &bounded::Box<&'a Scalar>,
+) -> Scalar
+where
+    Scalar: Add<Output = Scalar> + PartialOrd + Square<Output = Scalar> + Zero,
+    &'a Scalar: Sub<Output = Scalar>,
+{
+    to_intervals_gap(
+        first.get_min_x(),
+        first.get_max_x(),
+        second.get_min_x(),
+        second.get_max_x(),
+    )
+    .square()
+        + to_intervals_gap(
+            first.get_min_y(),
+            first.get_max_y(),
+            second.get_min_y(),
+            second.get_max_y(),
+        )
+        .square()
+}
+
 pub(crate) fn to_boxes_ids_with_common_area<Scalar>(
     boxes: &[bounded::Box<Scalar>],
     target_box: &bounded::Box<Scalar>,
@@ -488,6 +550,44 @@ where
         .collect::<Vec<_>>()
 }
 
+pub(crate) fn to_boxes_ids_within_squared_distance<'a, Scalar>(
+    boxes: &[bounded::Box<&'a Scalar>],
+    target_box: &bounded::Box<&'a Scalar>,
+    squared_distance: &Scalar,
+) -> Vec<usize>
+where
+    Scalar: Add<Output = Scalar> + PartialOrd + Square<Output = Scalar> + Zero,
+    &'a Scalar: Sub<Output = Scalar>,
+{
+    (0..boxes.len())
+        .filter(|&index| {
+            &to_boxes_squared_distance(&boxes[index], target_box)
+                <= squared_distance
+        })
+        .collect::<Vec<_>>()
+}
+
+pub(crate) fn to_doubled_signed_area<Point, Scalar>(
+    vertices: &[Point],
+) -> Scalar
+where
+    Scalar: Add<Output = Scalar> + Zero,
+    for<'a> &'a Point: CrossMultiply<Output = Scalar>,
+{
+    let first_vertex = &vertices[0];
+    vertices[1..]
+        .windows(2)
+        .fold(Scalar::zero(), |result, edge_vertices| {
+            result
+                + CrossMultiply::cross_multiply(
+                    first_vertex,
+                    &edge_vertices[0],
+                    first_vertex,
+                    &edge_vertices[1],
+                )
+        })
+}
+
 pub(crate) trait SegmentsCountable {
     fn segments_count(self) -> usize;
 }
@@ -519,6 +619,64 @@ where
     }
 }
 
+/// Returns doubled signed area of the region bounded by the contour
+/// with given vertices along with its first moments multiplied by 6.
+pub(crate) fn to_region_signed_moments<Point, Scalar, Vertices>(
+    vertices: &Vertices,
+) -> (Scalar, Scalar, Scalar)
+where
+    Scalar: Add<Output = Scalar>
+        + for<'a> Add<&'a Scalar, Output = Scalar>
+        + Mul<Output = Scalar>
+        + for<'a> Mul<&'a Scalar, Output = Scalar>
+        + Zero,
+    Vertices: Sequence<IndexItem = Point>,
+    for<'a> &'a Point:
+        CrossMultiply<Output = Scalar> + Elemental<Coordinate = &'a Scalar>,
+    for<'a> &'a Scalar: Add<Output = Scalar>,
+{
+    let first_vertex = &vertices[0];
+    let (first_x, first_y) = first_vertex.coordinates();
+    let (mut doubled_area, mut x_moment, mut y_moment) =
+        (Scalar::zero(), Scalar::zero(), Scalar::zero());
+    for index in 1..vertices.len() - 1 {
+        let (vertex, next_vertex) = (&vertices[index], &vertices[index + 1]);
+        let cross_product = CrossMultiply::cross_multiply(
+            first_vertex,
+            vertex,
+            first_vertex,
+            next_vertex,
+        );
+        x_moment = x_moment
+            + (first_x + vertex.x() + next_vertex.x()) * &cross_product;
+        y_moment = y_moment
+            + (first_y + vertex.y() + next_vertex.y()) * &cross_product;
+        doubled_area = doubled_area + cross_product;
+    }
+    (doubled_area, x_moment, y_moment)
+}
+
+/// Returns distance between given intervals on the same axis,
+/// which is zero if they intersect.
+fn to_intervals_gap<'a, Scalar>(
+    first_min: &'a Scalar,
+    first_max: &'a Scalar,
+    second_min: &'a Scalar,
+    second_max: &'a Scalar,
+) -> Scalar
+where
+    Scalar: PartialOrd + Zero,
+    &'a Scalar: Sub<Output = Scalar>,
+{
+    if first_max < second_min {
+        second_min - first_max
+    } else if second_max < first_min {
+        first_min - second_max
+    } else {
+        Scalar::zero()
+    }
+}
+
 pub(crate) fn to_segments_intersection_scale<Point, Scalar>(
     first_start: &Point,
     first_end: &Point,
@@ -552,3 +710,40 @@ pub(crate) fn to_sorted_pair<Value: PartialOrd>(
         (right, left)
     }
 }
+
+/// Returns root of the disjoint set containing given node
+/// halving paths along the way.
+pub(crate) fn to_union_find_root(
+    parents: &mut [usize],
+    mut node: usize,
+) -> usize {
+    while parents[node] != node {
+        parents[node] = parents[parents[node]];
+        node = parents[node];
+    }
+    node
+}
+
+pub(crate) fn vertices_to_centroid<'a, Point, Scalar>(
+    vertices: impl Iterator<Item = &'a Point>,
+) -> Point
+where
+    Point: From<(Scalar, Scalar)> + 'a,
+    Scalar: Add<Output = Scalar>
+        + for<'b> Add<&'b Scalar, Output = Scalar>
+        + Div<Output = Scalar>
+        + for<'b> Div<&'b Scalar, Output = Scalar>
+        + One
+        + Zero
+        + 'a,
+    &'a Point: Elemental<Coordinate = &'a Scalar>,
+{
+    let (x_sum, y_sum, vertices_count) = vertices.fold(
+        (Scalar::zero(), Scalar::zero(), Scalar::zero()),
+        |(x_sum, y_sum, vertices_count), vertex| {
+            let (x, y) = vertex.coordinates();
+            (x_sum + x, y_sum + y, vertices_count + Scalar::one())
+        },
+    );
+    Point::from((x_sum / &vertices_count, y_sum / vertices_count))
+}
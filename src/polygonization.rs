@@ -0,0 +1,397 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::ops::Add;
+
+use traiter::numbers::Zero;
+
+use crate::geometries::{Contour, Point, Polygon, Segment};
+use crate::locatable::Location;
+use crate::noding::ToNodedSegments;
+use crate::operations::{
+    locate_point_in_region, shrink_collinear_vertices, to_doubled_signed_area,
+    CrossMultiply, Orient,
+};
+use crate::oriented::Orientation;
+use crate::traits::{Elemental, Segmental};
+
+/// Faces of a planar graph formed by segments.
+///
+/// ```
+/// use rene::geometries::{Contour, Point, Polygon, Segment};
+/// use rene::polygonization::Polygonize;
+/// use rithm::big_int::BigInt;
+/// use rithm::fraction::Fraction;
+///
+/// type Scalar = Fraction<BigInt<u32, 31>>;
+///
+/// let to_point = |x: i32, y: i32| {
+///     Point::new(
+///         Scalar::from(BigInt::from(x)),
+///         Scalar::from(BigInt::from(y)),
+///     )
+/// };
+/// let segments = vec![
+///     Segment::new(to_point(0, 0), to_point(4, 0)),
+///     Segment::new(to_point(4, 0), to_point(4, 4)),
+///     Segment::new(to_point(4, 4), to_point(0, 4)),
+///     Segment::new(to_point(0, 4), to_point(0, 0)),
+///     Segment::new(to_point(2, 0), to_point(2, 4)),
+///     Segment::new(to_point(4, 2), to_point(6, 2)),
+///     Segment::new(to_point(6, 0), to_point(6, 4)),
+///     Segment::new(to_point(6, 0), to_point(8, 0)),
+///     Segment::new(to_point(8, 0), to_point(6, 4)),
+/// ];
+/// let polygonization = segments.as_slice().polygonize();
+/// assert_eq!(polygonization.get_polygons().len(), 3);
+/// assert!(polygonization.get_polygons().contains(&Polygon::new(
+///     Contour::new(vec![
+///         to_point(0, 0),
+///         to_point(2, 0),
+///         to_point(2, 4),
+///         to_point(0, 4),
+///     ]),
+///     vec![],
+/// )));
+/// assert!(polygonization.get_polygons().contains(&Polygon::new(
+///     Contour::new(vec![to_point(6, 0), to_point(8, 0), to_point(6, 4)]),
+///     vec![],
+/// )));
+/// assert!(polygonization.get_dangles().is_empty());
+/// assert!(polygonization.get_cut_edges()
+///     == [Segment::new(to_point(4, 2), to_point(6, 2))]);
+/// ```
+#[derive(Clone)]
+pub struct Polygonization<Scalar> {
+    cut_edges: Vec<Segment<Scalar>>,
+    dangles: Vec<Segment<Scalar>>,
+    polygons: Vec<Polygon<Scalar>>,
+}
+
+impl<Scalar> Polygonization<Scalar> {
+    /// pieces of segments with the same face on both sides,
+    /// connecting faces without bounding any of them
+    pub fn get_cut_edges(&self) -> &[Segment<Scalar>] {
+        &self.cut_edges
+    }
+
+    /// pieces of segments with a free end
+    /// after repeated removal of such pieces
+    pub fn get_dangles(&self) -> &[Segment<Scalar>] {
+        &self.dangles
+    }
+
+    /// bounded faces with counterclockwise borders
+    /// & clockwise holes, adjacent faces touch along borders
+    pub fn get_polygons(&self) -> &[Polygon<Scalar>] {
+        &self.polygons
+    }
+}
+
+pub trait Polygonize<Scalar> {
+    /// nodes segments & splits the plane by them into faces,
+    /// degenerate segments are skipped
+    fn polygonize(self) -> Polygonization<Scalar>;
+}
+
+impl<Scalar> Polygonize<Scalar> for &[Segment<Scalar>]
+where
+    Point<Scalar>: Clone + Ord,
+    Scalar: Add<Output = Scalar> + PartialOrd + Zero,
+    Segment<Scalar>: Clone,
+    for<'a> &'a [Segment<Scalar>]: ToNodedSegments<Scalar>,
+    for<'a> &'a Point<Scalar>: CrossMultiply<Output = Scalar>
+        + Elemental<Coordinate = &'a Scalar>
+        + Orient,
+    for<'a> &'a Segment<Scalar>: Segmental<Endpoint = &'a Point<Scalar>>,
+{
+    fn polygonize(self) -> Polygonization<Scalar> {
+        let segments = self.to_noded_segments();
+        let mut vertices = segments
+            .iter()
+            .flat_map(|segment| {
+                let (start, end) = segment.endpoints();
+                [start, end]
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        vertices.sort();
+        vertices.dedup();
+        let to_vertex_id = |point: &Point<Scalar>| unsafe {
+            vertices.binary_search(point).unwrap_unchecked()
+        };
+        let edges = segments
+            .iter()
+            .map(|segment| {
+                let (start, end) = segment.endpoints();
+                (to_vertex_id(start), to_vertex_id(end))
+            })
+            .collect::<Vec<_>>();
+        let mut are_edges_removed = vec![false; edges.len()];
+        let dangles_ids =
+            remove_dangles(vertices.len(), &edges, &mut are_edges_removed);
+        let mut walks = to_faces_walks(&vertices, &edges, &are_edges_removed);
+        let mut cut_edges_ids = Vec::new();
+        for walk in &walks {
+            let mut walk_edges_ids =
+                walk.iter().map(|&(edge_id, _)| edge_id).collect::<Vec<_>>();
+            walk_edges_ids.sort_unstable();
+            cut_edges_ids.extend(
+                walk_edges_ids
+                    .windows(2)
+                    .filter(|ids| ids[0] == ids[1])
+                    .map(|ids| ids[0]),
+            );
+        }
+        if !cut_edges_ids.is_empty() {
+            for &edge_id in &cut_edges_ids {
+                are_edges_removed[edge_id] = true;
+            }
+            walks = to_faces_walks(&vertices, &edges, &are_edges_removed);
+        }
+        let mut shells = Vec::<Contour<Scalar>>::new();
+        let mut shells_doubled_areas = Vec::<Scalar>::new();
+        let mut shells_holes = Vec::<Vec<Contour<Scalar>>>::new();
+        let mut components_holes = Vec::<(usize, Vec<Contour<Scalar>>)>::new();
+        for walk in walks {
+            let walk_vertices_ids = walk
+                .into_iter()
+                .map(|(_, vertex_id)| vertex_id)
+                .collect::<Vec<_>>();
+            let mut shell = None;
+            let mut holes = Vec::new();
+            for cycle in split_walk(&walk_vertices_ids) {
+                let (orientation, cycle_vertices) =
+                    to_oriented_cycle_vertices(&cycle, &vertices);
+                if orientation == Orientation::Counterclockwise {
+                    debug_assert!(shell.is_none());
+                    shell = Some(cycle_vertices);
+                } else {
+                    holes.push(Contour::new(cycle_vertices));
+                }
+            }
+            if let Some(shell_vertices) = shell {
+                shells_doubled_areas
+                    .push(to_doubled_signed_area(&shell_vertices));
+                shells.push(Contour::new(shell_vertices));
+                shells_holes.push(holes);
+            } else {
+                components_holes.push((walk_vertices_ids[0], holes));
+            }
+        }
+        for (vertex_id, holes) in components_holes {
+            let vertex = &vertices[vertex_id];
+            if let Some(shell_id) = (0..shells.len())
+                .filter(|&shell_id| {
+                    locate_point_in_region(&shells[shell_id], vertex)
+                        == Location::Interior
+                })
+                .min_by(|&first_id, &second_id| {
+                    shells_doubled_areas[first_id]
+                        .partial_cmp(&shells_doubled_areas[second_id])
+                        .unwrap_or(Ordering::Equal)
+                })
+            {
+                shells_holes[shell_id].extend(holes);
+            }
+        }
+        Polygonization {
+            cut_edges: cut_edges_ids
+                .into_iter()
+                .map(|edge_id| segments[edge_id].clone())
+                .collect(),
+            dangles: dangles_ids
+                .into_iter()
+                .map(|edge_id| segments[edge_id].clone())
+                .collect(),
+            polygons: shells
+                .into_iter()
+                .zip(shells_holes)
+                .map(|(shell, holes)| Polygon::new(shell, holes))
+                .collect(),
+        }
+    }
+}
+
+/// Returns orientation of a simple cycle of vertices
+/// along with its vertices without collinear ones.
+fn to_oriented_cycle_vertices<Scalar>(
+    cycle: &[usize],
+    vertices: &[Point<Scalar>],
+) -> (Orientation, Vec<Point<Scalar>>)
+where
+    Point<Scalar>: Clone + Ord,
+    for<'a> &'a Point<Scalar>: Orient,
+{
+    let min_position = unsafe {
+        (0..cycle.len())
+            .min_by_key(|&position| &vertices[cycle[position]])
+            .unwrap_unchecked()
+    };
+    let cycle_vertices = cycle[min_position..]
+        .iter()
+        .chain(&cycle[..min_position])
+        .map(|&vertex_id| &vertices[vertex_id])
+        .collect::<Vec<_>>();
+    // minimal vertex can not be collinear with its neighbours
+    let orientation = cycle_vertices[cycle_vertices.len() - 1]
+        .orient(cycle_vertices[0], cycle_vertices[1]);
+    (
+        orientation,
+        shrink_collinear_vertices(&cycle_vertices)
+            .into_iter()
+            .cloned()
+            .collect(),
+    )
+}
+
+/// Removes edges with a free end until there are none left,
+/// returns ids of removed edges.
+fn remove_dangles(
+    vertices_count: usize,
+    edges: &[(usize, usize)],
+    are_edges_removed: &mut [bool],
+) -> Vec<usize> {
+    let mut vertices_edges_ids = vec![Vec::new(); vertices_count];
+    for (edge_id, &(start_id, end_id)) in edges.iter().enumerate() {
+        vertices_edges_ids[start_id].push(edge_id);
+        vertices_edges_ids[end_id].push(edge_id);
+    }
+    let mut degrees = vertices_edges_ids
+        .iter()
+        .map(|edges_ids| edges_ids.len())
+        .collect::<Vec<_>>();
+    let mut candidates_ids = (0..vertices_count)
+        .filter(|&vertex_id| degrees[vertex_id] == 1)
+        .collect::<Vec<_>>();
+    let mut result = Vec::new();
+    while let Some(vertex_id) = candidates_ids.pop() {
+        if degrees[vertex_id] != 1 {
+            continue;
+        }
+        let edge_id = unsafe {
+            vertices_edges_ids[vertex_id]
+                .iter()
+                .copied()
+                .find(|&edge_id| !are_edges_removed[edge_id])
+                .unwrap_unchecked()
+        };
+        are_edges_removed[edge_id] = true;
+        result.push(edge_id);
+        let (start_id, end_id) = edges[edge_id];
+        let other_vertex_id = if start_id == vertex_id {
+            end_id
+        } else {
+            start_id
+        };
+        degrees[vertex_id] = 0;
+        degrees[other_vertex_id] -= 1;
+        if degrees[other_vertex_id] == 1 {
+            candidates_ids.push(other_vertex_id);
+        }
+    }
+    result
+}
+
+/// Splits closed walk into simple cycles at repeated vertices.
+fn split_walk(walk: &[usize]) -> Vec<Vec<usize>> {
+    let mut result = Vec::new();
+    let mut positions = BTreeMap::<usize, usize>::new();
+    let mut stack = Vec::<usize>::with_capacity(walk.len());
+    for &vertex_id in walk {
+        if let Some(&position) = positions.get(&vertex_id) {
+            for vertex_id in &stack[position + 1..] {
+                positions.remove(vertex_id);
+            }
+            result.push(stack[position..].to_vec());
+            stack.truncate(position + 1);
+        } else {
+            positions.insert(vertex_id, stack.len());
+            stack.push(vertex_id);
+        }
+    }
+    result.push(stack);
+    result
+}
+
+/// Returns closed walks along boundaries of faces
+/// with faces lying to the left,
+/// each step is given by an edge id & a vertex id it starts from.
+fn to_faces_walks<Point: Ord>(
+    vertices: &[Point],
+    edges: &[(usize, usize)],
+    are_edges_removed: &[bool],
+) -> Vec<Vec<(usize, usize)>>
+where
+    for<'a> &'a Point: Orient,
+{
+    let mut vertices_neighbours =
+        vec![Vec::<(usize, usize)>::new(); vertices.len()];
+    for (edge_id, &(start_id, end_id)) in edges.iter().enumerate() {
+        if !are_edges_removed[edge_id] {
+            vertices_neighbours[start_id].push((end_id, edge_id));
+            vertices_neighbours[end_id].push((start_id, edge_id));
+        }
+    }
+    for (vertex_id, neighbours) in vertices_neighbours.iter_mut().enumerate() {
+        let center = &vertices[vertex_id];
+        neighbours.sort_by(|&(first_id, _), &(second_id, _)| {
+            compare_angles(center, &vertices[first_id], &vertices[second_id])
+        });
+    }
+    let mut neighbours_positions = BTreeMap::<(usize, usize), usize>::new();
+    for (vertex_id, neighbours) in vertices_neighbours.iter().enumerate() {
+        for (position, &(neighbour_id, _)) in neighbours.iter().enumerate() {
+            neighbours_positions.insert((vertex_id, neighbour_id), position);
+        }
+    }
+    let mut are_visited = vertices_neighbours
+        .iter()
+        .map(|neighbours| vec![false; neighbours.len()])
+        .collect::<Vec<_>>();
+    let mut result = Vec::new();
+    for vertex_id in 0..vertices.len() {
+        for position in 0..vertices_neighbours[vertex_id].len() {
+            if are_visited[vertex_id][position] {
+                continue;
+            }
+            let mut walk = Vec::new();
+            let (mut start_id, mut start_position) = (vertex_id, position);
+            while !are_visited[start_id][start_position] {
+                are_visited[start_id][start_position] = true;
+                let (end_id, edge_id) =
+                    vertices_neighbours[start_id][start_position];
+                walk.push((edge_id, start_id));
+                let end_neighbours_count = vertices_neighbours[end_id].len();
+                // next edge is the previous one in counterclockwise order
+                start_position = (neighbours_positions[&(end_id, start_id)]
+                    + end_neighbours_count
+                    - 1)
+                    % end_neighbours_count;
+                start_id = end_id;
+            }
+            result.push(walk);
+        }
+    }
+    result
+}
+
+/// Compares counterclockwise angles of rays from center to given points
+/// starting from the negative direction of the y-axis (exclusively).
+fn compare_angles<Point: Ord>(
+    center: &Point,
+    first: &Point,
+    second: &Point,
+) -> Ordering
+where
+    for<'a> &'a Point: Orient,
+{
+    match (first < center, second < center) {
+        (false, true) => Ordering::Less,
+        (true, false) => Ordering::Greater,
+        _ => match center.orient(first, second) {
+            Orientation::Counterclockwise => Ordering::Less,
+            Orientation::Clockwise => Ordering::Greater,
+            Orientation::Collinear => Ordering::Equal,
+        },
+    }
+}
@@ -2,13 +2,18 @@ use std::cmp::Reverse;
 use std::collections::{BTreeSet, BinaryHeap};
 use std::ops::Bound::{Excluded, Unbounded};
 
-use crate::operations::{IntersectCrossingSegments, Orient};
-use crate::oriented::Orientation;
-use crate::relatable::Relation;
+use crate::bounded::Bounded;
+use crate::operations::{
+    IntersectCrossingSegments, Orient, ToReversedSegments,
+};
+use crate::oriented::{Orientation, Oriented};
+use crate::relatable::{Relatable, Relation};
 use crate::relating::event::is_event_right;
 use crate::relating::utils::all_equal;
 use crate::sweeping::traits::{EventsContainer, EventsQueue, SweepLine};
-use crate::traits::{Elemental, Segmental};
+use crate::traits::{
+    Contoural, Elemental, Iterable, Multisegmental, Segmental,
+};
 
 use super::event::{
     is_event_left, left_event_to_position, segment_id_to_left_event,
@@ -53,6 +58,61 @@ struct RelationState {
     second_is_subset: bool,
 }
 
+/// Relates regions bounded by given contours regardless of their orientations.
+pub(crate) fn relate_regions<
+    Border,
+    Point: Clone + Ord,
+    Scalar: Ord,
+    Segment: Clone + Segmental<Endpoint = Point>,
+>(
+    first: &Border,
+    second: &Border,
+) -> Relation
+where
+    Operation<Point>: EventsQueue<Event = Event> + SweepLine<Event = Event>,
+    for<'a> &'a Border: Bounded<&'a Scalar>
+        + Contoural<IndexSegment = Segment, IndexVertex = Point>
+        + Oriented
+        + ToReversedSegments<Output = Vec<Segment>>,
+    for<'a> &'a Point: Elemental<Coordinate = &'a Scalar>
+        + IntersectCrossingSegments<Output = Point>
+        + Orient,
+    for<'a> &'a Segment: Segmental<Endpoint = &'a Point>,
+{
+    let first_bounding_box = first.to_bounding_box();
+    let second_bounding_box = second.to_bounding_box();
+    if first_bounding_box.disjoint_with(&second_bounding_box) {
+        return Relation::Disjoint;
+    }
+    let min_max_x = first_bounding_box
+        .get_max_x()
+        .min(second_bounding_box.get_max_x());
+    let first_segments = to_counterclockwise_segments(first);
+    let second_segments = to_counterclockwise_segments(second);
+    Operation::<Point>::from_segments_iterators(
+        (first_segments.len(), first_segments.into_iter()),
+        (second_segments.len(), second_segments.into_iter()),
+    )
+    .into_relation(true, true, min_max_x)
+}
+
+fn to_counterclockwise_segments<Border, Point, Segment: Clone>(
+    border: &Border,
+) -> Vec<Segment>
+where
+    for<'a> &'a Border: Contoural<IndexSegment = Segment, IndexVertex = Point>
+        + Oriented
+        + ToReversedSegments<Output = Vec<Segment>>,
+    for<'a> &'a Point: Elemental,
+    for<'a> &'a Segment: Segmental,
+{
+    if border.to_orientation() == Orientation::Counterclockwise {
+        border.segments().iter().cloned().collect()
+    } else {
+        border.to_reversed_segments()
+    }
+}
+
 impl<Point> EventsContainer for Operation<Point> {
     type Endpoint = Point;
     type Event = Event;
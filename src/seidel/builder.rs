@@ -0,0 +1,93 @@
+use std::ops::{Add, Sub};
+
+use traiter::numbers::{One, Zeroable};
+
+use crate::bounded::Bounded;
+use crate::operations::{permute, Orient};
+use crate::oriented::Oriented;
+use crate::traits::{
+    Contoural, Elemental, Multisegmental, MultisegmentalIndexSegment,
+    MultivertexalIndexVertex, Polygonal, PolygonalIndexHole, Segmental,
+};
+
+use super::trapezoidation::Trapezoidation;
+
+/// builds trapezoidations with edges shuffled
+/// by the permutation defined by the seed,
+/// same seed gives same trapezoidation
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct TrapezoidationBuilder {
+    seed: usize,
+}
+
+impl TrapezoidationBuilder {
+    /// creates builder with the seed of edges' permutation
+    pub fn new(seed: usize) -> Self {
+        Self { seed }
+    }
+
+    /// seed of edges' permutation
+    pub fn seed(&self) -> usize {
+        self.seed
+    }
+
+    /// trapezoidation of segments of the multisegment
+    pub fn build_from_multisegment<
+        IndexSegment,
+        Multisegment,
+        Point,
+        Scalar,
+        Segment,
+    >(
+        self,
+        multisegment: &Multisegment,
+    ) -> Trapezoidation<Point>
+    where
+        Point: Clone + From<(Scalar, Scalar)> + PartialOrd,
+        Scalar: Clone + One,
+        for<'a> &'a Multisegment: Bounded<&'a Scalar>
+            + Multisegmental<
+                IndexSegment = IndexSegment,
+                IntoIteratorSegment = &'a Segment,
+            >,
+        for<'a> &'a IndexSegment: Segmental,
+        for<'a> &'a Point: Orient,
+        for<'a> &'a Scalar: Add<Scalar, Output = Scalar>
+            + Sub<Scalar, Output = Scalar>
+            + Sub<Output = Scalar>
+            + Zeroable,
+        for<'a> &'a Segment: Segmental<Endpoint = &'a Point>,
+    {
+        Trapezoidation::from_multisegment(multisegment, |values| {
+            permute(values, self.seed)
+        })
+    }
+
+    /// trapezoidation of edges of the polygon's border and holes
+    pub fn build_from_polygon<Contour, Point, Polygon, Scalar>(
+        self,
+        polygon: &Polygon,
+    ) -> Trapezoidation<Point>
+    where
+        Point: Clone + From<(Scalar, Scalar)> + PartialOrd,
+        Scalar: Clone + One,
+        for<'a> &'a Contour: Contoural<IndexVertex = Point> + Oriented,
+        for<'a> &'a Point: Elemental + Orient,
+        for<'a> &'a Polygon: Bounded<&'a Scalar>
+            + Polygonal<Contour = &'a Contour, IntoIteratorHole = &'a Contour>,
+        for<'a> &'a Scalar: Add<Scalar, Output = Scalar>
+            + Sub<Scalar, Output = Scalar>
+            + Sub<Output = Scalar>
+            + Zeroable,
+        for<'a, 'b> &'a MultisegmentalIndexSegment<&'b Contour>: Segmental,
+        for<'a, 'b> &'a PolygonalIndexHole<&'b Polygon>: Contoural,
+        for<'a, 'b, 'c> &'a MultisegmentalIndexSegment<&'b PolygonalIndexHole<&'c Polygon>>:
+            Segmental,
+        for<'a, 'b, 'c> &'a MultivertexalIndexVertex<&'b PolygonalIndexHole<&'c Polygon>>:
+            Elemental,
+    {
+        Trapezoidation::from_polygon(polygon, |values| {
+            permute(values, self.seed)
+        })
+    }
+}
@@ -1,5 +1,7 @@
-pub(crate) use self::trapezoidation::Trapezoidation;
+pub use self::builder::TrapezoidationBuilder;
+pub use self::trapezoidation::Trapezoidation;
 
+mod builder;
 mod edge;
 mod locatable;
 mod node;
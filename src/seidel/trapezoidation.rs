@@ -16,8 +16,42 @@ use super::edge::Edge;
 use super::node::Node;
 use super::trapezoid::Trapezoid;
 
+/// Seidel's trapezoidal decomposition
+/// which serves as a point location structure.
+///
+/// ```
+/// use rene::geometries::{Contour, Point, Polygon};
+/// use rene::locatable::{Locatable, Location};
+/// use rene::seidel::TrapezoidationBuilder;
+/// use rithm::big_int::BigInt;
+/// use rithm::fraction::Fraction;
+///
+/// type Scalar = Fraction<BigInt<u32, 31>>;
+///
+/// let to_point = |x: i32, y: i32| {
+///     Point::new(
+///         Scalar::from(BigInt::from(x)),
+///         Scalar::from(BigInt::from(y)),
+///     )
+/// };
+/// let polygon = Polygon::new(
+///     Contour::new(vec![
+///         to_point(0, 0),
+///         to_point(4, 0),
+///         to_point(4, 4),
+///         to_point(0, 4),
+///     ]),
+///     vec![],
+/// );
+/// let trapezoidation =
+///     TrapezoidationBuilder::new(42).build_from_polygon(&polygon);
+/// assert!(trapezoidation.height() > 0);
+/// assert_eq!(trapezoidation.locate(&to_point(1, 1)), Location::Interior);
+/// assert_eq!(trapezoidation.locate(&to_point(4, 2)), Location::Boundary);
+/// assert_eq!(trapezoidation.locate(&to_point(5, 5)), Location::Exterior);
+/// ```
 #[derive(Clone)]
-pub(crate) struct Trapezoidation<Point> {
+pub struct Trapezoidation<Point> {
     edges: Vec<Edge>,
     endpoints: Vec<Point>,
     nodes: Vec<Node>,
@@ -40,7 +74,8 @@ impl<Point> Trapezoidation<Point> {
         &self.nodes
     }
 
-    pub(crate) fn height(&self) -> usize {
+    /// height of the underlying search structure
+    pub fn height(&self) -> usize {
         self.get_root().height(self.get_nodes())
     }
 }
@@ -287,16 +322,16 @@ impl<Point> Trapezoidation<Point> {
         }
         let last_end_index = endpoints.len() - 1;
         edges.push(
-            if endpoints[first_start_index] < endpoints[last_end_index] {
+            if endpoints[last_end_index] < endpoints[first_start_index] {
                 Edge {
-                    left_point_index: first_start_index,
-                    right_point_index: last_end_index,
+                    left_point_index: last_end_index,
+                    right_point_index: first_start_index,
                     interior_to_left: is_contour_correctly_oriented,
                 }
             } else {
                 Edge {
-                    left_point_index: last_end_index,
-                    right_point_index: first_start_index,
+                    left_point_index: first_start_index,
+                    right_point_index: last_end_index,
                     interior_to_left: !is_contour_correctly_oriented,
                 }
             },
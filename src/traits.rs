@@ -169,6 +169,24 @@ pub type PolygonalIntoIteratorHole<T> = <T as Polygonal>::IntoIteratorHole;
 pub type SegmentalCoordinate<T> = ElementalCoordinate<SegmentalEndpoint<T>>;
 pub type SegmentalEndpoint<T> = <T as Segmental>::Endpoint;
 
+pub trait Area<Scalar> {
+    /// Returns the exact area,
+    /// which is signed for contours (positive for counterclockwise ones)
+    /// and non-negative for shaped geometries.
+    fn area(self) -> Scalar;
+}
+
+pub trait Centroid<Point> {
+    /// Returns the exact centroid.
+    /// Shaped geometries are weighted by area,
+    /// while segments of linear geometries are weighted equally
+    /// (so the result is the average of segments midpoints),
+    /// because weighting by length would require square roots.
+    /// Shaped geometries with zero area fall back
+    /// to the average of their borders vertices.
+    fn centroid(self) -> Point;
+}
+
 pub trait Intersection<Other = Self> {
     type Output;
 
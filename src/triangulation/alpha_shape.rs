@@ -0,0 +1,103 @@
+use std::ops::{Add, Mul};
+
+use crate::geometries::{Contour, Multipolygon, Point, Polygon};
+use crate::operations::{CrossMultiply, Orient, SquaredMetric};
+use crate::traits::Union;
+
+use super::delaunay::DelaunayTriangulation;
+
+impl<Scalar> DelaunayTriangulation<Point<Scalar>>
+where
+    Point<Scalar>: Clone + PartialOrd,
+    Scalar: PartialOrd + Mul<Output = Scalar>,
+    for<'a> &'a Scalar: Add<Output = Scalar> + Mul<Output = Scalar>,
+    for<'a> &'a Point<Scalar>: CrossMultiply<Output = Scalar>
+        + Orient
+        + SquaredMetric<Output = Scalar>,
+{
+    /// union of triangles with squared circumradii
+    /// not exceeding the squared alpha,
+    /// no polygons means that the alpha shape is empty
+    pub fn to_alpha_shape(
+        &self,
+        squared_alpha: &Scalar,
+    ) -> Vec<Polygon<Scalar>>
+    where
+        for<'a> &'a Multipolygon<Scalar>: Union<Output = Vec<Polygon<Scalar>>>
+            + Union<&'a Polygon<Scalar>, Output = Vec<Polygon<Scalar>>>,
+        for<'a> &'a Polygon<Scalar>: Union<Output = Vec<Polygon<Scalar>>>
+            + Union<&'a Multipolygon<Scalar>, Output = Vec<Polygon<Scalar>>>,
+    {
+        unite_polygons(
+            self.to_triangles_vertices()
+                .filter(|&(first, second, third)| {
+                    is_triangle_within_squared_circumradius(
+                        first,
+                        second,
+                        third,
+                        squared_alpha,
+                    )
+                })
+                .map(|(first, second, third)| {
+                    Polygon::new(
+                        Contour::from([
+                            first.clone(),
+                            second.clone(),
+                            third.clone(),
+                        ]),
+                        vec![],
+                    )
+                })
+                .collect(),
+        )
+    }
+}
+
+/// compares squared circumradius `(a * b * c) ^ 2 / (4 * area) ^ 2`
+/// with the squared alpha without divisions
+fn is_triangle_within_squared_circumradius<Scalar>(
+    first: &Point<Scalar>,
+    second: &Point<Scalar>,
+    third: &Point<Scalar>,
+    squared_alpha: &Scalar,
+) -> bool
+where
+    Scalar: PartialOrd + Mul<Output = Scalar>,
+    for<'a> &'a Scalar: Add<Output = Scalar> + Mul<Output = Scalar>,
+    for<'a> &'a Point<Scalar>:
+        CrossMultiply<Output = Scalar> + SquaredMetric<Output = Scalar>,
+{
+    let doubled_area =
+        CrossMultiply::cross_multiply(first, second, first, third);
+    let quadrupled_area = &doubled_area + &doubled_area;
+    first.squared_distance_to(second)
+        * second.squared_distance_to(third)
+        * third.squared_distance_to(first)
+        <= squared_alpha * &(&quadrupled_area * &quadrupled_area)
+}
+
+/// unites halves recursively to keep operands balanced,
+/// halves united into single polygons are not wrapped into multipolygons
+fn unite_polygons<Scalar>(
+    mut polygons: Vec<Polygon<Scalar>>,
+) -> Vec<Polygon<Scalar>>
+where
+    for<'a> &'a Multipolygon<Scalar>: Union<Output = Vec<Polygon<Scalar>>>
+        + Union<&'a Polygon<Scalar>, Output = Vec<Polygon<Scalar>>>,
+    for<'a> &'a Polygon<Scalar>: Union<Output = Vec<Polygon<Scalar>>>
+        + Union<&'a Multipolygon<Scalar>, Output = Vec<Polygon<Scalar>>>,
+{
+    if polygons.len() < 2 {
+        polygons
+    } else {
+        let second_half = polygons.split_off(polygons.len() / 2);
+        let first = unite_polygons(polygons);
+        let second = unite_polygons(second_half);
+        match (first.as_slice(), second.as_slice()) {
+            ([first], [second]) => first.union(second),
+            ([first], _) => first.union(&Multipolygon::new(second)),
+            (_, [second]) => (&Multipolygon::new(first)).union(second),
+            _ => (&Multipolygon::new(first)).union(&Multipolygon::new(second)),
+        }
+    }
+}
@@ -1,31 +1,108 @@
 use std::cmp::Ordering;
-use std::collections::VecDeque;
+use std::collections::{BTreeSet, VecDeque};
+use std::sync::OnceLock;
 
+use crate::bentley_ottmann::{
+    to_unique_non_crossing_or_overlapping_segments, Event, EventsRegistry,
+};
 use crate::constants::MIN_CONTOUR_VERTICES_COUNT;
-use crate::locatable::Location;
+use crate::geometries::{
+    ConstructionError, Contour, Multipolygon, Multisegment, Point, Segment,
+};
+use crate::locatable::{Locatable, Location};
 use crate::operations::{
-    shrink_collinear_vertices, LocatePointInPointPointPointCircle, Orient,
+    shrink_collinear_vertices, to_sorted_pair,
+    LocatePointInPointPointPointCircle, Orient,
 };
 use crate::oriented::Orientation;
 use crate::relatable::Relation;
 use crate::relating::segment_endpoints;
 use crate::traits::{
-    Contoural, Elemental, Iterable, Lengthsome, MultisegmentalIndexSegment,
-    Multivertexal, MultivertexalIndexVertex, Polygonal, PolygonalIndexHole,
-    Segmental, Sequence,
+    Contoural, Elemental, Iterable, Lengthsome, Multipolygonal,
+    Multisegmental, MultisegmentalIndexSegment, Multivertexal,
+    MultivertexalIndexVertex, Polygonal, PolygonalIndexHole, Segmental,
+    Sequence,
 };
 
-use super::mesh::Mesh;
+use super::locatable::{to_location, TriangleLocation};
+use super::mesh::{Mesh, MeshLocation};
 use super::operations::{BoundaryEndpoints, DelaunayTriangulatable};
 use super::quad_edge::{to_opposite_edge, QuadEdge, UNDEFINED_QUAD_EDGE};
 
+/// Delaunay triangulation constrained either by borders and holes edges
+/// of a polygon or a multipolygon, or by arbitrary segments.
+///
+/// ```
+/// use rene::geometries::{Contour, Point, Polygon};
+/// use rene::locatable::{Locatable, Location};
+/// use rene::triangulation::{
+///     ConstrainedDelaunayTriangulation, TriangleLocation,
+/// };
+/// use rithm::big_int::BigInt;
+/// use rithm::fraction::Fraction;
+///
+/// type Scalar = Fraction<BigInt<u32, 31>>;
+///
+/// let to_point = |x: i32, y: i32| {
+///     Point::new(
+///         Scalar::from(BigInt::from(x)),
+///         Scalar::from(BigInt::from(y)),
+///     )
+/// };
+/// let border = Contour::new(vec![
+///     to_point(0, 0),
+///     to_point(6, 0),
+///     to_point(6, 6),
+///     to_point(0, 6),
+/// ]);
+/// let hole = Contour::new(vec![
+///     to_point(2, 2),
+///     to_point(2, 4),
+///     to_point(4, 4),
+///     to_point(4, 2),
+/// ]);
+/// let polygon = Polygon::new(border.clone(), vec![hole]);
+/// let triangulation =
+///     ConstrainedDelaunayTriangulation::<Point<Scalar>>::from(&polygon);
+/// assert_eq!(triangulation.get_vertices().len(), 8);
+/// assert_eq!(triangulation.to_triangles().len(), 8);
+/// assert_eq!(triangulation.to_triangles_indices().count(), 8);
+/// assert_eq!(triangulation.to_edges_indices().len(), 16);
+/// assert_eq!(
+///     triangulation
+///         .to_edges_constraints_flags()
+///         .into_iter()
+///         .filter(|&flag| flag)
+///         .count(),
+///     8
+/// );
+/// assert!(triangulation
+///     .to_triangles_neighbours()
+///     .iter()
+///     .all(|neighbours| neighbours.iter().flatten().count() == 2));
+/// assert!(triangulation.to_border().unwrap() == border);
+/// assert!(matches!(
+///     triangulation.locate_triangle(&to_point(3, 1)),
+///     TriangleLocation::Triangle(_)
+/// ));
+/// assert_eq!(
+///     triangulation.locate_triangle(&to_point(3, 3)),
+///     TriangleLocation::Exterior
+/// );
+/// assert_eq!((&triangulation).locate(&to_point(3, 1)), Location::Interior);
+/// assert_eq!((&triangulation).locate(&to_point(2, 3)), Location::Boundary);
+/// assert_eq!((&triangulation).locate(&to_point(3, 3)), Location::Exterior);
+/// ```
 #[derive(Clone)]
-pub(crate) struct ConstrainedDelaunayTriangulation<Endpoint> {
+pub struct ConstrainedDelaunayTriangulation<Endpoint> {
+    constraints_indices: Vec<(usize, usize)>,
+    is_connected: bool,
     left_side: QuadEdge,
     mesh: Mesh<Endpoint>,
     polygon_vertices_positions: Vec<Vec<PolygonVertexPosition>>,
     right_side: QuadEdge,
-    triangular_holes_indices: Vec<usize>,
+    triangles_base_edges: OnceLock<Vec<QuadEdge>>,
+    triangular_holes_vertices_indices: Vec<[usize; 3]>,
 }
 
 impl<Endpoint: Clone> BoundaryEndpoints<Endpoint>
@@ -86,6 +163,16 @@ impl<'a, Endpoint: Ord> Ord for PolygonEndpoint<'a, Endpoint> {
     }
 }
 
+/// outcome of walking through a single triangle or around a vertex
+enum WalkStep {
+    /// the walk crosses the edge leaving the triangle on its right
+    Crossing(QuadEdge),
+    /// the walk ends at the location
+    Location(MeshLocation),
+    /// the walk passes through the start of the edge
+    Vertex(QuadEdge),
+}
+
 #[derive(Clone, Copy, Debug)]
 struct PolygonVertexPosition {
     contour_index: usize,
@@ -118,6 +205,140 @@ where
             }
             contours_vertices
         };
+        let contours_sizes = contours_vertices
+            .iter()
+            .map(|contour| contour.len())
+            .collect::<Vec<usize>>();
+        let mut result = Self::from_contours_vertices(&contours_vertices);
+        result.bound(&contours_sizes);
+        result.cut(&contours_vertices);
+        result.set_contours_constraints(&contours_sizes);
+        let triangular_holes_indices = contours_sizes[1..]
+            .iter()
+            .enumerate()
+            .filter(|(_, &hole_size)| hole_size == 3)
+            .map(|(hole_index, _)| hole_index + 1)
+            .collect::<Vec<_>>();
+        result.triangular_holes_vertices_indices =
+            to_triangular_contours_vertices_indices(
+                &triangular_holes_indices,
+                &result.polygon_vertices_positions,
+            );
+        result
+    }
+}
+
+/// triangulates borders and holes of all polygons as one mesh,
+/// polygons may touch each other at vertices
+impl<Scalar> From<&Multipolygon<Scalar>>
+    for ConstrainedDelaunayTriangulation<Point<Scalar>>
+where
+    Point<Scalar>: Clone + Ord,
+    for<'a> &'a Point<Scalar>: LocatePointInPointPointPointCircle + Orient,
+{
+    fn from(multipolygon: &Multipolygon<Scalar>) -> Self {
+        let mut contours_vertices = Vec::new();
+        for polygon in multipolygon.polygons() {
+            contours_vertices.push(polygon.border().vertices());
+            for hole in polygon.holes() {
+                contours_vertices.push(hole.vertices());
+            }
+        }
+        let contours_sizes = contours_vertices
+            .iter()
+            .map(|contour| contour.len())
+            .collect::<Vec<usize>>();
+        let mut result = Self::from_contours_vertices(&contours_vertices);
+        result.cut_exterior(&contours_sizes);
+        result.set_contours_constraints(&contours_sizes);
+        result
+    }
+}
+
+/// triangulates points along with endpoints of constraints
+/// so that constraints become edges of the triangulation,
+/// crossings of constraints are added as Steiner points
+impl<Scalar> From<(Vec<Point<Scalar>>, &Multisegment<Scalar>)>
+    for ConstrainedDelaunayTriangulation<Point<Scalar>>
+where
+    EventsRegistry<Point<Scalar>, true>:
+        Iterator<Item = Event> + for<'a> From<&'a Vec<Segment<Scalar>>>,
+    Point<Scalar>: Clone + Ord,
+    for<'a> &'a Point<Scalar>: LocatePointInPointPointPointCircle + Orient,
+    for<'a> &'a Segment<Scalar>: Segmental<Endpoint = &'a Point<Scalar>>,
+{
+    fn from(
+        (mut points, constraints): (Vec<Point<Scalar>>, &Multisegment<Scalar>),
+    ) -> Self {
+        let constraints = constraints
+            .segments()
+            .into_iter()
+            .filter_map(|segment| {
+                let (start, end) = segment.endpoints();
+                match start.cmp(end) {
+                    Ordering::Less => {
+                        Some(Segment::new(start.clone(), end.clone()))
+                    }
+                    Ordering::Greater => {
+                        Some(Segment::new(end.clone(), start.clone()))
+                    }
+                    Ordering::Equal => None,
+                }
+            })
+            .collect::<Vec<_>>();
+        let constraints_pieces =
+            to_unique_non_crossing_or_overlapping_segments::<
+                _,
+                (Point<Scalar>, Point<Scalar>),
+                _,
+            >(&constraints);
+        points.reserve(2 * constraints_pieces.len());
+        for (start, end) in &constraints_pieces {
+            points.push(start.clone());
+            points.push(end.clone());
+        }
+        points.sort();
+        points.dedup();
+        let constraints_indices = constraints_pieces
+            .iter()
+            .map(|(start, end)| {
+                to_sorted_pair(unsafe {
+                    (
+                        points.binary_search(start).unwrap_unchecked(),
+                        points.binary_search(end).unwrap_unchecked(),
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+        let mut mesh = Mesh::from(points);
+        let (left_side, right_side) = mesh.delaunay_triangulation();
+        let mut result = Self {
+            constraints_indices: Vec::new(),
+            is_connected: true,
+            left_side,
+            mesh,
+            polygon_vertices_positions: Vec::new(),
+            right_side,
+            triangles_base_edges: OnceLock::new(),
+            triangular_holes_vertices_indices: Vec::new(),
+        };
+        let mut constraints_indices =
+            result.constrain_segments(&constraints_indices);
+        constraints_indices.sort_unstable();
+        result.constraints_indices = constraints_indices;
+        result
+    }
+}
+
+impl<Endpoint: Clone + Ord> ConstrainedDelaunayTriangulation<Endpoint>
+where
+    for<'a> &'a Endpoint: LocatePointInPointPointPointCircle + Orient,
+{
+    fn from_contours_vertices<
+        ContourVertices: Sequence<IndexItem = Endpoint>,
+    >(
+        contours_vertices: &[ContourVertices],
+    ) -> Self {
         let polygon_endpoints = {
             let mut polygon_endpoints = Vec::with_capacity(
                 contours_vertices
@@ -177,32 +398,174 @@ where
         };
         let mut mesh = Mesh::from(polygon_vertices);
         let (left_side, right_side) = mesh.delaunay_triangulation();
-        let triangular_holes_indices = contours_vertices[1..]
-            .iter()
-            .enumerate()
-            .filter(|(_, hole_vertices)| hole_vertices.len() == 3)
-            .map(|(hole_index, _)| hole_index + 1)
-            .collect();
         let mut result = Self {
+            constraints_indices: Vec::new(),
+            is_connected: true,
             left_side,
             mesh,
             polygon_vertices_positions,
             right_side,
-            triangular_holes_indices,
+            triangles_base_edges: OnceLock::new(),
+            triangular_holes_vertices_indices: Vec::new(),
         };
         let contours_sizes = contours_vertices
             .iter()
             .map(|contour| contour.len())
             .collect::<Vec<usize>>();
-        result.constrain(&contours_sizes, &contours_vertices);
-        result.bound(&contours_sizes);
-        result.cut(&contours_vertices);
+        result.constrain(&contours_sizes, contours_vertices);
+        result
+    }
+
+    /// inserts the Steiner point lying either inside of the triangle
+    /// or on the edge between triangles which is not a constraint
+    /// as the last vertex (so vertices stay unsorted till `sort_vertices`)
+    /// restoring the Delaunay property by flips of non-constraint edges,
+    /// returns an edge starting at the point
+    pub(super) fn insert_steiner_point(
+        &mut self,
+        location: MeshLocation,
+        point: Endpoint,
+    ) -> QuadEdge {
+        let index = self.mesh.push_endpoint(point);
+        self.polygon_vertices_positions.push(Vec::new());
+        let constraints_indices = &self.constraints_indices;
+        let result = self.mesh.insert_constrained_inner_endpoint(
+            location,
+            index,
+            |mesh, edge| is_constraint_edge(constraints_indices, mesh, edge),
+        );
+        self.triangles_base_edges = OnceLock::new();
+        result
+    }
+
+    /// splits the constraint edge at the Steiner point
+    /// lying strictly inside of it, the point becomes the last vertex
+    /// (so vertices stay unsorted till `sort_vertices`)
+    /// and gets connected to apices of adjacent triangles,
+    /// returns the piece from the point to the end of the edge,
+    /// while the edge itself becomes the piece ending at the point
+    pub(super) fn split_constraint_edge(
+        &mut self,
+        edge: QuadEdge,
+        point: Endpoint,
+    ) -> QuadEdge {
+        debug_assert!(self.is_constraint_edge(edge));
+        let opposite_edge = to_opposite_edge(edge);
+        let mut sides_triangles_flags = [false; 2];
+        for (&side, is_triangle) in
+            [edge, opposite_edge].iter().zip(&mut sides_triangles_flags)
+        {
+            if !self.mesh.is_left_triangular_face(side) {
+                continue;
+            }
+            if self.is_triangular_hole(side) {
+                // hole stops being triangular
+                let mut vertices_indices =
+                    self.mesh.triangle_base_to_indices(side);
+                vertices_indices.sort_unstable();
+                self.triangular_holes_vertices_indices
+                    .retain(|hole_indices| *hole_indices != vertices_indices);
+            } else {
+                *is_triangle = true;
+            }
+        }
+        let (start_index, end_index) =
+            (self.mesh.to_start_index(edge), self.mesh.to_end_index(edge));
+        let index = self.mesh.push_endpoint(point);
+        self.polygon_vertices_positions.push(Vec::new());
+        let constraint_position = unsafe {
+            self.constraints_indices
+                .binary_search(&to_sorted_pair((start_index, end_index)))
+                .unwrap_unchecked()
+        };
+        self.constraints_indices.remove(constraint_position);
+        // the point has the greatest index so far
+        for constraint in [(start_index, index), (end_index, index)] {
+            let position = unsafe {
+                self.constraints_indices
+                    .binary_search(&constraint)
+                    .unwrap_err_unchecked()
+            };
+            self.constraints_indices.insert(position, constraint);
+        }
+        let result = self.mesh.split_edge(edge, index);
+        if self.left_side == opposite_edge {
+            self.left_side = to_opposite_edge(result);
+        }
+        if self.right_side == opposite_edge {
+            self.right_side = to_opposite_edge(result);
+        }
+        let mut suspicious_edges = Vec::new();
+        let [is_left_triangle, is_right_triangle] = sides_triangles_flags;
+        if is_left_triangle {
+            let end_edge = self.mesh.to_left_from_end(result);
+            let apex_edge = self.mesh.to_left_from_end(end_edge);
+            self.mesh.connect_edges(edge, apex_edge);
+            suspicious_edges.extend([end_edge, apex_edge]);
+        }
+        if is_right_triangle {
+            let start_edge = self.mesh.to_left_from_end(opposite_edge);
+            let apex_edge = self.mesh.to_left_from_end(start_edge);
+            self.mesh.connect_edges(to_opposite_edge(result), apex_edge);
+            suspicious_edges.extend([start_edge, apex_edge]);
+        }
+        let constraints_indices = &self.constraints_indices;
+        self.mesh.restore_constrained_delaunay_property(
+            suspicious_edges,
+            |mesh, edge| is_constraint_edge(constraints_indices, mesh, edge),
+        );
+        self.triangles_base_edges = OnceLock::new();
         result
     }
+
+    /// sorts vertices after insertion of Steiner points
+    pub(super) fn sort_vertices(&mut self) {
+        let new_indices = self.mesh.sort_endpoints();
+        for constraint in &mut self.constraints_indices {
+            *constraint = to_sorted_pair((
+                new_indices[constraint.0],
+                new_indices[constraint.1],
+            ));
+        }
+        self.constraints_indices.sort_unstable();
+        for vertices_indices in &mut self.triangular_holes_vertices_indices {
+            for index in vertices_indices.iter_mut() {
+                *index = new_indices[*index];
+            }
+            vertices_indices.sort_unstable();
+        }
+        self.triangular_holes_vertices_indices.sort_unstable();
+        let mut polygon_vertices_positions =
+            vec![Vec::new(); self.polygon_vertices_positions.len()];
+        for (index, positions) in
+            std::mem::take(&mut self.polygon_vertices_positions)
+                .into_iter()
+                .enumerate()
+        {
+            polygon_vertices_positions[new_indices[index]] = positions;
+        }
+        self.polygon_vertices_positions = polygon_vertices_positions;
+        self.triangles_base_edges = OnceLock::new();
+    }
 }
 
 impl<Endpoint> ConstrainedDelaunayTriangulation<Endpoint> {
-    pub(crate) fn is_empty(&self) -> bool {
+    pub(super) fn get_mesh(&self) -> &Mesh<Endpoint> {
+        &self.mesh
+    }
+
+    pub(super) fn is_constraint_edge(&self, edge: QuadEdge) -> bool {
+        is_constraint_edge(&self.constraints_indices, &self.mesh, edge)
+    }
+
+    /// sorted unique vertices of the triangulation,
+    /// triangles indices refer to them
+    pub fn get_vertices(&self) -> &[Endpoint] {
+        self.mesh.get_endpoints()
+    }
+
+    /// checks if the triangulation has no edges
+    pub fn is_empty(&self) -> bool {
         let result = self.mesh.is_empty();
         debug_assert_eq!(self.left_side == UNDEFINED_QUAD_EDGE, result);
         debug_assert_eq!(self.right_side == UNDEFINED_QUAD_EDGE, result);
@@ -242,25 +605,185 @@ impl<Endpoint: PartialOrd> ConstrainedDelaunayTriangulation<Endpoint>
 where
     for<'a> &'a Endpoint: Orient,
 {
-    pub(crate) fn to_triangles_vertices(
+    /// indices of counterclockwise oriented triangles' vertices
+    pub fn to_triangles_indices(
+        &self,
+    ) -> impl Iterator<Item = [usize; 3]> + '_ {
+        self.to_triangles_base_edges()
+            .map(move |edge| self.mesh.triangle_base_to_indices(edge))
+    }
+
+    /// counterclockwise oriented triangles' vertices
+    pub fn to_triangles_vertices(
         &self,
     ) -> impl Iterator<Item = (&Endpoint, &Endpoint, &Endpoint)> + '_ {
+        self.to_triangles_base_edges()
+            .map(move |edge| self.mesh.triangle_base_to_vertices(edge))
+    }
+
+    /// sorted pairs of sorted vertices indices of edges
+    pub fn to_edges_indices(&self) -> Vec<[usize; 2]> {
+        self.mesh.to_edges_indices()
+    }
+
+    /// flags of edges (as in `to_edges_indices`)
+    /// being constraints of the triangulation
+    pub fn to_edges_constraints_flags(&self) -> Vec<bool> {
+        self.mesh
+            .to_edges_indices()
+            .into_iter()
+            .map(|[start_index, end_index]| {
+                self.constraints_indices
+                    .binary_search(&(start_index, end_index))
+                    .is_ok()
+            })
+            .collect()
+    }
+
+    /// indices of triangles adjacent to each triangle
+    /// in the order of `to_triangles_indices`,
+    /// i-th neighbour lies across the edge
+    /// from i-th to the next vertex of the triangle,
+    /// `None` for edges on the border or holes
+    pub fn to_triangles_neighbours(&self) -> Vec<[Option<usize>; 3]> {
+        self.mesh.to_triangles_neighbours(
+            &self.to_triangles_base_edges().collect::<Vec<_>>(),
+        )
+    }
+
+    /// indices of edges (as in `to_edges_indices`) incident to each vertex
+    /// in counterclockwise order around it
+    pub fn to_vertices_edges_indices(&self) -> Vec<Vec<usize>> {
+        self.mesh
+            .to_vertices_edges_indices(&self.mesh.to_edges_indices())
+    }
+
+    /// locates the point walking through triangles
+    /// from the last located edge,
+    /// holes & concavities are crossed by walking along their boundaries,
+    /// falls back to checking every edge if the walk leaves
+    /// the triangulation of disconnected polygons
+    pub fn locate_triangle(&self, point: &Endpoint) -> TriangleLocation {
+        match self.locate_in_mesh(point) {
+            Some(MeshLocation::Edge(edge)) => {
+                TriangleLocation::Edge(self.mesh.to_edge_indices(edge))
+            }
+            Some(MeshLocation::Face(edge)) => {
+                TriangleLocation::Triangle(self.to_triangle_index(edge))
+            }
+            Some(MeshLocation::Vertex(edge)) => {
+                TriangleLocation::Vertex(self.mesh.to_start_index(edge))
+            }
+            Some(MeshLocation::Exterior(_)) | None => {
+                TriangleLocation::Exterior
+            }
+        }
+    }
+
+    pub(super) fn is_triangle(&self, edge: QuadEdge) -> bool {
+        self.mesh.is_left_triangular_face(edge)
+            && !self.is_triangular_hole(edge)
+    }
+
+    fn is_triangular_hole(&self, edge: QuadEdge) -> bool {
+        !self.triangular_holes_vertices_indices.is_empty() && {
+            let mut vertices_indices =
+                self.mesh.triangle_base_to_indices(edge);
+            vertices_indices.sort_unstable();
+            self.triangular_holes_vertices_indices
+                .binary_search(&vertices_indices)
+                .is_ok()
+        }
+    }
+
+    fn locate_in_mesh(&self, point: &Endpoint) -> Option<MeshLocation> {
+        if self.is_empty() {
+            return None;
+        }
+        let location =
+            match self.mesh.locate(point) {
+                location @ Some(
+                    MeshLocation::Edge(_)
+                    | MeshLocation::Face(_)
+                    | MeshLocation::Vertex(_),
+                ) => location,
+                _ if !self.is_connected => {
+                    return self
+                        .mesh
+                        .locate_by_scan(point, |edge| self.is_triangle(edge))
+                }
+                Some(MeshLocation::Exterior(edge)) => {
+                    Some(self.locate_vertically(point, edge))
+                }
+                None => Some(self.locate_vertically(
+                    point,
+                    to_opposite_edge(self.left_side),
+                )),
+            };
+        match location {
+            Some(MeshLocation::Face(edge))
+                if self.is_triangular_hole(edge) =>
+            {
+                None
+            }
+            _ => location,
+        }
+    }
+
+    /// continues the walk which has left triangles
+    /// by crossing holes & concavities of the triangulation
+    /// along the vertical line through the point,
+    /// starting from below of the triangulation if the point lies
+    /// below the boundary of the given face
+    fn locate_vertically(
+        &self,
+        point: &Endpoint,
+        face_edge: QuadEdge,
+    ) -> MeshLocation {
+        let exterior_edge = to_opposite_edge(self.left_side);
+        self.mesh
+            .locate_vertically(point, face_edge)
+            .or_else(|| self.mesh.locate_vertically(point, exterior_edge))
+            .unwrap_or(MeshLocation::Exterior(exterior_edge))
+    }
+
+    fn to_triangle_index(&self, edge: QuadEdge) -> usize {
+        unsafe {
+            self.triangles_base_edges
+                .get_or_init(|| self.to_triangles_base_edges().collect())
+                .binary_search(&self.mesh.to_triangle_base_edge(edge))
+                .unwrap_unchecked()
+        }
+    }
+
+    pub(super) fn to_triangles_base_edges(
+        &self,
+    ) -> impl Iterator<Item = QuadEdge> + '_ {
         self.mesh
             .to_triangles_base_edges()
-            .filter(move |&edge| {
-                self.triangular_holes_indices.is_empty()
-                    || !are_triangular_hole_vertices(
-                        &self.polygon_vertices_positions
-                            [self.mesh.to_start_index(edge)],
-                        &self.polygon_vertices_positions
-                            [self.mesh.to_end_index(edge)],
-                        &self.polygon_vertices_positions[self
-                            .mesh
-                            .to_end_index(self.mesh.to_left_from_start(edge))],
-                        &self.triangular_holes_indices,
-                    )
+            .filter(move |&edge| !self.is_triangular_hole(edge))
+    }
+}
+
+impl<Scalar> ConstrainedDelaunayTriangulation<Point<Scalar>>
+where
+    Point<Scalar>: Clone + PartialOrd,
+    for<'a> &'a Point<Scalar>: Orient,
+{
+    /// counterclockwise contour of the triangulation boundary
+    pub fn to_border(&self) -> Result<Contour<Scalar>, ConstructionError> {
+        Contour::try_new(
+            self.get_boundary_endpoints().into_iter().cloned().collect(),
+        )
+    }
+
+    /// counterclockwise oriented triangles
+    pub fn to_triangles(&self) -> Vec<Contour<Scalar>> {
+        self.to_triangles_vertices()
+            .map(|(first, second, third)| {
+                Contour::from([first.clone(), second.clone(), third.clone()])
             })
-            .map(move |edge| self.mesh.triangle_base_to_vertices(edge))
+            .collect()
     }
 }
 
@@ -268,6 +791,219 @@ impl<Endpoint: PartialOrd> ConstrainedDelaunayTriangulation<Endpoint>
 where
     for<'a> &'a Endpoint: LocatePointInPointPointPointCircle + Orient,
 {
+    /// constraint edges bounding the cavity of triangles
+    /// which circumcircles contain the point strictly inside,
+    /// grown without crossing constraints from the location of the point
+    /// (either a triangle or an edge between triangles)
+    pub(super) fn to_cavity_constraint_edges(
+        &self,
+        location: MeshLocation,
+        point: &Endpoint,
+    ) -> Vec<QuadEdge> {
+        let mut queue = match location {
+            MeshLocation::Edge(edge) => vec![edge, to_opposite_edge(edge)],
+            MeshLocation::Face(edge) => vec![edge],
+            MeshLocation::Exterior(_) | MeshLocation::Vertex(_) => {
+                unreachable!("Point should lie inside of the triangulation.")
+            }
+        };
+        let mut visited_base_edges = queue
+            .iter()
+            .map(|&edge| self.mesh.to_triangle_base_edge(edge))
+            .collect::<BTreeSet<_>>();
+        let mut result = Vec::new();
+        while let Some(edge) = queue.pop() {
+            for edge in self.mesh.triangle_base_to_edges(edge) {
+                if self.is_constraint_edge(edge) {
+                    result.push(edge);
+                    continue;
+                }
+                let neighbour_edge = to_opposite_edge(edge);
+                let (first, second, third) =
+                    self.mesh.triangle_base_to_vertices(neighbour_edge);
+                if point.locate_point_in_point_point_point_circle(
+                    first, second, third,
+                ) == Location::Interior
+                    && visited_base_edges.insert(
+                        self.mesh.to_triangle_base_edge(neighbour_edge),
+                    )
+                {
+                    queue.push(neighbour_edge);
+                }
+            }
+        }
+        result
+    }
+
+    /// walks straight from the origin lying strictly inside of the triangle
+    /// on the left of the edge to the point,
+    /// returns location of the point if the walk crosses no constraints
+    /// or constraint edges blocking the walk otherwise
+    pub(super) fn walk_to_point(
+        &self,
+        edge: QuadEdge,
+        origin: &Endpoint,
+        point: &Endpoint,
+    ) -> Result<MeshLocation, Vec<QuadEdge>> {
+        let mut step = self.walk_through_triangle(edge, origin, point);
+        loop {
+            step = match step {
+                WalkStep::Crossing(edge) => {
+                    if self.is_constraint_edge(edge) {
+                        return Err(vec![edge]);
+                    }
+                    self.walk_through_triangle(
+                        to_opposite_edge(edge),
+                        origin,
+                        point,
+                    )
+                }
+                WalkStep::Location(location) => return Ok(location),
+                WalkStep::Vertex(edge) => {
+                    self.walk_around_vertex(edge, point)?
+                }
+            };
+        }
+    }
+
+    /// searches the angle between edges from the start of the given one
+    /// which contains the point,
+    /// returns boundary edges of the vertex
+    /// if the point lies in no angle of triangles
+    fn walk_around_vertex(
+        &self,
+        start_edge: QuadEdge,
+        point: &Endpoint,
+    ) -> Result<WalkStep, Vec<QuadEdge>> {
+        let vertex = self.mesh.get_start(start_edge);
+        if vertex.eq(point) {
+            return Ok(WalkStep::Location(MeshLocation::Vertex(start_edge)));
+        }
+        let mut edge = start_edge;
+        loop {
+            let end = self.mesh.get_end(edge);
+            if self.mesh.orient_point_to_edge(edge, point)
+                == Orientation::Collinear
+                && (vertex < point) == (vertex < end)
+            {
+                return Ok(if end.eq(point) {
+                    WalkStep::Location(MeshLocation::Vertex(to_opposite_edge(
+                        edge,
+                    )))
+                } else if (vertex < end) == (end < point) {
+                    WalkStep::Vertex(to_opposite_edge(edge))
+                } else {
+                    WalkStep::Location(MeshLocation::Edge(edge))
+                });
+            }
+            edge = self.mesh.to_left_from_start(edge);
+            if edge == start_edge {
+                break;
+            }
+        }
+        let mut boundary_edges = Vec::new();
+        loop {
+            let next_edge = self.mesh.to_left_from_start(edge);
+            if !self.is_triangle(edge) {
+                boundary_edges.extend([edge, next_edge]);
+            } else if self.mesh.orient_point_to_edge(edge, point)
+                == Orientation::Counterclockwise
+                && self.mesh.orient_point_to_edge(next_edge, point)
+                    == Orientation::Clockwise
+            {
+                let opposite_edge = self.mesh.to_left_from_end(edge);
+                return Ok(
+                    match self.mesh.orient_point_to_edge(opposite_edge, point)
+                    {
+                        Orientation::Clockwise => {
+                            WalkStep::Crossing(opposite_edge)
+                        }
+                        Orientation::Collinear => WalkStep::Location(
+                            MeshLocation::Edge(opposite_edge),
+                        ),
+                        Orientation::Counterclockwise => {
+                            WalkStep::Location(MeshLocation::Face(edge))
+                        }
+                    },
+                );
+            }
+            edge = next_edge;
+            if edge == start_edge {
+                break;
+            }
+        }
+        Err(boundary_edges)
+    }
+
+    /// determines where the walk from the origin to the point
+    /// leaves the triangle on the left of the edge
+    /// which the walk has entered or started in
+    fn walk_through_triangle(
+        &self,
+        edge: QuadEdge,
+        origin: &Endpoint,
+        point: &Endpoint,
+    ) -> WalkStep {
+        let edges = self.mesh.triangle_base_to_edges(edge);
+        let exit_edges = edges
+            .iter()
+            .copied()
+            .filter(|&edge| {
+                self.mesh.orient_point_to_edge(edge, point)
+                    == Orientation::Clockwise
+            })
+            .collect::<Vec<_>>();
+        match exit_edges[..] {
+            [] => WalkStep::Location(
+                if let Some(&vertex_edge) = edges
+                    .iter()
+                    .find(|&&edge| self.mesh.get_start(edge).eq(point))
+                {
+                    MeshLocation::Vertex(vertex_edge)
+                } else if let Some(&boundary_edge) =
+                    edges.iter().find(|&&edge| {
+                        self.mesh.orient_point_to_edge(edge, point)
+                            == Orientation::Collinear
+                    })
+                {
+                    MeshLocation::Edge(boundary_edge)
+                } else {
+                    MeshLocation::Face(edge)
+                },
+            ),
+            // the walk line meets the line of the edge at a single point,
+            // so it passes through an endpoint only if they are collinear
+            [exit_edge] => {
+                if origin.orient(point, self.mesh.get_start(exit_edge))
+                    == Orientation::Collinear
+                {
+                    WalkStep::Vertex(exit_edge)
+                } else if origin.orient(point, self.mesh.get_end(exit_edge))
+                    == Orientation::Collinear
+                {
+                    WalkStep::Vertex(to_opposite_edge(exit_edge))
+                } else {
+                    WalkStep::Crossing(exit_edge)
+                }
+            }
+            // the point lies beyond the vertex shared by the edges
+            [first, second] => {
+                let (first, second) =
+                    if self.mesh.to_left_from_end(first) == second {
+                        (first, second)
+                    } else {
+                        (second, first)
+                    };
+                match origin.orient(point, self.mesh.get_end(first)) {
+                    Orientation::Clockwise => WalkStep::Crossing(second),
+                    Orientation::Collinear => WalkStep::Vertex(second),
+                    Orientation::Counterclockwise => WalkStep::Crossing(first),
+                }
+            }
+            _ => unreachable!("Point cannot lie beyond every triangle edge."),
+        }
+    }
+
     fn bound(&mut self, contours_sizes: &[usize]) {
         let mut extraneous_mouths = self
             .to_unique_boundary_edges()
@@ -331,25 +1067,12 @@ where
                         &contours_vertices[contour_index][vertex_index];
                     let next_vertex_point =
                         &contours_vertices[contour_index][next_vertex_index];
-                    let angle_base_edge = to_angle_containing_constraint_base(
-                        &self.mesh,
+                    insert_constraint(
+                        &mut self.mesh,
                         edge,
-                        next_vertex_point,
-                    );
-                    let crossings = detect_crossings(
-                        &self.mesh,
-                        angle_base_edge,
                         vertex_point,
                         next_vertex_point,
                     );
-                    if !crossings.is_empty() {
-                        set_constraint(
-                            &mut self.mesh,
-                            vertex_point,
-                            next_vertex_point,
-                            crossings,
-                        );
-                    }
                     contours_constraints_flags[contour_index]
                         [constraint_index] = true;
                 }
@@ -357,6 +1080,55 @@ where
         }
     }
 
+    /// inserts constraints splitting them at vertices lying on them,
+    /// returns resulting constraints
+    fn constrain_segments(
+        &mut self,
+        constraints: &[(usize, usize)],
+    ) -> Vec<(usize, usize)>
+    where
+        Endpoint: Clone,
+    {
+        let mut vertices_constraints_ends =
+            vec![Vec::new(); self.mesh.get_endpoints().len()];
+        for &(start_index, end_index) in constraints {
+            vertices_constraints_ends[start_index].push(end_index);
+            vertices_constraints_ends[end_index].push(start_index);
+        }
+        let mut pending_constraints =
+            constraints.iter().copied().collect::<BTreeSet<_>>();
+        let mut result = Vec::with_capacity(constraints.len());
+        for edge in self.mesh.iter_unique_edges() {
+            let edge_indices = to_sorted_pair((
+                self.mesh.to_start_index(edge),
+                self.mesh.to_end_index(edge),
+            ));
+            if pending_constraints.remove(&edge_indices) {
+                result.push(edge_indices);
+            }
+        }
+        for edge in self.mesh.to_edges() {
+            if pending_constraints.is_empty() {
+                break;
+            }
+            let start_index = self.mesh.to_start_index(edge);
+            for &end_index in &vertices_constraints_ends[start_index] {
+                if pending_constraints
+                    .remove(&to_sorted_pair((start_index, end_index)))
+                {
+                    insert_splittable_constraint(
+                        &mut self.mesh,
+                        edge,
+                        end_index,
+                        &mut result,
+                    );
+                }
+            }
+        }
+        debug_assert!(pending_constraints.is_empty());
+        result
+    }
+
     fn cut<ContourVertices: Sequence<IndexItem = Endpoint>>(
         &mut self,
         contours_vertices: &[ContourVertices],
@@ -371,6 +1143,113 @@ where
                 self.delete_edge(edge);
             }
         }
+        self.is_connected = self.mesh.is_connected();
+    }
+
+    fn set_contours_constraints(&mut self, contours_sizes: &[usize]) {
+        let mut constraints_indices = self
+            .mesh
+            .iter_unique_edges()
+            .filter(|&edge| {
+                is_contour_edge(
+                    &self.mesh,
+                    edge,
+                    contours_sizes,
+                    &self.polygon_vertices_positions,
+                )
+            })
+            .map(|edge| {
+                to_sorted_pair((
+                    self.mesh.to_start_index(edge),
+                    self.mesh.to_end_index(edge),
+                ))
+            })
+            .collect::<Vec<_>>();
+        constraints_indices.sort_unstable();
+        self.constraints_indices = constraints_indices;
+    }
+
+    fn cut_exterior(&mut self, contours_sizes: &[usize]) {
+        let edges = self.mesh.to_edges();
+        let mut faces_interior_flags =
+            vec![None; edges.last().map_or(0, |edge| edge / 2 + 1)];
+        let mut queue = Vec::new();
+        for &edge in &edges {
+            let opposite_edge = to_opposite_edge(edge);
+            if !is_triangular_face(&self.mesh, edge)
+                && is_triangular_face(&self.mesh, opposite_edge)
+            {
+                queue.push((
+                    opposite_edge,
+                    is_contour_edge(
+                        &self.mesh,
+                        edge,
+                        contours_sizes,
+                        &self.polygon_vertices_positions,
+                    ),
+                ));
+            }
+        }
+        let mut triangular_holes_vertices_indices = Vec::new();
+        while let Some((edge, is_interior)) = queue.pop() {
+            if faces_interior_flags[edge / 2].is_some() {
+                continue;
+            }
+            let next_edge = to_left_face_next_edge(&self.mesh, edge);
+            let face_edges = [
+                edge,
+                next_edge,
+                to_left_face_next_edge(&self.mesh, next_edge),
+            ];
+            let mut are_contour_edges = true;
+            for face_edge in face_edges {
+                faces_interior_flags[face_edge / 2] = Some(is_interior);
+            }
+            for face_edge in face_edges {
+                let is_constraint = is_contour_edge(
+                    &self.mesh,
+                    face_edge,
+                    contours_sizes,
+                    &self.polygon_vertices_positions,
+                );
+                are_contour_edges &= is_constraint;
+                let opposite_edge = to_opposite_edge(face_edge);
+                if faces_interior_flags[opposite_edge / 2].is_none()
+                    && is_triangular_face(&self.mesh, opposite_edge)
+                {
+                    queue.push((opposite_edge, is_interior != is_constraint));
+                }
+            }
+            if !is_interior && are_contour_edges {
+                let mut vertices_indices =
+                    self.mesh.triangle_base_to_indices(edge);
+                vertices_indices.sort_unstable();
+                triangular_holes_vertices_indices.push(vertices_indices);
+            }
+        }
+        triangular_holes_vertices_indices.sort_unstable();
+        self.triangular_holes_vertices_indices =
+            triangular_holes_vertices_indices;
+        let exterior_edges = self
+            .mesh
+            .to_unique_edges()
+            .into_iter()
+            .filter(|&edge| {
+                faces_interior_flags[edge / 2] != Some(true)
+                    && faces_interior_flags[to_opposite_edge(edge) / 2]
+                        != Some(true)
+                    && !is_contour_edge(
+                        &self.mesh,
+                        edge,
+                        contours_sizes,
+                        &self.polygon_vertices_positions,
+                    )
+            })
+            .collect::<Vec<_>>();
+        for edge in exterior_edges {
+            self.delete_edge(edge);
+        }
+        self.is_connected = self.mesh.is_connected();
     }
 }
 
@@ -403,32 +1282,12 @@ fn are_polygon_edge_indices(
         || (first_vertex_index == contour_size - 1 && second_vertex_index == 0)
 }
 
-fn are_triangular_hole_vertices(
-    first_positions: &[PolygonVertexPosition],
-    second_positions: &[PolygonVertexPosition],
-    third_positions: &[PolygonVertexPosition],
-    triangular_holes_indices: &[usize],
-) -> bool {
-    first_positions
-        .iter()
-        .map(|position| position.contour_index)
-        .filter(|contour_index| {
-            second_positions
-                .iter()
-                .any(|position| position.contour_index.eq(contour_index))
-                && third_positions
-                    .iter()
-                    .any(|position| position.contour_index.eq(contour_index))
-        })
-        .any(|contour_index| triangular_holes_indices.contains(&contour_index))
-}
-
 fn detect_crossings<Endpoint: PartialEq + PartialOrd>(
     mesh: &Mesh<Endpoint>,
     base_edge: QuadEdge,
     constraint_start: &Endpoint,
     constraint_end: &Endpoint,
-) -> Vec<QuadEdge>
+) -> (Vec<QuadEdge>, Option<QuadEdge>)
 where
     for<'a> &'a Endpoint: Orient,
 {
@@ -445,6 +1304,13 @@ where
         );
         result.push(last_crossing);
         candidate = mesh.to_right_from_start(last_crossing);
+        let apex = mesh.get_end(candidate);
+        if apex.ne(constraint_end)
+            && constraint_start.orient(constraint_end, apex)
+                == Orientation::Collinear
+        {
+            return (result, Some(to_opposite_edge(candidate)));
+        }
         if mesh.orient_point_to_edge(candidate, constraint_end)
             != Orientation::Clockwise
             || constraint_start.orient(constraint_end, mesh.get_end(candidate))
@@ -454,7 +1320,7 @@ where
                 to_opposite_edge(mesh.to_right_from_end(last_crossing));
         }
     }
-    result
+    (result, None)
 }
 
 fn edge_should_be_swapped<Endpoint>(
@@ -483,6 +1349,84 @@ where
                 == Location::Interior))
 }
 
+fn insert_constraint<Endpoint: PartialOrd>(
+    mesh: &mut Mesh<Endpoint>,
+    start_edge: QuadEdge,
+    constraint_start: &Endpoint,
+    constraint_end: &Endpoint,
+) where
+    for<'a> &'a Endpoint: LocatePointInPointPointPointCircle + Orient,
+{
+    let angle_base_edge =
+        to_angle_containing_constraint_base(mesh, start_edge, constraint_end);
+    let (crossings, maybe_vertex_edge) = detect_crossings(
+        mesh,
+        angle_base_edge,
+        constraint_start,
+        constraint_end,
+    );
+    debug_assert!(maybe_vertex_edge.is_none());
+    if !crossings.is_empty() {
+        set_constraint(mesh, constraint_start, constraint_end, crossings);
+    }
+}
+
+/// inserts the constraint from the start of the edge
+/// to the vertex with the given index,
+/// splitting it at vertices lying on it
+/// into pieces which get appended to the given ones
+fn insert_splittable_constraint<Endpoint: Clone + PartialOrd>(
+    mesh: &mut Mesh<Endpoint>,
+    mut start_edge: QuadEdge,
+    end_index: usize,
+    pieces: &mut Vec<(usize, usize)>,
+) where
+    for<'a> &'a Endpoint: LocatePointInPointPointPointCircle + Orient,
+{
+    let constraint_end = mesh.get_endpoints()[end_index].clone();
+    loop {
+        let start_index = mesh.to_start_index(start_edge);
+        let constraint_start = mesh.get_start(start_edge).clone();
+        let next_start_edge = if let Some(edge) = to_constraint_edge(
+            mesh,
+            start_edge,
+            &constraint_start,
+            &constraint_end,
+        ) {
+            pieces
+                .push(to_sorted_pair((start_index, mesh.to_end_index(edge))));
+            to_opposite_edge(edge)
+        } else {
+            let angle_base_edge = to_angle_containing_constraint_base(
+                mesh,
+                start_edge,
+                &constraint_end,
+            );
+            let (crossings, maybe_vertex_edge) = detect_crossings(
+                mesh,
+                angle_base_edge,
+                &constraint_start,
+                &constraint_end,
+            );
+            let piece_end_index = maybe_vertex_edge
+                .map_or(end_index, |vertex_edge| {
+                    mesh.to_start_index(vertex_edge)
+                });
+            let piece_end = mesh.get_endpoints()[piece_end_index].clone();
+            set_constraint(mesh, &constraint_start, &piece_end, crossings);
+            pieces.push(to_sorted_pair((start_index, piece_end_index)));
+            match maybe_vertex_edge {
+                Some(vertex_edge) => vertex_edge,
+                None => break,
+            }
+        };
+        if mesh.to_start_index(next_start_edge) == end_index {
+            break;
+        }
+        start_edge = next_start_edge;
+    }
+}
+
 fn intersect_polygon_vertices_positions_slices<const WITH_BORDER: bool>(
     left: &[PolygonVertexPosition],
     right: &[PolygonVertexPosition],
@@ -612,6 +1556,39 @@ where
     false
 }
 
+fn is_constraint_edge<Endpoint>(
+    constraints_indices: &[(usize, usize)],
+    mesh: &Mesh<Endpoint>,
+    edge: QuadEdge,
+) -> bool {
+    constraints_indices
+        .binary_search(&to_sorted_pair((
+            mesh.to_start_index(edge),
+            mesh.to_end_index(edge),
+        )))
+        .is_ok()
+}
+
+fn is_contour_edge<Endpoint>(
+    mesh: &Mesh<Endpoint>,
+    edge: QuadEdge,
+    contours_sizes: &[usize],
+    polygon_vertices_positions: &[Vec<PolygonVertexPosition>],
+) -> bool {
+    intersect_polygon_vertices_positions_slices::<true>(
+        &polygon_vertices_positions[mesh.to_start_index(edge)],
+        &polygon_vertices_positions[mesh.to_end_index(edge)],
+    )
+    .into_iter()
+    .any(|(start_position, end_position)| {
+        are_polygon_edge_indices(
+            start_position.vertex_index,
+            end_position.vertex_index,
+            contours_sizes[start_position.contour_index],
+        )
+    })
+}
+
 fn is_convex_quadrilateral_diagonal<Endpoint>(
     mesh: &Mesh<Endpoint>,
     edge: QuadEdge,
@@ -657,6 +1634,17 @@ fn is_polygon_edge<Endpoint>(
     })
 }
 
+fn is_triangular_face<Endpoint>(mesh: &Mesh<Endpoint>, edge: QuadEdge) -> bool
+where
+    for<'a> &'a Endpoint: Orient,
+{
+    let next_edge = to_left_face_next_edge(mesh, edge);
+    to_left_face_next_edge(mesh, to_left_face_next_edge(mesh, next_edge))
+        == edge
+        && mesh.orient_point_to_edge(edge, mesh.get_end(next_edge))
+            == Orientation::Counterclockwise
+}
+
 fn mouth_edge_to_incidents<Endpoint>(
     mesh: &Mesh<Endpoint>,
     edge: QuadEdge,
@@ -766,6 +1754,42 @@ where
     edge
 }
 
+/// searches edges from the start of the given one
+/// for the one lying on the constraint
+fn to_constraint_edge<Endpoint: PartialOrd>(
+    mesh: &Mesh<Endpoint>,
+    start_edge: QuadEdge,
+    constraint_start: &Endpoint,
+    constraint_end: &Endpoint,
+) -> Option<QuadEdge>
+where
+    for<'a> &'a Endpoint: Orient,
+{
+    let mut edge = start_edge;
+    loop {
+        let end = mesh.get_end(edge);
+        if end.eq(constraint_end)
+            || (constraint_start.orient(constraint_end, end)
+                == Orientation::Collinear
+                && ((constraint_start < end && end < constraint_end)
+                    || (constraint_end < end && end < constraint_start)))
+        {
+            return Some(edge);
+        }
+        edge = mesh.to_left_from_start(edge);
+        if edge == start_edge {
+            return None;
+        }
+    }
+}
+
+fn to_left_face_next_edge<Endpoint>(
+    mesh: &Mesh<Endpoint>,
+    edge: QuadEdge,
+) -> QuadEdge {
+    mesh.to_right_from_start(to_opposite_edge(edge))
+}
+
 fn to_constraint_index(
     first_vertex_index: usize,
     second_vertex_index: usize,
@@ -812,3 +1836,41 @@ fn to_contours_constraints_flags<Endpoint>(
     }
     are_constraints_satisfied
 }
+
+fn to_triangular_contours_vertices_indices(
+    triangular_contours_indices: &[usize],
+    polygon_vertices_positions: &[Vec<PolygonVertexPosition>],
+) -> Vec<[usize; 3]> {
+    debug_assert!(triangular_contours_indices
+        .windows(2)
+        .all(|window| window[0] < window[1]));
+    let mut result = vec![[0usize; 3]; triangular_contours_indices.len()];
+    for (vertex_index, positions) in
+        polygon_vertices_positions.iter().enumerate()
+    {
+        for position in positions {
+            if let Ok(index) = triangular_contours_indices
+                .binary_search(&position.contour_index)
+            {
+                result[index][position.vertex_index] = vertex_index;
+            }
+        }
+    }
+    for vertices_indices in &mut result {
+        vertices_indices.sort_unstable();
+    }
+    result.sort_unstable();
+    result
+}
+
+impl<Endpoint: PartialOrd> Locatable<&Endpoint>
+    for &ConstrainedDelaunayTriangulation<Endpoint>
+where
+    for<'a> &'a Endpoint: Orient,
+{
+    fn locate(self, point: &Endpoint) -> Location {
+        to_location(&self.mesh, self.locate_in_mesh(point), |edge| {
+            self.is_triangle(edge)
+        })
+    }
+}
@@ -1,15 +1,63 @@
+use std::sync::OnceLock;
+
 use crate::constants::MIN_CONTOUR_VERTICES_COUNT;
-use crate::operations::{shrink_collinear_vertices, Orient};
+use crate::geometries::{
+    ConstructionError, Contour, Multipolygon, Point, Polygon,
+};
+use crate::locatable::{Locatable, Location};
+use crate::operations::{
+    shrink_collinear_vertices, LocatePointInPointPointPointCircle, Orient,
+};
+use crate::oriented::Orientation;
+use crate::traits::{Multipolygonal, Multivertexal, Polygonal};
 
-use super::mesh::Mesh;
+use super::locatable::{to_location, TriangleLocation};
+use super::mesh::{Mesh, MeshLocation};
 use super::operations::{BoundaryEndpoints, DelaunayTriangulatable};
-use super::quad_edge::{QuadEdge, UNDEFINED_QUAD_EDGE};
+use super::quad_edge::{to_opposite_edge, QuadEdge, UNDEFINED_QUAD_EDGE};
 
+/// Delaunay triangulation of a set of points
+/// with duplicate points removed.
+///
+/// ```
+/// use rene::geometries::{Contour, Point};
+/// use rene::triangulation::DelaunayTriangulation;
+/// use rithm::big_int::BigInt;
+/// use rithm::fraction::Fraction;
+///
+/// type Scalar = Fraction<BigInt<u32, 31>>;
+///
+/// let to_point = |x: i32, y: i32| {
+///     Point::new(
+///         Scalar::from(BigInt::from(x)),
+///         Scalar::from(BigInt::from(y)),
+///     )
+/// };
+/// let triangulation = DelaunayTriangulation::from(vec![
+///     to_point(0, 0),
+///     to_point(1, 0),
+///     to_point(1, 1),
+///     to_point(0, 1),
+///     to_point(0, 0),
+/// ]);
+/// assert_eq!(triangulation.get_vertices().len(), 4);
+/// assert_eq!(triangulation.to_triangles().len(), 2);
+/// assert!(
+///     triangulation.to_border().unwrap()
+///         == Contour::new(vec![
+///             to_point(0, 0),
+///             to_point(1, 0),
+///             to_point(1, 1),
+///             to_point(0, 1),
+///         ])
+/// );
+/// ```
 #[derive(Clone)]
-pub(crate) struct DelaunayTriangulation<Endpoint> {
+pub struct DelaunayTriangulation<Endpoint> {
     left_side: QuadEdge,
     mesh: Mesh<Endpoint>,
     right_side: QuadEdge,
+    triangles_base_edges: OnceLock<Vec<QuadEdge>>,
 }
 
 impl<Endpoint> BoundaryEndpoints<Endpoint> for DelaunayTriangulation<Endpoint>
@@ -50,28 +98,454 @@ where
             left_side,
             mesh,
             right_side,
+            triangles_base_edges: OnceLock::new(),
         }
     }
 }
 
+/// triangulates vertices of the border and holes of the polygon
+impl<Scalar> From<&Polygon<Scalar>> for DelaunayTriangulation<Point<Scalar>>
+where
+    Point<Scalar>: Clone + Ord,
+    for<'a> &'a Point<Scalar>: LocatePointInPointPointPointCircle + Orient,
+{
+    fn from(polygon: &Polygon<Scalar>) -> Self {
+        Self::from(
+            polygon
+                .border()
+                .vertices()
+                .into_iter()
+                .chain(
+                    polygon
+                        .holes()
+                        .into_iter()
+                        .flat_map(|hole| hole.vertices().into_iter()),
+                )
+                .cloned()
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+/// triangulates vertices of borders and holes of the multipolygon
+impl<Scalar> From<&Multipolygon<Scalar>>
+    for DelaunayTriangulation<Point<Scalar>>
+where
+    Point<Scalar>: Clone + Ord,
+    for<'a> &'a Point<Scalar>: LocatePointInPointPointPointCircle + Orient,
+{
+    fn from(multipolygon: &Multipolygon<Scalar>) -> Self {
+        Self::from(
+            multipolygon
+                .polygons()
+                .into_iter()
+                .flat_map(|polygon| {
+                    polygon.border().vertices().into_iter().chain(
+                        polygon
+                            .holes()
+                            .into_iter()
+                            .flat_map(|hole| hole.vertices().into_iter()),
+                    )
+                })
+                .cloned()
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
 impl<Endpoint> DelaunayTriangulation<Endpoint> {
-    pub(crate) fn is_empty(&self) -> bool {
+    pub(super) fn get_mesh(&self) -> &Mesh<Endpoint> {
+        &self.mesh
+    }
+
+    /// unique points of the triangulation sorted on its creation
+    /// followed by inserted ones in the order of insertion,
+    /// a removed point is replaced by the last one,
+    /// triangles indices refer to them
+    pub fn get_vertices(&self) -> &[Endpoint] {
+        self.mesh.get_endpoints()
+    }
+
+    /// checks if the triangulation has no edges,
+    /// which is the case for less than two distinct points
+    pub fn is_empty(&self) -> bool {
         let result = self.mesh.is_empty();
         debug_assert_eq!(self.left_side == UNDEFINED_QUAD_EDGE, result);
         debug_assert_eq!(self.right_side == UNDEFINED_QUAD_EDGE, result);
         result
     }
+
+    fn is_degenerate(&self) -> bool {
+        self.mesh.get_endpoints().len() < MIN_CONTOUR_VERTICES_COUNT
+            || self.mesh.to_left_from_start(self.left_side) == self.left_side
+    }
+}
+
+impl<Endpoint: Clone + Ord> DelaunayTriangulation<Endpoint>
+where
+    for<'a> &'a Endpoint: LocatePointInPointPointPointCircle + Orient,
+{
+    /// inserts the point restoring the Delaunay property,
+    /// returns `false` if it is already a vertex,
+    /// the point gets the next vertex index,
+    /// so indices of other vertices are kept,
+    /// but previously obtained triangles indices are invalidated
+    pub fn insert(&mut self, point: Endpoint) -> bool {
+        if self.is_degenerate() {
+            if self.mesh.get_endpoints().contains(&point) {
+                return false;
+            }
+            let mut endpoints = self.take_endpoints();
+            endpoints.push(point);
+            *self = Self::from_unique_endpoints(endpoints);
+        } else {
+            let location =
+                unsafe { self.locate_in_mesh(&point).unwrap_unchecked() };
+            if matches!(location, MeshLocation::Vertex(_)) {
+                return false;
+            }
+            let (mut left_index, mut right_index) = (
+                self.mesh.to_start_index(self.left_side),
+                self.mesh.to_start_index(self.right_side),
+            );
+            let index = self.mesh.push_endpoint(point);
+            let point = &self.mesh.get_endpoints()[index];
+            if point < &self.mesh.get_endpoints()[left_index] {
+                left_index = index;
+            } else if point > &self.mesh.get_endpoints()[right_index] {
+                right_index = index;
+            }
+            match location {
+                MeshLocation::Edge(edge)
+                    if !self.mesh.is_left_triangular_face(edge)
+                        || !self.mesh.is_left_triangular_face(
+                            to_opposite_edge(edge),
+                        ) =>
+                {
+                    let inner_edge = if self.mesh.is_left_triangular_face(edge)
+                    {
+                        edge
+                    } else {
+                        to_opposite_edge(edge)
+                    };
+                    let boundary_edge = self.mesh.to_left_from_end(inner_edge);
+                    self.mesh.delete_edge(inner_edge);
+                    self.mesh.insert_outer_endpoint(boundary_edge, index);
+                }
+                MeshLocation::Exterior(edge) => {
+                    self.mesh.insert_outer_endpoint(edge, index)
+                }
+                location => self.mesh.insert_inner_endpoint(location, index),
+            }
+            self.update_sides(left_index, right_index);
+            self.triangles_base_edges = OnceLock::new();
+        }
+        true
+    }
+
+    /// removes the point restoring the Delaunay property,
+    /// returns `false` if it is not a vertex,
+    /// the last vertex takes the index of the removed one,
+    /// so indices of other vertices are kept,
+    /// but previously obtained triangles indices are invalidated
+    pub fn remove(&mut self, point: &Endpoint) -> bool {
+        if self.is_degenerate()
+            || self.mesh.get_endpoints().len() <= MIN_CONTOUR_VERTICES_COUNT
+        {
+            let Some(index) = self
+                .mesh
+                .get_endpoints()
+                .iter()
+                .position(|endpoint| endpoint == point)
+            else {
+                return false;
+            };
+            let mut endpoints = self.take_endpoints();
+            endpoints.swap_remove(index);
+            *self = Self::from_unique_endpoints(endpoints);
+        } else {
+            let Some(MeshLocation::Vertex(edge)) = self.locate_in_mesh(point)
+            else {
+                return false;
+            };
+            let index = self.mesh.to_start_index(edge);
+            let (mut left_index, mut right_index) = (
+                self.mesh.to_start_index(self.left_side),
+                self.mesh.to_start_index(self.right_side),
+            );
+            if index == left_index || index == right_index {
+                // the next extreme vertex is a neighbour of the removed one
+                let mut neighbours_indices = Vec::new();
+                let mut spoke = edge;
+                loop {
+                    neighbours_indices.push(self.mesh.to_end_index(spoke));
+                    spoke = self.mesh.to_left_from_start(spoke);
+                    if spoke == edge {
+                        break;
+                    }
+                }
+                let endpoints = self.mesh.get_endpoints();
+                let to_endpoint = |&index: &usize| &endpoints[index];
+                if index == left_index {
+                    left_index = unsafe {
+                        neighbours_indices
+                            .iter()
+                            .copied()
+                            .min_by_key(to_endpoint)
+                            .unwrap_unchecked()
+                    };
+                } else {
+                    right_index = unsafe {
+                        neighbours_indices
+                            .iter()
+                            .copied()
+                            .max_by_key(to_endpoint)
+                            .unwrap_unchecked()
+                    };
+                }
+            }
+            self.mesh.remove_vertex(edge);
+            let last_index = self.mesh.get_endpoints().len() - 1;
+            let last_endpoint_edge = (index != last_index).then(|| {
+                self.locate_vertex(&self.mesh.get_endpoints()[last_index])
+            });
+            self.mesh.swap_remove_endpoint(index, last_endpoint_edge);
+            if left_index == last_index {
+                left_index = index;
+            }
+            if right_index == last_index {
+                right_index = index;
+            }
+            self.update_sides(left_index, right_index);
+            self.triangles_base_edges = OnceLock::new();
+        }
+        true
+    }
+
+    /// triangulates unique endpoints keeping their order
+    fn from_unique_endpoints(endpoints: Vec<Endpoint>) -> Self {
+        let mut mesh = Mesh::from(endpoints);
+        let sorted_indices = mesh.sort_endpoints();
+        let (left_side, right_side) = mesh.delaunay_triangulation();
+        let mut original_indices = vec![0; sorted_indices.len()];
+        for (index, sorted_index) in sorted_indices.into_iter().enumerate() {
+            original_indices[sorted_index] = index;
+        }
+        mesh.permute_endpoints(&original_indices);
+        Self {
+            left_side,
+            mesh,
+            right_side,
+            triangles_base_edges: OnceLock::new(),
+        }
+    }
+
+    /// edge starting at the vertex,
+    /// does not rely on sides, so works while they are outdated
+    fn locate_vertex(&self, vertex: &Endpoint) -> QuadEdge {
+        match self
+            .mesh
+            .locate(vertex)
+            .or_else(|| self.mesh.locate_by_scan(vertex, |_| false))
+        {
+            Some(MeshLocation::Vertex(edge)) => edge,
+            _ => {
+                unreachable!("Point should be a vertex of the triangulation.")
+            }
+        }
+    }
+
+    fn take_endpoints(&mut self) -> Vec<Endpoint> {
+        std::mem::replace(&mut self.mesh, Mesh::from(Vec::new()))
+            .into_endpoints()
+    }
+
+    fn to_vertex_edge(&self, candidate: QuadEdge, index: usize) -> QuadEdge {
+        if !self.mesh.is_deleted_edge(candidate)
+            && self.mesh.to_start_index(candidate) == index
+        {
+            candidate
+        } else {
+            unsafe {
+                self.mesh
+                    .iter_edges()
+                    .find(|&edge| self.mesh.to_start_index(edge) == index)
+                    .unwrap_unchecked()
+            }
+        }
+    }
+
+    fn update_sides(&mut self, left_index: usize, right_index: usize) {
+        let mut left_side = self.to_vertex_edge(self.left_side, left_index);
+        loop {
+            let candidate = self.mesh.to_right_from_start(left_side);
+            if candidate == left_side
+                || self.mesh.orient_point_to_edge(
+                    left_side,
+                    self.mesh.get_end(candidate),
+                ) == Orientation::Counterclockwise
+            {
+                break;
+            }
+            left_side = candidate;
+        }
+        let mut right_side = self.to_vertex_edge(self.right_side, right_index);
+        loop {
+            let candidate = self.mesh.to_left_from_start(right_side);
+            if candidate == right_side
+                || self.mesh.orient_point_to_edge(
+                    right_side,
+                    self.mesh.get_end(candidate),
+                ) == Orientation::Clockwise
+            {
+                break;
+            }
+            right_side = candidate;
+        }
+        (self.left_side, self.right_side) = (left_side, right_side);
+    }
 }
 
 impl<Endpoint: PartialOrd> DelaunayTriangulation<Endpoint>
 where
     for<'a> &'a Endpoint: Orient,
 {
-    pub(crate) fn iter_triangles_vertices(
+    /// indices of counterclockwise oriented triangles' vertices
+    pub fn to_triangles_indices(
+        &self,
+    ) -> impl Iterator<Item = [usize; 3]> + '_ {
+        self.mesh.to_triangles_base_edges().map(move |base_edge| {
+            self.mesh.triangle_base_to_indices(base_edge)
+        })
+    }
+
+    /// locates the point walking through triangles
+    /// from the last located edge,
+    /// falls back to checking every edge if the walk does not end
+    pub fn locate_triangle(&self, point: &Endpoint) -> TriangleLocation {
+        match self.locate_in_mesh(point) {
+            Some(MeshLocation::Edge(edge)) => {
+                TriangleLocation::Edge(self.mesh.to_edge_indices(edge))
+            }
+            Some(MeshLocation::Face(edge)) => {
+                TriangleLocation::Triangle(self.to_triangle_index(edge))
+            }
+            Some(MeshLocation::Vertex(edge)) => {
+                TriangleLocation::Vertex(self.mesh.to_start_index(edge))
+            }
+            None if self.mesh.is_empty() => self
+                .mesh
+                .get_endpoints()
+                .iter()
+                .position(|vertex| vertex == point)
+                .map_or(TriangleLocation::Exterior, TriangleLocation::Vertex),
+            Some(MeshLocation::Exterior(_)) | None => {
+                TriangleLocation::Exterior
+            }
+        }
+    }
+
+    /// counterclockwise oriented triangles' vertices
+    pub fn to_triangles_vertices(
         &self,
     ) -> impl Iterator<Item = (&Endpoint, &Endpoint, &Endpoint)> {
         self.mesh.to_triangles_base_edges().map(move |base_edge| {
             self.mesh.triangle_base_to_vertices(base_edge)
         })
     }
+
+    /// sorted pairs of sorted vertices indices of edges
+    pub fn to_edges_indices(&self) -> Vec<[usize; 2]> {
+        self.mesh.to_edges_indices()
+    }
+
+    /// fails only for points outside of edges & vertices
+    /// of degenerate triangulations
+    fn locate_in_mesh(&self, point: &Endpoint) -> Option<MeshLocation> {
+        if self.is_degenerate() {
+            self.mesh.locate_by_scan(point, |_| false)
+        } else {
+            self.mesh
+                .locate(point)
+                .or_else(|| {
+                    self.mesh.locate_by_scan(point, |edge| {
+                        self.mesh.is_left_triangular_face(edge)
+                    })
+                })
+                .or_else(|| {
+                    // the point lies outside of the convex hull,
+                    // so some of its boundary edges face the point
+                    self.mesh
+                        .iter_edges()
+                        .find(|&edge| {
+                            !self.mesh.is_left_triangular_face(edge)
+                                && self.mesh.orient_point_to_edge(edge, point)
+                                    == Orientation::Counterclockwise
+                        })
+                        .map(MeshLocation::Exterior)
+                })
+        }
+    }
+
+    fn to_triangle_index(&self, edge: QuadEdge) -> usize {
+        unsafe {
+            self.triangles_base_edges
+                .get_or_init(|| self.mesh.to_triangles_base_edges().collect())
+                .binary_search(&self.mesh.to_triangle_base_edge(edge))
+                .unwrap_unchecked()
+        }
+    }
+
+    /// indices of triangles adjacent to each triangle
+    /// in the order of `to_triangles_indices`,
+    /// i-th neighbour lies across the edge
+    /// from i-th to the next vertex of the triangle,
+    /// `None` for edges on the border
+    pub fn to_triangles_neighbours(&self) -> Vec<[Option<usize>; 3]> {
+        self.mesh.to_triangles_neighbours(
+            &self.mesh.to_triangles_base_edges().collect::<Vec<_>>(),
+        )
+    }
+
+    /// indices of edges (as in `to_edges_indices`) incident to each vertex
+    /// in counterclockwise order around it
+    pub fn to_vertices_edges_indices(&self) -> Vec<Vec<usize>> {
+        self.mesh
+            .to_vertices_edges_indices(&self.mesh.to_edges_indices())
+    }
+}
+
+impl<Scalar> DelaunayTriangulation<Point<Scalar>>
+where
+    Point<Scalar>: Clone + PartialOrd,
+    for<'a> &'a Point<Scalar>: Orient,
+{
+    /// counterclockwise contour of the convex hull of the points,
+    /// fails for less than three non-collinear points
+    pub fn to_border(&self) -> Result<Contour<Scalar>, ConstructionError> {
+        Contour::try_new(
+            self.get_boundary_endpoints().into_iter().cloned().collect(),
+        )
+    }
+
+    /// counterclockwise oriented triangles
+    pub fn to_triangles(&self) -> Vec<Contour<Scalar>> {
+        self.to_triangles_vertices()
+            .map(|(first, second, third)| {
+                Contour::from([first.clone(), second.clone(), third.clone()])
+            })
+            .collect()
+    }
+}
+
+impl<Endpoint: PartialOrd> Locatable<&Endpoint>
+    for &DelaunayTriangulation<Endpoint>
+where
+    for<'a> &'a Endpoint: Orient,
+{
+    fn locate(self, point: &Endpoint) -> Location {
+        to_location(&self.mesh, self.locate_in_mesh(point), |edge| {
+            self.mesh.is_left_triangular_face(edge)
+        })
+    }
 }
@@ -0,0 +1,60 @@
+use crate::locatable::Location;
+
+use super::mesh::{Mesh, MeshLocation};
+use super::quad_edge::{to_opposite_edge, QuadEdge};
+
+/// location of a point relative to triangles of a triangulation
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TriangleLocation {
+    /// point lies strictly inside of the edge
+    /// with given sorted vertices indices
+    Edge([usize; 2]),
+    /// point lies outside of triangles, edges and vertices
+    Exterior,
+    /// point lies strictly inside of the triangle with given index
+    /// in the order of triangles indices
+    Triangle(usize),
+    /// point coincides with the vertex with given index
+    Vertex(usize),
+}
+
+/// `None` location should be passed only for points known to lie
+/// outside of the mesh, walks which do not end should be resolved
+/// by callers beforehand
+pub(super) fn to_location<Endpoint>(
+    mesh: &Mesh<Endpoint>,
+    location: Option<MeshLocation>,
+    is_triangle: impl Fn(QuadEdge) -> bool,
+) -> Location {
+    match location {
+        Some(MeshLocation::Edge(edge)) => {
+            match (is_triangle(edge), is_triangle(to_opposite_edge(edge))) {
+                (true, true) => Location::Interior,
+                (false, false) => Location::Exterior,
+                _ => Location::Boundary,
+            }
+        }
+        Some(MeshLocation::Face(_)) => Location::Interior,
+        Some(MeshLocation::Vertex(edge)) => {
+            let (mut has_triangles, mut has_gaps) = (false, false);
+            let mut spoke = edge;
+            loop {
+                if is_triangle(spoke) {
+                    has_triangles = true;
+                } else {
+                    has_gaps = true;
+                }
+                spoke = mesh.to_left_from_start(spoke);
+                if spoke == edge {
+                    break;
+                }
+            }
+            match (has_triangles, has_gaps) {
+                (true, false) => Location::Interior,
+                (true, true) => Location::Boundary,
+                _ => Location::Exterior,
+            }
+        }
+        Some(MeshLocation::Exterior(_)) | None => Location::Exterior,
+    }
+}
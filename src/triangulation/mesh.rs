@@ -1,3 +1,6 @@
+use std::cmp::Ordering;
+use std::sync::atomic::{self, AtomicUsize};
+
 use traiter::numbers::{DivRem, Parity};
 
 use crate::locatable::Location;
@@ -11,19 +14,50 @@ use super::quad_edge::{
     to_opposite_edge, to_rotated_edge, QuadEdge, UNDEFINED_QUAD_EDGE,
 };
 
-#[derive(Clone)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(super) enum MeshLocation {
+    /// point lies strictly inside of the edge
+    Edge(QuadEdge),
+    /// point lies outside of the mesh, but not to the right of the edge,
+    /// which has no triangle on the left
+    Exterior(QuadEdge),
+    /// point lies strictly inside of the triangle on the left of the edge
+    Face(QuadEdge),
+    /// point coincides with the start of the edge
+    Vertex(QuadEdge),
+}
+
 pub(super) struct Mesh<Endpoint> {
     endpoints: Vec<Endpoint>,
+    /// quad-edges of deleted edges to be reused by created ones
+    free_edges: Vec<QuadEdge>,
     left_from_start: Vec<QuadEdge>,
+    located_edge: AtomicUsize,
     starts_indices: Vec<usize>,
 }
 
+impl<Endpoint: Clone> Clone for Mesh<Endpoint> {
+    fn clone(&self) -> Self {
+        Self {
+            endpoints: self.endpoints.clone(),
+            free_edges: self.free_edges.clone(),
+            left_from_start: self.left_from_start.clone(),
+            located_edge: AtomicUsize::new(
+                self.located_edge.load(atomic::Ordering::Relaxed),
+            ),
+            starts_indices: self.starts_indices.clone(),
+        }
+    }
+}
+
 impl<Endpoint> From<Vec<Endpoint>> for Mesh<Endpoint> {
     fn from(endpoints: Vec<Endpoint>) -> Self {
         let endpoints_count = endpoints.len();
         Self {
             endpoints,
+            free_edges: Vec::new(),
             left_from_start: Vec::with_capacity(4 * endpoints_count),
+            located_edge: AtomicUsize::new(UNDEFINED_QUAD_EDGE),
             starts_indices: Vec::with_capacity(2 * endpoints_count),
         }
     }
@@ -42,6 +76,78 @@ impl<Endpoint> Mesh<Endpoint> {
         &self.endpoints[self.to_start_index(to_opposite_edge(edge))]
     }
 
+    /// appends the endpoint returning its index
+    pub(super) fn push_endpoint(&mut self, endpoint: Endpoint) -> usize {
+        self.endpoints.push(endpoint);
+        self.endpoints.len() - 1
+    }
+
+    /// moves endpoints to given new indices renumbering edges' ones
+    pub(super) fn permute_endpoints(&mut self, new_indices: &[usize]) {
+        debug_assert_eq!(new_indices.len(), self.endpoints.len());
+        let mut indexed_endpoints = std::mem::take(&mut self.endpoints)
+            .into_iter()
+            .zip(new_indices)
+            .collect::<Vec<_>>();
+        indexed_endpoints.sort_by_key(|&(_, &new_index)| new_index);
+        self.endpoints = indexed_endpoints
+            .into_iter()
+            .map(|(endpoint, _)| endpoint)
+            .collect();
+        for start_index in &mut self.starts_indices {
+            *start_index = new_indices[*start_index];
+        }
+    }
+
+    /// sorts endpoints renumbering edges' ones,
+    /// returns new indices of endpoints
+    pub(super) fn sort_endpoints(&mut self) -> Vec<usize>
+    where
+        Endpoint: Ord,
+    {
+        let mut indices = (0..self.endpoints.len()).collect::<Vec<_>>();
+        indices.sort_by(|&first, &second| {
+            self.endpoints[first].cmp(&self.endpoints[second])
+        });
+        let mut result = vec![0; indices.len()];
+        for (index, old_index) in indices.into_iter().enumerate() {
+            result[old_index] = index;
+        }
+        self.permute_endpoints(&result);
+        result
+    }
+
+    /// removes the endpoint moving the last one into its place,
+    /// so only edges starting at the latter are renumbered,
+    /// the removed endpoint should have no edges
+    /// & the given edge should start at the last endpoint
+    /// unless the removed endpoint is the last one
+    pub(super) fn swap_remove_endpoint(
+        &mut self,
+        index: usize,
+        last_endpoint_edge: Option<QuadEdge>,
+    ) -> Endpoint {
+        if let Some(edge) = last_endpoint_edge {
+            debug_assert_eq!(
+                self.to_start_index(edge),
+                self.endpoints.len() - 1
+            );
+            let mut spoke = edge;
+            loop {
+                self.starts_indices[spoke / 2] = index;
+                spoke = self.to_left_from_start(spoke);
+                if spoke == edge {
+                    break;
+                }
+            }
+        }
+        self.endpoints.swap_remove(index)
+    }
+
+    pub(super) fn into_endpoints(self) -> Vec<Endpoint> {
+        self.endpoints
+    }
+
     pub(super) fn is_empty(&self) -> bool {
         self.left_from_start.is_empty()
     }
@@ -109,8 +215,35 @@ impl<Endpoint> Mesh<Endpoint> {
         result
     }
 
-    fn is_deleted_edge(&self, edge: QuadEdge) -> bool {
-        self.to_left_from_start(edge) == edge
+    /// checks if each vertex with edges is reachable from any other one
+    pub(super) fn is_connected(&self) -> bool {
+        let Some(start_edge) = self.iter_edges().next() else {
+            return true;
+        };
+        let mut are_vertices_visited = vec![false; self.endpoints.len()];
+        let mut edges = vec![start_edge];
+        while let Some(edge) = edges.pop() {
+            let vertex_index = self.to_start_index(edge);
+            if are_vertices_visited[vertex_index] {
+                continue;
+            }
+            are_vertices_visited[vertex_index] = true;
+            let mut spoke = edge;
+            loop {
+                edges.push(to_opposite_edge(spoke));
+                spoke = self.to_left_from_start(spoke);
+                if spoke == edge {
+                    break;
+                }
+            }
+        }
+        self.iter_edges()
+            .all(|edge| are_vertices_visited[self.to_start_index(edge)])
+    }
+
+    pub(super) fn is_deleted_edge(&self, edge: QuadEdge) -> bool {
+        let rotated_edge = to_rotated_edge(edge);
+        self.to_left_from_start(rotated_edge) == rotated_edge
     }
 }
 
@@ -134,6 +267,17 @@ impl<Endpoint> Mesh<Endpoint> {
         start_index: usize,
         end_index: usize,
     ) -> QuadEdge {
+        if let Some(edge) = self.free_edges.pop() {
+            self.starts_indices[edge / 2] = start_index;
+            self.starts_indices[edge / 2 + 1] = end_index;
+            self.left_from_start[edge..edge + 4].copy_from_slice(&[
+                edge,
+                edge + 3,
+                edge + 2,
+                edge + 1,
+            ]);
+            return edge;
+        }
         self.starts_indices.push(start_index);
         self.starts_indices.push(end_index);
         let edge = self.left_from_start.len();
@@ -154,6 +298,33 @@ impl<Endpoint> Mesh<Endpoint> {
             opposite_edge,
             self.to_right_from_start(opposite_edge),
         );
+        // isolated edges differ from deleted ones by self-looped dual edges
+        let (rotated_edge, rotated_opposite_edge) =
+            (to_rotated_edge(edge), to_rotated_edge(opposite_edge));
+        self.left_from_start[rotated_edge] = rotated_edge;
+        self.left_from_start[rotated_opposite_edge] = rotated_opposite_edge;
+        self.free_edges.push((edge >> 2) << 2);
+    }
+
+    /// splits the edge at the endpoint with the given index
+    /// lying on it, so the edge ends at the endpoint,
+    /// returns the edge from the endpoint to the former end of the edge
+    pub(super) fn split_edge(
+        &mut self,
+        edge: QuadEdge,
+        endpoint_index: usize,
+    ) -> QuadEdge {
+        let opposite_edge = to_opposite_edge(edge);
+        let end_index = self.to_start_index(opposite_edge);
+        let end_prior_edge = self.to_right_from_start(opposite_edge);
+        self.splice_edges(opposite_edge, end_prior_edge);
+        self.starts_indices[opposite_edge / 2] = endpoint_index;
+        let result = self.create_edge(endpoint_index, end_index);
+        self.splice_edges(result, opposite_edge);
+        if end_prior_edge != opposite_edge {
+            self.splice_edges(to_opposite_edge(result), end_prior_edge);
+        }
+        result
     }
 
     pub(super) fn splice_edges(&mut self, first: QuadEdge, second: QuadEdge) {
@@ -205,6 +376,118 @@ impl<Endpoint> Mesh<Endpoint> {
         })
     }
 
+    pub(super) fn to_edges_indices(&self) -> Vec<[usize; 2]> {
+        let mut result = self
+            .iter_unique_edges()
+            .map(|edge| self.to_edge_indices(edge))
+            .collect::<Vec<_>>();
+        result.sort_unstable();
+        result
+    }
+
+    pub(super) fn to_triangles_neighbours(
+        &self,
+        triangles_base_edges: &[QuadEdge],
+    ) -> Vec<[Option<usize>; 3]> {
+        let mut edges_triangles = vec![None; self.left_from_start.len() / 2];
+        for (triangle_index, &base_edge) in
+            triangles_base_edges.iter().enumerate()
+        {
+            for edge in self.triangle_base_to_edges(base_edge) {
+                edges_triangles[edge / 2] = Some(triangle_index);
+            }
+        }
+        triangles_base_edges
+            .iter()
+            .map(|&base_edge| {
+                self.triangle_base_to_edges(base_edge)
+                    .map(|edge| edges_triangles[to_opposite_edge(edge) / 2])
+            })
+            .collect()
+    }
+
+    pub(super) fn to_vertices_edges_indices(
+        &self,
+        edges_indices: &[[usize; 2]],
+    ) -> Vec<Vec<usize>> {
+        let mut vertices_edges = vec![None; self.endpoints.len()];
+        for edge in self.iter_edges() {
+            vertices_edges[self.to_start_index(edge)].get_or_insert(edge);
+        }
+        vertices_edges
+            .into_iter()
+            .map(|vertex_edge| {
+                let mut result = Vec::new();
+                if let Some(start_edge) = vertex_edge {
+                    let mut edge = start_edge;
+                    loop {
+                        if let Ok(edge_index) = edges_indices
+                            .binary_search(&self.to_edge_indices(edge))
+                        {
+                            result.push(edge_index);
+                        }
+                        edge = self.to_left_from_start(edge);
+                        if edge == start_edge {
+                            break;
+                        }
+                    }
+                }
+                result
+            })
+            .collect()
+    }
+
+    /// sorted indices of the edge's endpoints
+    pub(super) fn to_edge_indices(&self, edge: QuadEdge) -> [usize; 2] {
+        let (start_index, end_index) =
+            (self.to_start_index(edge), self.to_end_index(edge));
+        if start_index < end_index {
+            [start_index, end_index]
+        } else {
+            [end_index, start_index]
+        }
+    }
+
+    /// edge of the triangle on the left of the given edge
+    /// starting from the least vertex
+    pub(super) fn to_triangle_base_edge(&self, edge: QuadEdge) -> QuadEdge {
+        self.triangle_base_to_edges(edge).iter().fold(
+            edge,
+            |base_edge, &candidate| {
+                if self.to_start_index(candidate)
+                    < self.to_start_index(base_edge)
+                {
+                    candidate
+                } else {
+                    base_edge
+                }
+            },
+        )
+    }
+
+    pub(super) fn triangle_base_to_edges(
+        &self,
+        edge: QuadEdge,
+    ) -> [QuadEdge; 3] {
+        let next_edge = self.to_right_from_start(to_opposite_edge(edge));
+        [
+            edge,
+            next_edge,
+            self.to_right_from_start(to_opposite_edge(next_edge)),
+        ]
+    }
+
+    pub(super) fn triangle_base_to_indices(
+        &self,
+        edge: QuadEdge,
+    ) -> [usize; 3] {
+        [
+            self.to_start_index(edge),
+            self.to_end_index(edge),
+            self.to_end_index(self.to_left_from_start(edge)),
+        ]
+    }
+
     pub(super) fn triangle_base_to_vertices(
         &self,
         edge: QuadEdge,
@@ -322,7 +605,8 @@ where
     ) -> (QuadEdge, QuadEdge) {
         let (first_right_side, base_edge, second_left_side) =
             self.build_base_edge(first_right_side, second_left_side);
-        self.rise_bubble(base_edge);
+        // sides should be picked before rising the bubble
+        // which may delete them
         let left_side = if self.get_start(first_left_side)
             == self.get_start(first_right_side)
         {
@@ -337,6 +621,7 @@ where
         } else {
             second_right_side
         };
+        self.rise_bubble(base_edge);
         (left_side, right_side)
     }
 
@@ -429,6 +714,528 @@ where
     }
 }
 
+impl<Endpoint: PartialOrd> Mesh<Endpoint>
+where
+    for<'a> &'a Endpoint: Orient,
+{
+    pub(super) fn is_left_triangular_face(&self, edge: QuadEdge) -> bool {
+        let next_edge = self.to_left_from_end(edge);
+        self.to_left_from_end(self.to_left_from_end(next_edge)) == edge
+            && self.orient_point_to_edge(edge, self.get_end(next_edge))
+                == Orientation::Counterclockwise
+    }
+
+    /// walks towards the point through triangular faces of the mesh
+    /// starting from the last located edge,
+    /// fails if the walk does not end in a number of steps
+    /// not exceeding the number of edges
+    pub(super) fn locate(&self, point: &Endpoint) -> Option<MeshLocation> {
+        let result = self.walk(point)?;
+        // the last located edge is only a hint for the next walk,
+        // so concurrent queries may overwrite each other's ones
+        self.located_edge.store(
+            match result {
+                MeshLocation::Edge(edge)
+                | MeshLocation::Exterior(edge)
+                | MeshLocation::Face(edge)
+                | MeshLocation::Vertex(edge) => edge,
+            },
+            atomic::Ordering::Relaxed,
+        );
+        Some(result)
+    }
+
+    /// checks every edge of the mesh, only faces on the left of edges
+    /// satisfying the predicate are considered
+    pub(super) fn locate_by_scan(
+        &self,
+        point: &Endpoint,
+        is_triangle: impl Fn(QuadEdge) -> bool,
+    ) -> Option<MeshLocation> {
+        if let Some(edge) = self
+            .iter_edges()
+            .find(|&edge| self.get_start(edge) == point)
+        {
+            return Some(MeshLocation::Vertex(edge));
+        }
+        for edge in self.iter_edges() {
+            let orientation = self.orient_point_to_edge(edge, point);
+            if orientation == Orientation::Collinear
+                && ((self.get_start(edge) < point)
+                    == (point < self.get_end(edge)))
+            {
+                return Some(MeshLocation::Edge(edge));
+            } else if orientation == Orientation::Counterclockwise
+                && is_triangle(edge)
+            {
+                let next_edge = self.to_left_from_end(edge);
+                if self.orient_point_to_edge(next_edge, point)
+                    == Orientation::Counterclockwise
+                    && self.orient_point_to_edge(
+                        self.to_left_from_end(next_edge),
+                        point,
+                    ) == Orientation::Counterclockwise
+                {
+                    return Some(MeshLocation::Face(edge));
+                }
+            }
+        }
+        None
+    }
+
+    /// walks upwards along the vertical line through the point
+    /// (tilted by the lexicographic order of points) starting from the face
+    /// on the left of the given edge, faces which are not triangles
+    /// are crossed by checking their boundaries,
+    /// so each such face should have a single boundary
+    /// (like in connected meshes),
+    /// fails if the point lies below the boundary of the starting face
+    pub(super) fn locate_vertically(
+        &self,
+        point: &Endpoint,
+        mut face_edge: QuadEdge,
+    ) -> Option<MeshLocation> {
+        loop {
+            debug_assert!(!self.is_left_triangular_face(face_edge));
+            let mut below_edge: Option<QuadEdge> = None;
+            let mut edge = face_edge;
+            loop {
+                if self.get_start(edge) == point {
+                    return Some(MeshLocation::Vertex(edge));
+                } else if self.get_end(edge) == point {
+                    return Some(MeshLocation::Vertex(to_opposite_edge(edge)));
+                } else if self.is_vertically_crossed_edge(edge, point) {
+                    match self.orient_point_to_sorted_edge(edge, point) {
+                        Orientation::Collinear => {
+                            return Some(MeshLocation::Edge(edge));
+                        }
+                        Orientation::Counterclockwise => {
+                            if below_edge.map_or(true, |below_edge| match self
+                                .compare_vertically(below_edge, edge)
+                            {
+                                Ordering::Equal => {
+                                    self.get_start(edge) < self.get_end(edge)
+                                }
+                                ordering => ordering == Ordering::Less,
+                            }) {
+                                below_edge = Some(edge);
+                            }
+                        }
+                        Orientation::Clockwise => {}
+                    }
+                }
+                edge = self.to_left_from_end(edge);
+                if edge == face_edge {
+                    break;
+                }
+            }
+            let below_edge = below_edge?;
+            if self.get_start(below_edge) < self.get_end(below_edge) {
+                // the face lies above the edge, so it contains the point
+                return Some(MeshLocation::Exterior(below_edge));
+            }
+            edge = to_opposite_edge(below_edge);
+            loop {
+                if !self.is_left_triangular_face(edge) {
+                    face_edge = edge;
+                    break;
+                }
+                let next_edge = self.to_left_from_end(edge);
+                let prior_edge = self.to_left_from_end(next_edge);
+                if self.get_start(prior_edge) == point {
+                    return Some(MeshLocation::Vertex(prior_edge));
+                }
+                let exit_edge =
+                    if self.is_vertically_crossed_edge(next_edge, point) {
+                        next_edge
+                    } else {
+                        prior_edge
+                    };
+                match self.orient_point_to_sorted_edge(exit_edge, point) {
+                    Orientation::Clockwise => {
+                        return Some(MeshLocation::Face(edge));
+                    }
+                    Orientation::Collinear => {
+                        return Some(MeshLocation::Edge(exit_edge));
+                    }
+                    Orientation::Counterclockwise => {
+                        edge = to_opposite_edge(exit_edge);
+                    }
+                }
+            }
+        }
+    }
+
+    /// compares heights of edges crossed by a vertical line,
+    /// edges should not cross each other
+    fn compare_vertically(
+        &self,
+        first: QuadEdge,
+        second: QuadEdge,
+    ) -> Ordering {
+        let second_start_orientation =
+            self.orient_point_to_sorted_edge(first, self.get_start(second));
+        let second_end_orientation =
+            self.orient_point_to_sorted_edge(first, self.get_end(second));
+        if second_start_orientation == Orientation::Collinear
+            && second_end_orientation == Orientation::Collinear
+        {
+            Ordering::Equal
+        } else if second_start_orientation != Orientation::Clockwise
+            && second_end_orientation != Orientation::Clockwise
+        {
+            Ordering::Less
+        } else if second_start_orientation != Orientation::Counterclockwise
+            && second_end_orientation != Orientation::Counterclockwise
+        {
+            Ordering::Greater
+        } else if self
+            .orient_point_to_sorted_edge(second, self.get_start(first))
+            != Orientation::Counterclockwise
+            && self.orient_point_to_sorted_edge(second, self.get_end(first))
+                != Orientation::Counterclockwise
+        {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        }
+    }
+
+    fn is_vertically_crossed_edge(
+        &self,
+        edge: QuadEdge,
+        point: &Endpoint,
+    ) -> bool {
+        (self.get_start(edge) < point) != (self.get_end(edge) < point)
+    }
+
+    /// orientation of the point relative to the edge
+    /// directed from its least endpoint
+    fn orient_point_to_sorted_edge(
+        &self,
+        edge: QuadEdge,
+        point: &Endpoint,
+    ) -> Orientation {
+        let (start, end) = (self.get_start(edge), self.get_end(edge));
+        if start < end {
+            start.orient(end, point)
+        } else {
+            end.orient(start, point)
+        }
+    }
+
+    fn walk(&self, point: &Endpoint) -> Option<MeshLocation> {
+        let start = {
+            let located_edge =
+                self.located_edge.load(atomic::Ordering::Relaxed);
+            if located_edge != UNDEFINED_QUAD_EDGE
+                && !self.is_deleted_edge(located_edge)
+            {
+                located_edge
+            } else {
+                self.iter_edges().next()?
+            }
+        };
+        let mut edge = if self.orient_point_to_edge(start, point)
+            == Orientation::Clockwise
+        {
+            to_opposite_edge(start)
+        } else {
+            start
+        };
+        let mut steps_count = 0usize;
+        loop {
+            steps_count += 1;
+            if steps_count > self.left_from_start.len() {
+                return None;
+            }
+            if self.get_start(edge) == point {
+                return Some(MeshLocation::Vertex(edge));
+            } else if self.get_end(edge) == point {
+                return Some(MeshLocation::Vertex(to_opposite_edge(edge)));
+            }
+            let orientation = self.orient_point_to_edge(edge, point);
+            if !self.is_left_triangular_face(edge) {
+                if orientation != Orientation::Collinear {
+                    return Some(MeshLocation::Exterior(edge));
+                }
+                let (start, end) = (self.get_start(edge), self.get_end(edge));
+                if (start < point) == (point < end) {
+                    return Some(MeshLocation::Edge(edge));
+                }
+                // point lies on the line of the boundary edge,
+                // which may be continued by collinear boundary edges
+                edge = if (start < point) == (start < end) {
+                    self.to_left_from_end(edge)
+                } else {
+                    to_opposite_edge(self.to_left_from_start(edge))
+                };
+                continue;
+            }
+            let next_edge = self.to_left_from_end(edge);
+            let prior_edge = self.to_left_from_end(next_edge);
+            let next_orientation = self.orient_point_to_edge(next_edge, point);
+            let prior_orientation =
+                self.orient_point_to_edge(prior_edge, point);
+            if next_orientation == Orientation::Clockwise
+                && (prior_orientation != Orientation::Clockwise
+                    || steps_count.is_even())
+            {
+                edge = to_opposite_edge(next_edge);
+            } else if prior_orientation == Orientation::Clockwise {
+                edge = to_opposite_edge(prior_edge);
+            } else if self.get_start(prior_edge) == point {
+                return Some(MeshLocation::Vertex(prior_edge));
+            } else if orientation == Orientation::Collinear {
+                return Some(MeshLocation::Edge(edge));
+            } else if next_orientation == Orientation::Collinear {
+                return Some(MeshLocation::Edge(next_edge));
+            } else if prior_orientation == Orientation::Collinear {
+                return Some(MeshLocation::Edge(prior_edge));
+            } else {
+                return Some(MeshLocation::Face(edge));
+            }
+        }
+    }
+}
+
+impl<Endpoint: PartialOrd> Mesh<Endpoint>
+where
+    for<'a> &'a Endpoint: LocatePointInPointPointPointCircle + Orient,
+{
+    /// connects the endpoint with the given index
+    /// to vertices of the triangle (or of the pair of triangles
+    /// sharing the edge) the endpoint lies in
+    /// and restores the Delaunay property
+    pub(super) fn insert_inner_endpoint(
+        &mut self,
+        location: MeshLocation,
+        endpoint_index: usize,
+    ) {
+        self.insert_constrained_inner_endpoint(
+            location,
+            endpoint_index,
+            |_, _| false,
+        );
+    }
+
+    /// connects the endpoint with the given index
+    /// to vertices of the triangle (or of the pair of triangles
+    /// sharing the non-constraint edge) the endpoint lies in
+    /// and restores the Delaunay property keeping constraints,
+    /// returns an edge starting at the endpoint
+    pub(super) fn insert_constrained_inner_endpoint(
+        &mut self,
+        location: MeshLocation,
+        endpoint_index: usize,
+        is_constraint: impl Fn(&Self, QuadEdge) -> bool,
+    ) -> QuadEdge {
+        let mut edge = match location {
+            MeshLocation::Edge(edge) => {
+                debug_assert!(self.is_left_triangular_face(edge));
+                debug_assert!(
+                    self.is_left_triangular_face(to_opposite_edge(edge))
+                );
+                let result = self.to_right_from_start(edge);
+                self.delete_edge(edge);
+                result
+            }
+            MeshLocation::Face(edge) => edge,
+            MeshLocation::Exterior(_) | MeshLocation::Vertex(_) => {
+                unreachable!("Endpoint should lie inside of the mesh.")
+            }
+        };
+        let mut base_edge =
+            self.create_edge(self.to_start_index(edge), endpoint_index);
+        self.splice_edges(base_edge, edge);
+        let start_edge = base_edge;
+        let mut suspicious_edges = Vec::new();
+        loop {
+            suspicious_edges.push(edge);
+            base_edge = self.connect_edges(edge, to_opposite_edge(base_edge));
+            edge = self.to_right_from_start(base_edge);
+            if self.to_left_from_end(edge) == start_edge {
+                suspicious_edges.push(edge);
+                break;
+            }
+        }
+        self.restore_constrained_delaunay_property(
+            suspicious_edges,
+            is_constraint,
+        );
+        // edges incident to the endpoint are never flipped
+        to_opposite_edge(start_edge)
+    }
+
+    /// connects the endpoint with the given index lying outside of the mesh
+    /// to visible boundary vertices and restores the Delaunay property,
+    /// the edge should be a boundary edge with exterior on the left
+    /// and the endpoint not to the right of it
+    pub(super) fn insert_outer_endpoint(
+        &mut self,
+        edge: QuadEdge,
+        endpoint_index: usize,
+    ) {
+        let endpoint = &self.endpoints[endpoint_index];
+        let mut last_edge = if self.orient_point_to_edge(edge, endpoint)
+            == Orientation::Collinear
+            && (self.get_start(edge) < endpoint)
+                == (self.get_start(edge) < self.get_end(edge))
+        {
+            edge
+        } else if self.orient_point_to_edge(edge, endpoint)
+            == Orientation::Collinear
+        {
+            to_opposite_edge(self.to_left_from_start(edge))
+        } else {
+            edge
+        };
+        while self
+            .orient_point_to_edge(self.to_left_from_end(last_edge), endpoint)
+            == Orientation::Counterclockwise
+        {
+            last_edge = self.to_left_from_end(last_edge);
+        }
+        let mut visible_edges = Vec::new();
+        let mut visible_edge = last_edge;
+        while self.orient_point_to_edge(visible_edge, endpoint)
+            == Orientation::Counterclockwise
+        {
+            visible_edges.push(visible_edge);
+            visible_edge =
+                to_opposite_edge(self.to_left_from_start(visible_edge));
+        }
+        let mut base_edge =
+            self.create_edge(self.to_end_index(last_edge), endpoint_index);
+        self.splice_edges(base_edge, self.to_left_from_end(last_edge));
+        for &visible_edge in &visible_edges {
+            base_edge =
+                to_opposite_edge(self.connect_edges(base_edge, visible_edge));
+        }
+        self.restore_delaunay_property(visible_edges);
+    }
+
+    /// removes edges incident to the start of the given edge
+    /// and triangulates the resulting hole keeping the Delaunay property
+    pub(super) fn remove_vertex(&mut self, edge: QuadEdge) {
+        let mut spokes = vec![edge];
+        let mut spoke = self.to_left_from_start(edge);
+        while spoke != edge {
+            spokes.push(spoke);
+            spoke = self.to_left_from_start(spoke);
+        }
+        let exterior_spoke_position = spokes.iter().position(|&spoke| {
+            self.orient_point_to_edge(
+                spoke,
+                self.get_end(self.to_left_from_start(spoke)),
+            ) != Orientation::Counterclockwise
+        });
+        let is_boundary_vertex = exterior_spoke_position.is_some();
+        if let Some(position) = exterior_spoke_position {
+            spokes.rotate_left(position + 1);
+        }
+        let mut polygon_edges = spokes
+            [..spokes.len() - usize::from(is_boundary_vertex)]
+            .iter()
+            .map(|&spoke| self.to_left_from_end(spoke))
+            .collect::<Vec<_>>();
+        for spoke in spokes {
+            self.delete_edge(spoke);
+        }
+        let mut new_edges = Vec::new();
+        let min_polygon_edges_count = if is_boundary_vertex { 1 } else { 3 };
+        while polygon_edges.len() > min_polygon_edges_count {
+            let ears_candidates_count = if is_boundary_vertex {
+                polygon_edges.len() - 1
+            } else {
+                polygon_edges.len()
+            };
+            let Some(position) =
+                (0..ears_candidates_count).find(|&position| {
+                    self.is_polygon_ear(&polygon_edges, position)
+                })
+            else {
+                break;
+            };
+            let next_position = (position + 1) % polygon_edges.len();
+            let new_edge = self.connect_edges(
+                polygon_edges[next_position],
+                polygon_edges[position],
+            );
+            new_edges.push(new_edge);
+            polygon_edges[position] = to_opposite_edge(new_edge);
+            polygon_edges.remove(next_position);
+        }
+        debug_assert!(is_boundary_vertex || polygon_edges.len() == 3);
+        self.restore_delaunay_property(new_edges);
+    }
+
+    fn is_polygon_ear(
+        &self,
+        polygon_edges: &[QuadEdge],
+        position: usize,
+    ) -> bool {
+        let edge = polygon_edges[position];
+        let next_edge = polygon_edges[(position + 1) % polygon_edges.len()];
+        let (first, second, third) = (
+            self.get_start(edge),
+            self.get_end(edge),
+            self.get_end(next_edge),
+        );
+        self.orient_point_to_edge(edge, third) == Orientation::Counterclockwise
+            && polygon_edges
+                .iter()
+                .map(|&polygon_edge| self.get_end(polygon_edge))
+                .chain([self.get_start(polygon_edges[0])])
+                .all(|vertex| {
+                    vertex == first
+                        || vertex == second
+                        || vertex == third
+                        || !is_point_in_triangle(vertex, first, second, third)
+                })
+    }
+
+    fn restore_delaunay_property(&mut self, edges: Vec<QuadEdge>) {
+        self.restore_constrained_delaunay_property(edges, |_, _| false);
+    }
+
+    /// flips non-Delaunay edges except constraint ones
+    pub(super) fn restore_constrained_delaunay_property(
+        &mut self,
+        mut edges: Vec<QuadEdge>,
+        is_constraint: impl Fn(&Self, QuadEdge) -> bool,
+    ) {
+        while let Some(edge) = edges.pop() {
+            if !is_constraint(self, edge) && self.is_non_delaunay_edge(edge) {
+                self.swap_diagonal(edge);
+                let opposite_edge = to_opposite_edge(edge);
+                edges.push(self.to_left_from_end(edge));
+                edges.push(self.to_left_from_end(self.to_left_from_end(edge)));
+                edges.push(self.to_left_from_end(opposite_edge));
+                edges.push(
+                    self.to_left_from_end(
+                        self.to_left_from_end(opposite_edge),
+                    ),
+                );
+            }
+        }
+    }
+
+    fn is_non_delaunay_edge(&self, edge: QuadEdge) -> bool {
+        let left_vertex = self.get_end(self.to_left_from_start(edge));
+        let right_vertex = self.get_end(self.to_right_from_start(edge));
+        self.orient_point_to_edge(edge, left_vertex)
+            == Orientation::Counterclockwise
+            && self.orient_point_to_edge(edge, right_vertex)
+                == Orientation::Clockwise
+            && right_vertex.locate_point_in_point_point_point_circle(
+                self.get_start(edge),
+                self.get_end(edge),
+                left_vertex,
+            ) == Location::Interior
+    }
+}
+
 impl<Endpoint: Clone + Ord> DelaunayTriangulatable for Mesh<Endpoint>
 where
     for<'a> &'a Endpoint: LocatePointInPointPointPointCircle + Orient,
@@ -495,3 +1302,17 @@ fn to_base_cases(points_count: usize) -> (usize, usize) {
         (1, triangles_count)
     }
 }
+
+fn is_point_in_triangle<Point>(
+    point: &Point,
+    first: &Point,
+    second: &Point,
+    third: &Point,
+) -> bool
+where
+    for<'a> &'a Point: Orient,
+{
+    first.orient(second, point) != Orientation::Clockwise
+        && second.orient(third, point) != Orientation::Clockwise
+        && third.orient(first, point) != Orientation::Clockwise
+}
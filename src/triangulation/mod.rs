@@ -1,10 +1,18 @@
-pub(crate) use self::constrained_delaunay::ConstrainedDelaunayTriangulation;
-pub(crate) use self::delaunay::DelaunayTriangulation;
+pub use self::constrained_delaunay::ConstrainedDelaunayTriangulation;
+pub use self::delaunay::DelaunayTriangulation;
+pub use self::locatable::TriangleLocation;
 pub(crate) use self::operations::BoundaryEndpoints;
 pub(crate) use self::quad_edge::QuadEdge;
+pub use self::refinement::{RefinementBuilder, RefinementError};
+pub use self::voronoi::VoronoiDiagram;
 
+mod alpha_shape;
 mod constrained_delaunay;
 mod delaunay;
+mod locatable;
 mod mesh;
 mod operations;
+mod proximity_graphs;
 mod quad_edge;
+mod refinement;
+mod voronoi;
@@ -1,3 +1,7 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::geometries::Point;
+use crate::traits::Elemental;
 use crate::triangulation::QuadEdge;
 
 pub(crate) trait BoundaryEndpoints<Endpoint> {
@@ -7,3 +11,37 @@ pub(crate) trait BoundaryEndpoints<Endpoint> {
 pub(super) trait DelaunayTriangulatable {
     fn delaunay_triangulation(&mut self) -> (QuadEdge, QuadEdge);
 }
+
+pub(super) fn to_circumcenter<Scalar>(
+    first: &Point<Scalar>,
+    second: &Point<Scalar>,
+    third: &Point<Scalar>,
+) -> Point<Scalar>
+where
+    Scalar: Clone
+        + Add<Output = Scalar>
+        + Div<Output = Scalar>
+        + Mul<Output = Scalar>
+        + Sub<Output = Scalar>,
+    for<'a> &'a Scalar:
+        Add<Output = Scalar> + Mul<Output = Scalar> + Sub<Output = Scalar>,
+{
+    let (first_x, first_y) = first.coordinates();
+    let (second_dx, second_dy) = (second.x() - first_x, second.y() - first_y);
+    let (third_dx, third_dy) = (third.x() - first_x, third.y() - first_y);
+    let second_squared_norm =
+        &second_dx * &second_dx + &second_dy * &second_dy;
+    let third_squared_norm = &third_dx * &third_dx + &third_dy * &third_dy;
+    let cross_product = &second_dx * &third_dy - &second_dy * &third_dx;
+    let denominator = &cross_product + &cross_product;
+    Point::new(
+        first_x
+            + &((&third_dy * &second_squared_norm
+                - &second_dy * &third_squared_norm)
+                / denominator.clone()),
+        first_y
+            + &((second_dx * third_squared_norm
+                - third_dx * second_squared_norm)
+                / denominator),
+    )
+}
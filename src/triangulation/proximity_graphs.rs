@@ -0,0 +1,174 @@
+use std::collections::BTreeSet;
+
+use traiter::numbers::Zero;
+
+use crate::geometries::{ConstructionError, Multisegment, Point, Segment};
+use crate::operations::{
+    to_union_find_root, DotMultiply, Orient, SquaredMetric,
+};
+
+use super::delaunay::DelaunayTriangulation;
+use super::quad_edge::{to_opposite_edge, QuadEdge};
+
+impl<Scalar> DelaunayTriangulation<Point<Scalar>>
+where
+    Point<Scalar>: Clone + PartialOrd,
+    Scalar: Ord + Zero,
+    for<'a> &'a Point<Scalar>:
+        DotMultiply<Output = Scalar> + Orient + SquaredMetric<Output = Scalar>,
+{
+    /// sorted pairs of sorted vertices indices of edges
+    /// of the Euclidean minimum spanning tree,
+    /// edges of equal lengths are picked in the order of their indices
+    pub fn to_minimum_spanning_tree_edges_indices(&self) -> Vec<[usize; 2]> {
+        let vertices = self.get_vertices();
+        let mut candidates = self
+            .to_relative_neighbourhood_graph_edges_indices()
+            .into_iter()
+            .map(|[start, end]| {
+                (
+                    vertices[start].squared_distance_to(&vertices[end]),
+                    [start, end],
+                )
+            })
+            .collect::<Vec<_>>();
+        candidates.sort();
+        let mut parents = (0..vertices.len()).collect::<Vec<_>>();
+        let mut result = Vec::with_capacity(vertices.len().saturating_sub(1));
+        for (_, [start, end]) in candidates {
+            let start_root = to_union_find_root(&mut parents, start);
+            let end_root = to_union_find_root(&mut parents, end);
+            if start_root != end_root {
+                parents[start_root] = end_root;
+                result.push([start, end]);
+            }
+        }
+        result.sort_unstable();
+        result
+    }
+
+    /// sorted pairs of sorted vertices indices of edges
+    /// which diametral closed disks contain no other vertices
+    pub fn to_gabriel_graph_edges_indices(&self) -> Vec<[usize; 2]> {
+        let mesh = self.get_mesh();
+        let mut result = mesh
+            .iter_unique_edges()
+            .filter(|&edge| self.is_gabriel_edge(edge))
+            .map(|edge| mesh.to_edge_indices(edge))
+            .collect::<Vec<_>>();
+        result.sort_unstable();
+        result
+    }
+
+    /// sorted pairs of sorted vertices indices of edges
+    /// which have no vertices strictly closer to both of their endpoints
+    /// than the endpoints are to each other
+    pub fn to_relative_neighbourhood_graph_edges_indices(
+        &self,
+    ) -> Vec<[usize; 2]> {
+        let mesh = self.get_mesh();
+        let mut result = mesh
+            .iter_unique_edges()
+            .filter(|&edge| {
+                self.is_gabriel_edge(edge)
+                    && self.is_relative_neighbourhood_edge(edge)
+            })
+            .map(|edge| mesh.to_edge_indices(edge))
+            .collect::<Vec<_>>();
+        result.sort_unstable();
+        result
+    }
+
+    /// Euclidean minimum spanning tree of the vertices,
+    /// fails for less than three distinct points
+    pub fn to_minimum_spanning_tree(
+        &self,
+    ) -> Result<Multisegment<Scalar>, ConstructionError> {
+        self.to_multisegment(&self.to_minimum_spanning_tree_edges_indices())
+    }
+
+    /// Gabriel graph of the vertices,
+    /// fails if it has less than two edges
+    pub fn to_gabriel_graph(
+        &self,
+    ) -> Result<Multisegment<Scalar>, ConstructionError> {
+        self.to_multisegment(&self.to_gabriel_graph_edges_indices())
+    }
+
+    /// relative neighbourhood graph of the vertices,
+    /// fails if it has less than two edges
+    pub fn to_relative_neighbourhood_graph(
+        &self,
+    ) -> Result<Multisegment<Scalar>, ConstructionError> {
+        self.to_multisegment(
+            &self.to_relative_neighbourhood_graph_edges_indices(),
+        )
+    }
+
+    /// checks if opposite vertices of adjacent triangles
+    /// lie outside of the edge's diametral closed disk,
+    /// Delaunay property guarantees that the rest of vertices do as well
+    fn is_gabriel_edge(&self, edge: QuadEdge) -> bool {
+        let mesh = self.get_mesh();
+        [edge, to_opposite_edge(edge)].iter().all(|&side| {
+            !mesh.is_left_triangular_face(side) || {
+                let apex = mesh.get_end(mesh.to_left_from_end(side));
+                DotMultiply::dot_multiply(
+                    apex,
+                    mesh.get_start(side),
+                    apex,
+                    mesh.get_end(side),
+                ) > Scalar::zero()
+            }
+        })
+    }
+
+    /// checks if the edge's lune has no vertices
+    /// searching through vertices strictly closer to the edge's start
+    /// than its end, which are connected by the triangulation
+    /// since each vertex has a neighbour closer to the start
+    fn is_relative_neighbourhood_edge(&self, edge: QuadEdge) -> bool {
+        let mesh = self.get_mesh();
+        let (start, end) = (mesh.get_start(edge), mesh.get_end(edge));
+        let squared_length = start.squared_distance_to(end);
+        let mut visited_indices = BTreeSet::from([mesh.to_start_index(edge)]);
+        let mut queue = vec![edge];
+        while let Some(vertex_edge) = queue.pop() {
+            let mut candidate = vertex_edge;
+            loop {
+                let vertex = mesh.get_end(candidate);
+                if start.squared_distance_to(vertex) < squared_length
+                    && visited_indices.insert(mesh.to_end_index(candidate))
+                {
+                    if end.squared_distance_to(vertex) < squared_length {
+                        return false;
+                    }
+                    queue.push(to_opposite_edge(candidate));
+                }
+                candidate = mesh.to_left_from_start(candidate);
+                if candidate == vertex_edge {
+                    break;
+                }
+            }
+        }
+        true
+    }
+
+    fn to_multisegment(
+        &self,
+        edges_indices: &[[usize; 2]],
+    ) -> Result<Multisegment<Scalar>, ConstructionError> {
+        let vertices = self.get_vertices();
+        Multisegment::try_new(
+            edges_indices
+                .iter()
+                .map(|&[start, end]| {
+                    Segment::new(
+                        vertices[start].clone(),
+                        vertices[end].clone(),
+                    )
+                })
+                .collect(),
+        )
+    }
+}
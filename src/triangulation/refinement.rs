@@ -0,0 +1,775 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::fmt;
+use std::ops::{Add, Div, Mul, Sub};
+
+use traiter::numbers::{One, Zero};
+
+use crate::geometries::{Multipolygon, Point, Polygon, Segment};
+use crate::operations::{
+    halve, to_sorted_pair, vertices_to_centroid, CrossMultiply, DotMultiply,
+    LocatePointInPointPointPointCircle, Orient, SquaredMetric,
+};
+use crate::traits::{
+    Elemental, Multipolygonal, Multisegmental, Polygonal, Segmental,
+};
+
+use super::constrained_delaunay::ConstrainedDelaunayTriangulation;
+use super::mesh::{Mesh, MeshLocation};
+use super::operations::to_circumcenter;
+use super::quad_edge::{to_opposite_edge, QuadEdge};
+
+/// builds constrained Delaunay triangulations refined in Ruppert's fashion:
+/// encroached boundary segments are split at their midpoints
+/// and circumcenters of bad triangles are inserted
+/// until every triangle satisfies the minimum angle bound
+/// and the optional maximum area bound,
+/// all Steiner points are exact.
+///
+/// ```
+/// use rene::geometries::{Contour, Point, Polygon};
+/// use rene::triangulation::{RefinementBuilder, RefinementError};
+/// use rithm::big_int::BigInt;
+/// use rithm::fraction::Fraction;
+///
+/// type Scalar = Fraction<BigInt<u32, 31>>;
+///
+/// let to_scalar = |value: i32| Scalar::from(BigInt::from(value));
+/// let to_point = |x: i32, y: i32| Point::new(to_scalar(x), to_scalar(y));
+/// let polygon = Polygon::new(
+///     Contour::new(vec![
+///         to_point(0, 0),
+///         to_point(8, 0),
+///         to_point(8, 1),
+///         to_point(0, 1),
+///     ]),
+///     vec![],
+/// );
+/// // angles are at least about 20.7 degrees
+/// let builder = RefinementBuilder::new(to_scalar(7) / to_scalar(8)).unwrap();
+/// let triangulation = builder.build_from_polygon(&polygon);
+/// let vertices = triangulation.get_vertices();
+/// assert!(vertices.len() > 4);
+/// assert!(triangulation
+///     .to_triangles_indices()
+///     .all(|indices| indices.iter().all(|&index| index < vertices.len())));
+/// assert!(triangulation.to_triangles().len() > 2);
+/// assert_eq!(
+///     RefinementBuilder::new(to_scalar(1) / to_scalar(4)).err(),
+///     Some(RefinementError::MaxSquaredCosineOutOfRange)
+/// );
+/// // refinement with larger angles may never stop
+/// assert_eq!(
+///     RefinementBuilder::new(to_scalar(1) / to_scalar(2)).err(),
+///     Some(RefinementError::UnboundedSteinerPointsCount)
+/// );
+/// assert!(RefinementBuilder::new_with_max_steiner_points_count(
+///     to_scalar(1) / to_scalar(2),
+///     100
+/// )
+/// .is_ok());
+/// assert_eq!(
+///     builder.with_max_area(to_scalar(0)).err(),
+///     Some(RefinementError::NonPositiveMaxArea)
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct RefinementBuilder<Scalar> {
+    max_area: Option<Scalar>,
+    max_squared_cosine: Scalar,
+    max_steiner_points_count: Option<usize>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RefinementError {
+    /// squared cosine of the minimum angle lies out of `(1/4, 1]` range
+    MaxSquaredCosineOutOfRange,
+    /// maximum area of triangles is not positive
+    NonPositiveMaxArea,
+    /// squared cosine of the minimum angle lies in `(1/4, 7/8)` range,
+    /// for which the refinement may never stop,
+    /// but the number of Steiner points is not bounded
+    UnboundedSteinerPointsCount,
+}
+
+impl fmt::Display for RefinementError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MaxSquaredCosineOutOfRange => formatter.write_str(
+                "Squared cosine of the minimum angle should lie \
+                 in (1/4, 1] range.",
+            ),
+            Self::NonPositiveMaxArea => {
+                formatter.write_str("Maximum area should be positive.")
+            }
+            Self::UnboundedSteinerPointsCount => formatter.write_str(
+                "Squared cosine of the minimum angle should not be \
+                 less than 7/8 unless the number of Steiner points \
+                 is bounded.",
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RefinementError {}
+
+impl<Scalar: One + PartialOrd + Zero> RefinementBuilder<Scalar>
+where
+    for<'a> &'a Scalar: Add<Output = Scalar>,
+{
+    /// creates builder with the minimum angle bound
+    /// given by the squared cosine of the angle
+    /// lying in `[7/8, 1]` range (so the angle is up to about 20.7 degrees),
+    /// for which the refinement is guaranteed to stop
+    pub fn new(max_squared_cosine: Scalar) -> Result<Self, RefinementError> {
+        let result =
+            Self::new_with_unbounded_steiner_points_count(max_squared_cosine)?;
+        let doubled_max_squared_cosine =
+            &result.max_squared_cosine + &result.max_squared_cosine;
+        let quadrupled_max_squared_cosine =
+            &doubled_max_squared_cosine + &doubled_max_squared_cosine;
+        let one = Scalar::one();
+        let two = &one + &one;
+        let four = &two + &two;
+        if &(&quadrupled_max_squared_cosine + &quadrupled_max_squared_cosine)
+            + &one
+            < &four + &four
+        {
+            Err(RefinementError::UnboundedSteinerPointsCount)
+        } else {
+            Ok(result)
+        }
+    }
+
+    /// creates builder with the minimum angle bound
+    /// given by the squared cosine of the angle
+    /// lying in `(1/4, 1]` range (so the angle is less than 60 degrees)
+    /// and the maximum number of Steiner points,
+    /// which stops the refinement for squared cosines less than 7/8
+    pub fn new_with_max_steiner_points_count(
+        max_squared_cosine: Scalar,
+        max_steiner_points_count: usize,
+    ) -> Result<Self, RefinementError> {
+        Self::new_with_unbounded_steiner_points_count(max_squared_cosine).map(
+            |builder| {
+                builder.with_max_steiner_points_count(max_steiner_points_count)
+            },
+        )
+    }
+
+    /// sets the positive maximum area of triangles
+    pub fn with_max_area(
+        self,
+        max_area: Scalar,
+    ) -> Result<Self, RefinementError> {
+        if max_area > Scalar::zero() {
+            Ok(Self {
+                max_area: Some(max_area),
+                ..self
+            })
+        } else {
+            Err(RefinementError::NonPositiveMaxArea)
+        }
+    }
+
+    fn new_with_unbounded_steiner_points_count(
+        max_squared_cosine: Scalar,
+    ) -> Result<Self, RefinementError> {
+        let doubled_max_squared_cosine =
+            &max_squared_cosine + &max_squared_cosine;
+        if &doubled_max_squared_cosine + &doubled_max_squared_cosine
+            > Scalar::one()
+            && max_squared_cosine <= Scalar::one()
+        {
+            Ok(Self {
+                max_area: None,
+                max_squared_cosine,
+                max_steiner_points_count: None,
+            })
+        } else {
+            Err(RefinementError::MaxSquaredCosineOutOfRange)
+        }
+    }
+}
+
+impl<Scalar> RefinementBuilder<Scalar> {
+    /// sets the maximum number of Steiner points,
+    /// after inserting them the refinement stops
+    /// even if some triangles still violate the bounds
+    pub fn with_max_steiner_points_count(self, count: usize) -> Self {
+        Self {
+            max_steiner_points_count: Some(count),
+            ..self
+        }
+    }
+
+    /// maximum area of triangles if any
+    pub fn max_area(&self) -> Option<&Scalar> {
+        self.max_area.as_ref()
+    }
+
+    /// squared cosine of the minimum angle of triangles
+    pub fn max_squared_cosine(&self) -> &Scalar {
+        &self.max_squared_cosine
+    }
+
+    /// maximum number of Steiner points if any
+    pub fn max_steiner_points_count(&self) -> Option<usize> {
+        self.max_steiner_points_count
+    }
+}
+
+impl<Scalar> RefinementBuilder<Scalar>
+where
+    Point<Scalar>: Clone + Ord,
+    Scalar: Clone
+        + PartialOrd
+        + One
+        + Zero
+        + Add<Output = Scalar>
+        + for<'a> Add<&'a Scalar, Output = Scalar>
+        + Div<Output = Scalar>
+        + for<'a> Div<&'a Scalar, Output = Scalar>
+        + Mul<Output = Scalar>
+        + Sub<Output = Scalar>,
+    for<'a> &'a Point<Scalar>: CrossMultiply<Output = Scalar>
+        + DotMultiply<Output = Scalar>
+        + Elemental<Coordinate = &'a Scalar>
+        + LocatePointInPointPointPointCircle
+        + Orient
+        + SquaredMetric<Output = Scalar>,
+    for<'a> &'a Scalar:
+        Add<Output = Scalar> + Mul<Output = Scalar> + Sub<Output = Scalar>,
+    for<'a> &'a Segment<Scalar>: Segmental<Endpoint = &'a Point<Scalar>>,
+{
+    /// refined triangulation of the polygon
+    pub fn build_from_polygon(
+        &self,
+        polygon: &Polygon<Scalar>,
+    ) -> ConstrainedDelaunayTriangulation<Point<Scalar>> {
+        let mut segments = polygon
+            .border()
+            .segments()
+            .into_iter()
+            .cloned()
+            .collect::<Vec<_>>();
+        for hole in polygon.holes() {
+            segments.extend(hole.segments().into_iter().cloned());
+        }
+        self.refine(ConstrainedDelaunayTriangulation::from(polygon), &segments)
+    }
+
+    /// refined triangulation of the multipolygon,
+    /// polygons may touch each other at vertices
+    pub fn build_from_multipolygon(
+        &self,
+        multipolygon: &Multipolygon<Scalar>,
+    ) -> ConstrainedDelaunayTriangulation<Point<Scalar>> {
+        let mut segments = Vec::new();
+        for polygon in multipolygon.polygons() {
+            segments.extend(polygon.border().segments().into_iter().cloned());
+            for hole in polygon.holes() {
+                segments.extend(hole.segments().into_iter().cloned());
+            }
+        }
+        self.refine(
+            ConstrainedDelaunayTriangulation::from(multipolygon),
+            &segments,
+        )
+    }
+
+    /// inserts Steiner points in place processing worklists
+    /// of encroached subsegments (which go first) and of bad triangles,
+    /// which get updated only around inserted points
+    fn refine(
+        &self,
+        mut triangulation: ConstrainedDelaunayTriangulation<Point<Scalar>>,
+        segments: &[Segment<Scalar>],
+    ) -> ConstrainedDelaunayTriangulation<Point<Scalar>> {
+        let mut worklists = Worklists::new(&triangulation, segments);
+        for edge in triangulation.to_triangles_base_edges().collect::<Vec<_>>()
+        {
+            self.enqueue_bad_triangle(&triangulation, edge, &mut worklists);
+        }
+        let mut steiner_points_count = 0usize;
+        while Some(steiner_points_count) != self.max_steiner_points_count {
+            let vertex_edge = if let Some(subsegment) =
+                worklists.encroached_subsegments.pop_first()
+            {
+                match worklists.subsegments.get(&subsegment) {
+                    Some(&(edge, segment_index)) => worklists
+                        .split_subsegment(
+                            &mut triangulation,
+                            subsegment,
+                            edge,
+                            segment_index,
+                        ),
+                    None => continue,
+                }
+            } else if let Some((edge, vertices_indices)) =
+                worklists.bad_triangles.pop_front()
+            {
+                match self.split_bad_triangle(
+                    &mut triangulation,
+                    edge,
+                    vertices_indices,
+                    &mut worklists,
+                ) {
+                    Some(vertex_edge) => vertex_edge,
+                    None => continue,
+                }
+            } else {
+                break;
+            };
+            steiner_points_count += 1;
+            let mesh = triangulation.get_mesh();
+            let mut edge = vertex_edge;
+            loop {
+                for candidate in [edge, mesh.to_left_from_end(edge)] {
+                    worklists.enqueue_encroached_subsegment(
+                        &triangulation,
+                        candidate,
+                    );
+                }
+                self.enqueue_bad_triangle(
+                    &triangulation,
+                    edge,
+                    &mut worklists,
+                );
+                edge = mesh.to_left_from_start(edge);
+                if edge == vertex_edge {
+                    break;
+                }
+            }
+        }
+        triangulation.sort_vertices();
+        triangulation
+    }
+
+    /// inserts the circumcenter of the triangle on the left of the edge
+    /// unless it is no longer the triangle with given vertices,
+    /// the circumcenter is not visible from the triangle
+    /// or encroaches subsegments (which get enqueued along with the triangle),
+    /// returns an edge starting at the inserted point
+    fn split_bad_triangle(
+        &self,
+        triangulation: &mut ConstrainedDelaunayTriangulation<Point<Scalar>>,
+        edge: QuadEdge,
+        vertices_indices: [usize; 3],
+        worklists: &mut Worklists<Scalar>,
+    ) -> Option<QuadEdge> {
+        let mesh = triangulation.get_mesh();
+        if mesh.is_deleted_edge(edge)
+            || !triangulation.is_triangle(edge)
+            || mesh.triangle_base_to_indices(edge) != vertices_indices
+        {
+            return None;
+        }
+        let (first, second, third) = mesh.triangle_base_to_vertices(edge);
+        let circumcenter = to_circumcenter(first, second, third);
+        let centroid =
+            vertices_to_centroid([first, second, third].iter().copied());
+        let blocking_edges = match triangulation.walk_to_point(
+            edge,
+            &centroid,
+            &circumcenter,
+        ) {
+            Ok(MeshLocation::Edge(location_edge))
+                if triangulation.is_constraint_edge(location_edge) =>
+            {
+                vec![location_edge]
+            }
+            Ok(location @ (MeshLocation::Edge(_) | MeshLocation::Face(_))) => {
+                let cavity_edges = triangulation
+                    .to_cavity_constraint_edges(location, &circumcenter);
+                if !cavity_edges.iter().any(|&cavity_edge| {
+                    is_segment_encroached(
+                        mesh.get_start(cavity_edge),
+                        mesh.get_end(cavity_edge),
+                        &circumcenter,
+                    )
+                }) {
+                    return Some(
+                        triangulation
+                            .insert_steiner_point(location, circumcenter),
+                    );
+                }
+                cavity_edges
+            }
+            Ok(MeshLocation::Exterior(_) | MeshLocation::Vertex(_)) => {
+                return None;
+            }
+            Err(blocking_edges) => blocking_edges,
+        };
+        let mut is_encroaching = false;
+        for blocking_edge in blocking_edges {
+            if is_segment_encroached(
+                mesh.get_start(blocking_edge),
+                mesh.get_end(blocking_edge),
+                &circumcenter,
+            ) {
+                worklists
+                    .encroached_subsegments
+                    .insert(to_edge_key(mesh, blocking_edge));
+                is_encroaching = true;
+            }
+        }
+        if is_encroaching {
+            // the triangle may survive splits of subsegments
+            worklists.bad_triangles.push_back((edge, vertices_indices));
+        }
+        None
+    }
+
+    fn enqueue_bad_triangle(
+        &self,
+        triangulation: &ConstrainedDelaunayTriangulation<Point<Scalar>>,
+        edge: QuadEdge,
+        worklists: &mut Worklists<Scalar>,
+    ) {
+        if !triangulation.is_triangle(edge) {
+            return;
+        }
+        let mesh = triangulation.get_mesh();
+        let vertices_indices = mesh.triangle_base_to_indices(edge);
+        if self.is_bad_triangle(
+            vertices_indices,
+            mesh.get_endpoints(),
+            worklists,
+        ) {
+            worklists.bad_triangles.push_back((edge, vertices_indices));
+        }
+    }
+
+    fn is_bad_triangle(
+        &self,
+        vertices_indices: [usize; 3],
+        vertices: &[Point<Scalar>],
+        worklists: &Worklists<Scalar>,
+    ) -> bool {
+        let [first, second, third] = vertices_indices;
+        // the smallest angle is opposite to the shortest side
+        let (apex, start, end) = {
+            let first_squared_length =
+                vertices[second].squared_distance_to(&vertices[third]);
+            let second_squared_length =
+                vertices[third].squared_distance_to(&vertices[first]);
+            let third_squared_length =
+                vertices[first].squared_distance_to(&vertices[second]);
+            if first_squared_length <= second_squared_length
+                && first_squared_length <= third_squared_length
+            {
+                (first, second, third)
+            } else if second_squared_length <= third_squared_length {
+                (second, third, first)
+            } else {
+                (third, first, second)
+            }
+        };
+        let (apex_point, start_point, end_point) =
+            (&vertices[apex], &vertices[start], &vertices[end]);
+        if let Some(max_area) = &self.max_area {
+            let doubled_area = CrossMultiply::cross_multiply(
+                apex_point,
+                start_point,
+                apex_point,
+                end_point,
+            );
+            if doubled_area > max_area + max_area {
+                return true;
+            }
+        }
+        self.is_small_angle(apex_point, start_point, end_point)
+            && !self.is_cut_off_from_small_input_angle(
+                start, end, vertices, worklists,
+            )
+    }
+
+    /// checks if the vertices lie on distinct segments
+    /// which form a small angle at their common endpoint
+    /// (not being that endpoint), such angles cannot be improved
+    /// and splitting triangles cut off from them would never stop
+    fn is_cut_off_from_small_input_angle(
+        &self,
+        start: usize,
+        end: usize,
+        vertices: &[Point<Scalar>],
+        worklists: &Worklists<Scalar>,
+    ) -> bool {
+        let (start_point, end_point) = (&vertices[start], &vertices[end]);
+        worklists.to_vertex_segments_indices(start).iter().any(
+            |&start_segment_index| {
+                worklists.to_vertex_segments_indices(end).iter().any(
+                    |&end_segment_index| {
+                        start_segment_index != end_segment_index && {
+                            let (first_start, first_end) = (&worklists
+                                .segments[start_segment_index])
+                                .endpoints();
+                            let (second_start, second_end) = (&worklists
+                                .segments[end_segment_index])
+                                .endpoints();
+                            [
+                                (first_start, first_end),
+                                (first_end, first_start),
+                            ]
+                            .iter()
+                            .any(
+                                |&(origin, first_other)| {
+                                    origin != start_point
+                                        && origin != end_point
+                                        && [
+                                            (second_start, second_end),
+                                            (second_end, second_start),
+                                        ]
+                                        .iter()
+                                        .any(|&(second_origin, second_other)| {
+                                            origin == second_origin
+                                                && self.is_small_angle(
+                                                    origin,
+                                                    first_other,
+                                                    second_other,
+                                                )
+                                        })
+                                },
+                            )
+                        }
+                    },
+                )
+            },
+        )
+    }
+
+    /// checks if the angle at the vertex violates the minimum angle bound
+    fn is_small_angle(
+        &self,
+        vertex: &Point<Scalar>,
+        first_ray_point: &Point<Scalar>,
+        second_ray_point: &Point<Scalar>,
+    ) -> bool {
+        let dot_product = DotMultiply::dot_multiply(
+            vertex,
+            first_ray_point,
+            vertex,
+            second_ray_point,
+        );
+        dot_product > Scalar::zero()
+            && &dot_product * &dot_product
+                > &self.max_squared_cosine
+                    * &(vertex.squared_distance_to(first_ray_point)
+                        * vertex.squared_distance_to(second_ray_point))
+    }
+}
+
+/// pending work of the refinement
+struct Worklists<'a, Scalar> {
+    bad_triangles: VecDeque<(QuadEdge, [usize; 3])>,
+    encroached_subsegments: BTreeSet<(usize, usize)>,
+    segments: &'a [Segment<Scalar>],
+    segments_steiner_points: BTreeMap<usize, usize>,
+    subsegments: BTreeMap<(usize, usize), (QuadEdge, usize)>,
+    vertices_segments_indices: BTreeMap<usize, Vec<usize>>,
+}
+
+impl<'a, Scalar> Worklists<'a, Scalar>
+where
+    Point<Scalar>: Clone + Ord,
+    Scalar:
+        PartialOrd + One + Zero + Add<Output = Scalar> + Div<Output = Scalar>,
+    for<'b> &'b Point<Scalar>: DotMultiply<Output = Scalar>
+        + LocatePointInPointPointPointCircle
+        + Orient,
+    for<'b> &'b Scalar: Add<Output = Scalar>,
+    for<'b> &'b Segment<Scalar>: Segmental<Endpoint = &'b Point<Scalar>>,
+{
+    fn new(
+        triangulation: &ConstrainedDelaunayTriangulation<Point<Scalar>>,
+        segments: &'a [Segment<Scalar>],
+    ) -> Self {
+        let mesh = triangulation.get_mesh();
+        let vertices = mesh.get_endpoints();
+        let to_vertex_index = |point: &Point<Scalar>| unsafe {
+            vertices.binary_search(point).unwrap_unchecked()
+        };
+        let segments_indices = segments
+            .iter()
+            .enumerate()
+            .map(|(segment_index, segment)| {
+                let (start, end) = segment.endpoints();
+                (
+                    to_sorted_pair((
+                        to_vertex_index(start),
+                        to_vertex_index(end),
+                    )),
+                    segment_index,
+                )
+            })
+            .collect::<BTreeMap<_, _>>();
+        let mut vertices_segments_indices =
+            BTreeMap::<usize, Vec<usize>>::new();
+        for (&(start_index, end_index), &segment_index) in &segments_indices {
+            for vertex_index in [start_index, end_index] {
+                vertices_segments_indices
+                    .entry(vertex_index)
+                    .or_default()
+                    .push(segment_index);
+            }
+        }
+        let subsegments = mesh
+            .iter_unique_edges()
+            .filter_map(|edge| {
+                let key = to_edge_key(mesh, edge);
+                segments_indices
+                    .get(&key)
+                    .map(|&segment_index| (key, (edge, segment_index)))
+            })
+            .collect::<BTreeMap<_, _>>();
+        let mut result = Self {
+            bad_triangles: VecDeque::new(),
+            encroached_subsegments: BTreeSet::new(),
+            segments,
+            segments_steiner_points: BTreeMap::new(),
+            subsegments,
+            vertices_segments_indices,
+        };
+        for edge in result
+            .subsegments
+            .values()
+            .map(|&(edge, _)| edge)
+            .collect::<Vec<_>>()
+        {
+            result.enqueue_encroached_subsegment(triangulation, edge);
+        }
+        result
+    }
+
+    /// enqueues the edge if it is a subsegment
+    /// encroached by an apex of adjacent triangles
+    fn enqueue_encroached_subsegment(
+        &mut self,
+        triangulation: &ConstrainedDelaunayTriangulation<Point<Scalar>>,
+        edge: QuadEdge,
+    ) {
+        let mesh = triangulation.get_mesh();
+        let key = to_edge_key(mesh, edge);
+        let segment_index = match self.subsegments.get(&key) {
+            Some(&(_, segment_index)) => segment_index,
+            None => return,
+        };
+        let (start, end) = (mesh.get_start(edge), mesh.get_end(edge));
+        if [edge, to_opposite_edge(edge)].iter().any(|&side| {
+            triangulation.is_triangle(side) && {
+                let apex_index =
+                    mesh.to_end_index(mesh.to_left_from_end(side));
+                is_segment_encroached(
+                    start,
+                    end,
+                    &mesh.get_endpoints()[apex_index],
+                ) && !is_encroachment_negligible(
+                    apex_index,
+                    segment_index,
+                    self.segments,
+                    &self.segments_steiner_points,
+                )
+            }
+        }) {
+            self.encroached_subsegments.insert(key);
+        }
+    }
+
+    /// returns indices of segments the vertex lies on
+    fn to_vertex_segments_indices(&self, vertex_index: usize) -> &[usize] {
+        match self.segments_steiner_points.get(&vertex_index) {
+            Some(segment_index) => std::slice::from_ref(segment_index),
+            None => self
+                .vertices_segments_indices
+                .get(&vertex_index)
+                .map_or(&[], Vec::as_slice),
+        }
+    }
+
+    /// splits the subsegment at its midpoint,
+    /// returns an edge starting at the midpoint
+    fn split_subsegment(
+        &mut self,
+        triangulation: &mut ConstrainedDelaunayTriangulation<Point<Scalar>>,
+        subsegment: (usize, usize),
+        edge: QuadEdge,
+        segment_index: usize,
+    ) -> QuadEdge {
+        let midpoint = {
+            let mesh = triangulation.get_mesh();
+            let (start, end) = (mesh.get_start(edge), mesh.get_end(edge));
+            Point::new(halve(start.x() + end.x()), halve(start.y() + end.y()))
+        };
+        let result = triangulation.split_constraint_edge(edge, midpoint);
+        let mesh = triangulation.get_mesh();
+        self.segments_steiner_points
+            .insert(mesh.to_start_index(result), segment_index);
+        self.subsegments.remove(&subsegment);
+        for piece in [edge, result] {
+            self.subsegments
+                .insert(to_edge_key(mesh, piece), (piece, segment_index));
+        }
+        result
+    }
+}
+
+/// checks if the vertex lies on the segment which shares an endpoint
+/// with the encroached one at an acute angle,
+/// splitting segments in such a case would never stop
+fn is_encroachment_negligible<Scalar>(
+    vertex_index: usize,
+    segment_index: usize,
+    segments: &[Segment<Scalar>],
+    segments_steiner_points: &BTreeMap<usize, usize>,
+) -> bool
+where
+    Point<Scalar>: PartialEq,
+    Scalar: PartialOrd + Zero,
+    for<'a> &'a Point<Scalar>: DotMultiply<Output = Scalar>,
+    for<'a> &'a Segment<Scalar>: Segmental<Endpoint = &'a Point<Scalar>>,
+{
+    match segments_steiner_points.get(&vertex_index) {
+        Some(&vertex_segment_index)
+            if vertex_segment_index != segment_index =>
+        {
+            let (start, end) = (&segments[segment_index]).endpoints();
+            let (vertex_start, vertex_end) =
+                (&segments[vertex_segment_index]).endpoints();
+            [(start, end), (end, start)].iter().any(|&(origin, other)| {
+                [(vertex_start, vertex_end), (vertex_end, vertex_start)]
+                    .iter()
+                    .any(|&(vertex_origin, vertex_other)| {
+                        origin == vertex_origin
+                            && DotMultiply::dot_multiply(
+                                origin,
+                                other,
+                                origin,
+                                vertex_other,
+                            ) > Scalar::zero()
+                    })
+            })
+        }
+        _ => false,
+    }
+}
+
+/// checks if the point lies strictly inside the diametral circle
+/// of the segment
+fn is_segment_encroached<Scalar>(
+    start: &Point<Scalar>,
+    end: &Point<Scalar>,
+    point: &Point<Scalar>,
+) -> bool
+where
+    Scalar: PartialOrd + Zero,
+    for<'a> &'a Point<Scalar>: DotMultiply<Output = Scalar>,
+{
+    DotMultiply::dot_multiply(point, start, point, end) < Scalar::zero()
+}
+
+fn to_edge_key<Endpoint>(
+    mesh: &Mesh<Endpoint>,
+    edge: QuadEdge,
+) -> (usize, usize) {
+    to_sorted_pair((mesh.to_start_index(edge), mesh.to_end_index(edge)))
+}
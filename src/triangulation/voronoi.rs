@@ -0,0 +1,330 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use traiter::numbers::Zero;
+
+use crate::bounded::Box;
+use crate::geometries::{Contour, Point};
+use crate::operations::{shrink_collinear_vertices, Orient};
+use crate::oriented::Orientation;
+use crate::traits::Elemental;
+
+use super::delaunay::DelaunayTriangulation;
+use super::mesh::Mesh;
+use super::operations::to_circumcenter;
+use super::quad_edge::{QuadEdge, UNDEFINED_QUAD_EDGE};
+
+/// Voronoi diagram dual to the Delaunay triangulation
+/// with exact circumcenters as its vertices,
+/// unbounded cells are clipped to the box.
+///
+/// ```
+/// use rene::bounded::Box;
+/// use rene::geometries::{Contour, Point};
+/// use rene::triangulation::{DelaunayTriangulation, VoronoiDiagram};
+/// use rithm::big_int::BigInt;
+/// use rithm::fraction::Fraction;
+///
+/// type Scalar = Fraction<BigInt<u32, 31>>;
+///
+/// let to_scalar = |value: i32| Scalar::from(BigInt::from(value));
+/// let to_point = |x: i32, y: i32| Point::new(to_scalar(x), to_scalar(y));
+/// let triangulation = DelaunayTriangulation::from(vec![
+///     to_point(0, 0),
+///     to_point(2, 0),
+///     to_point(2, 2),
+///     to_point(0, 2),
+///     to_point(1, 1),
+/// ]);
+/// let voronoi_diagram = VoronoiDiagram::from((
+///     &triangulation,
+///     &Box::new(to_scalar(-1), to_scalar(3), to_scalar(-1), to_scalar(3)),
+/// ));
+/// assert_eq!(voronoi_diagram.get_sites(), triangulation.get_vertices());
+/// assert_eq!(voronoi_diagram.get_vertices().len(), 4);
+/// assert!(
+///     voronoi_diagram.get_cells()[2]
+///         == Some(Contour::new(vec![
+///             to_point(1, 0),
+///             to_point(2, 1),
+///             to_point(1, 2),
+///             to_point(0, 1),
+///         ]))
+/// );
+/// ```
+#[derive(Clone)]
+pub struct VoronoiDiagram<Scalar> {
+    cells: Vec<Option<Contour<Scalar>>>,
+    sites: Vec<Point<Scalar>>,
+    vertices: Vec<Point<Scalar>>,
+}
+
+impl<Scalar> VoronoiDiagram<Scalar> {
+    /// cells of the sites in the same order,
+    /// missing if the clipped cell has no interior
+    pub fn get_cells(&self) -> &[Option<Contour<Scalar>>] {
+        &self.cells
+    }
+
+    /// sorted unique sites of the diagram
+    pub fn get_sites(&self) -> &[Point<Scalar>] {
+        &self.sites
+    }
+
+    /// sorted unique circumcenters of the Delaunay triangles
+    pub fn get_vertices(&self) -> &[Point<Scalar>] {
+        &self.vertices
+    }
+}
+
+impl<Scalar> From<(&DelaunayTriangulation<Point<Scalar>>, &Box<Scalar>)>
+    for VoronoiDiagram<Scalar>
+where
+    Point<Scalar>: Clone + Ord,
+    Scalar: Clone
+        + PartialOrd
+        + Zero
+        + Add<Output = Scalar>
+        + Div<Output = Scalar>
+        + Mul<Output = Scalar>
+        + Sub<Output = Scalar>,
+    for<'a> &'a Scalar: Add<Output = Scalar>
+        + Mul<Output = Scalar>
+        + Mul<Scalar, Output = Scalar>
+        + Sub<Output = Scalar>,
+    for<'a> &'a Point<Scalar>: Orient,
+{
+    fn from(
+        (triangulation, box_): (
+            &DelaunayTriangulation<Point<Scalar>>,
+            &Box<Scalar>,
+        ),
+    ) -> Self {
+        let mesh = triangulation.get_mesh();
+        let sites = mesh.get_endpoints();
+        let mut vertices = mesh
+            .to_triangles_base_edges()
+            .map(|edge| {
+                let (first, second, third) =
+                    mesh.triangle_base_to_vertices(edge);
+                to_circumcenter(first, second, third)
+            })
+            .collect::<Vec<_>>();
+        vertices.sort();
+        vertices.dedup();
+        let cells = if vertices.is_empty() {
+            // sites are collinear, so sorted sites are neighbours
+            (0..sites.len())
+                .map(|index| {
+                    let mut neighbours = Vec::with_capacity(2);
+                    if index > 0 {
+                        neighbours.push(&sites[index - 1]);
+                    }
+                    if index + 1 < sites.len() {
+                        neighbours.push(&sites[index + 1]);
+                    }
+                    to_clipped_cell(&sites[index], &neighbours, box_)
+                })
+                .collect()
+        } else {
+            let mut sites_edges = vec![UNDEFINED_QUAD_EDGE; sites.len()];
+            for edge in mesh.iter_edges() {
+                let start_index = mesh.to_start_index(edge);
+                if sites_edges[start_index] == UNDEFINED_QUAD_EDGE {
+                    sites_edges[start_index] = edge;
+                }
+            }
+            sites
+                .iter()
+                .zip(sites_edges)
+                .map(|(site, site_edge)| {
+                    let site_edges = to_vertex_edges(mesh, site_edge);
+                    if is_bounded_cell(mesh, &site_edges) {
+                        Some(to_bounded_cell(mesh, site, &site_edges))
+                    } else {
+                        to_clipped_cell(
+                            site,
+                            &site_edges
+                                .into_iter()
+                                .map(|edge| mesh.get_end(edge))
+                                .collect::<Vec<_>>(),
+                            box_,
+                        )
+                    }
+                })
+                .collect()
+        };
+        Self {
+            cells,
+            sites: sites.to_vec(),
+            vertices,
+        }
+    }
+}
+
+fn intersect_segment_with_bisector<Scalar>(
+    start: &Point<Scalar>,
+    end: &Point<Scalar>,
+    start_value: Scalar,
+    end_value: Scalar,
+) -> Point<Scalar>
+where
+    Scalar: Clone
+        + Div<Output = Scalar>
+        + Mul<Output = Scalar>
+        + Sub<Output = Scalar>,
+    for<'a> &'a Scalar: Add<Output = Scalar> + Sub<Output = Scalar>,
+{
+    let scale = start_value.clone() / (start_value - end_value);
+    let (start_x, start_y) = start.coordinates();
+    let (end_x, end_y) = end.coordinates();
+    Point::new(
+        start_x + &((end_x - start_x) * scale.clone()),
+        start_y + &((end_y - start_y) * scale),
+    )
+}
+
+fn is_bounded_cell<Endpoint>(
+    mesh: &Mesh<Endpoint>,
+    vertex_edges: &[QuadEdge],
+) -> bool
+where
+    for<'a> &'a Endpoint: Orient,
+{
+    vertex_edges.len() >= 3
+        && vertex_edges.iter().enumerate().all(|(index, &edge)| {
+            mesh.orient_point_to_edge(
+                edge,
+                mesh.get_end(vertex_edges[(index + 1) % vertex_edges.len()]),
+            ) == Orientation::Counterclockwise
+        })
+}
+
+fn to_bisector_value<Scalar>(
+    site: &Point<Scalar>,
+    neighbour: &Point<Scalar>,
+    point: &Point<Scalar>,
+) -> Scalar
+where
+    Scalar: Add<Output = Scalar> + Sub<Output = Scalar>,
+    for<'a> &'a Scalar: Add<Output = Scalar>
+        + Mul<Scalar, Output = Scalar>
+        + Sub<Output = Scalar>,
+{
+    let (site_x, site_y) = site.coordinates();
+    let (neighbour_x, neighbour_y) = neighbour.coordinates();
+    let (x, y) = point.coordinates();
+    &(neighbour_x - site_x) * ((x + x) - (site_x + neighbour_x))
+        + &(neighbour_y - site_y) * ((y + y) - (site_y + neighbour_y))
+}
+
+fn to_bounded_cell<Scalar>(
+    mesh: &Mesh<Point<Scalar>>,
+    site: &Point<Scalar>,
+    site_edges: &[QuadEdge],
+) -> Contour<Scalar>
+where
+    Point<Scalar>: PartialEq,
+    Scalar: Clone
+        + Add<Output = Scalar>
+        + Div<Output = Scalar>
+        + Mul<Output = Scalar>
+        + Sub<Output = Scalar>,
+    for<'a> &'a Scalar:
+        Add<Output = Scalar> + Mul<Output = Scalar> + Sub<Output = Scalar>,
+{
+    let mut vertices = Vec::<Point<Scalar>>::with_capacity(site_edges.len());
+    for (index, &edge) in site_edges.iter().enumerate() {
+        let vertex = to_circumcenter(
+            site,
+            mesh.get_end(edge),
+            mesh.get_end(site_edges[(index + 1) % site_edges.len()]),
+        );
+        if vertices.last() != Some(&vertex) {
+            vertices.push(vertex);
+        }
+    }
+    if vertices.len() > 1 && vertices.first() == vertices.last() {
+        vertices.pop();
+    }
+    Contour::new(vertices)
+}
+
+fn to_clipped_cell<Scalar>(
+    site: &Point<Scalar>,
+    neighbours: &[&Point<Scalar>],
+    box_: &Box<Scalar>,
+) -> Option<Contour<Scalar>>
+where
+    Point<Scalar>: Clone + PartialOrd,
+    Scalar: Clone
+        + PartialOrd
+        + Zero
+        + Add<Output = Scalar>
+        + Div<Output = Scalar>
+        + Mul<Output = Scalar>
+        + Sub<Output = Scalar>,
+    for<'a> &'a Scalar: Add<Output = Scalar>
+        + Mul<Scalar, Output = Scalar>
+        + Sub<Output = Scalar>,
+    for<'a> &'a Point<Scalar>: Orient,
+{
+    let mut vertices = vec![
+        Point::new(box_.get_min_x().clone(), box_.get_min_y().clone()),
+        Point::new(box_.get_max_x().clone(), box_.get_min_y().clone()),
+        Point::new(box_.get_max_x().clone(), box_.get_max_y().clone()),
+        Point::new(box_.get_min_x().clone(), box_.get_max_y().clone()),
+    ];
+    for &neighbour in neighbours {
+        let values = vertices
+            .iter()
+            .map(|vertex| to_bisector_value(site, neighbour, vertex))
+            .collect::<Vec<_>>();
+        let mut clipped_vertices = Vec::with_capacity(vertices.len() + 1);
+        for index in 0..vertices.len() {
+            let next_index = (index + 1) % vertices.len();
+            let (value, next_value) = (&values[index], &values[next_index]);
+            if *value <= Scalar::zero() {
+                clipped_vertices.push(vertices[index].clone());
+            }
+            if (*value < Scalar::zero() && *next_value > Scalar::zero())
+                || (*value > Scalar::zero() && *next_value < Scalar::zero())
+            {
+                clipped_vertices.push(intersect_segment_with_bisector(
+                    &vertices[index],
+                    &vertices[next_index],
+                    value.clone(),
+                    next_value.clone(),
+                ));
+            }
+        }
+        vertices = clipped_vertices;
+        if vertices.is_empty() {
+            return None;
+        }
+    }
+    Contour::try_new(
+        shrink_collinear_vertices(&vertices.iter().collect::<Vec<_>>())
+            .into_iter()
+            .cloned()
+            .collect(),
+    )
+    .ok()
+}
+
+fn to_vertex_edges<Endpoint>(
+    mesh: &Mesh<Endpoint>,
+    start: QuadEdge,
+) -> Vec<QuadEdge> {
+    let mut result = Vec::new();
+    if start != UNDEFINED_QUAD_EDGE {
+        let mut edge = start;
+        loop {
+            result.push(edge);
+            edge = mesh.to_left_from_start(edge);
+            if edge == start {
+                break;
+            }
+        }
+    }
+    result
+}
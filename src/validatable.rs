@@ -0,0 +1,372 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use traiter::numbers::Parity;
+
+use crate::bentley_ottmann::{
+    to_unique_non_crossing_or_overlapping_segments, EventsRegistry,
+};
+use crate::clipping::shaped::Operation;
+use crate::clipping::traits::ReduceEvents;
+use crate::clipping::{Event, EVEN_ODD_FILL, NON_ZERO_FILL};
+use crate::geometries::{Point, Polygon, Segment};
+use crate::operations::{to_sorted_pair, Orient};
+use crate::oriented::Orientation;
+use crate::relatable::Relation;
+use crate::traits::Segmental;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum FillRule {
+    /// point is inside if any ray from it
+    /// crosses the boundary an odd number of times
+    EvenOdd,
+    /// point is inside if the boundary winds around it
+    /// a non-zero number of times
+    NonZero,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ValidityError<Point> {
+    /// border of the polygon is invalid
+    Border(Box<ValidityError<Point>>),
+    /// consecutive vertices of the contour lie on the same line
+    CollinearVertices {
+        vertices_ids: [usize; 3],
+        vertices: [Point; 3],
+    },
+    /// segment of the geometry has coinciding endpoints
+    DegenerateSegment { segment_id: usize, point: Point },
+    /// border and holes of the polygon touch in a way
+    /// that splits the interior of the polygon into several parts
+    DisconnectedInterior { hole_id: usize, point: Point },
+    /// hole of the polygon is invalid
+    Hole {
+        hole_id: usize,
+        error: Box<ValidityError<Point>>,
+    },
+    /// hole of the polygon intersects its border not at a single point
+    HoleBorderIntersection {
+        hole_id: usize,
+        relation: Relation,
+        start: Point,
+        end: Point,
+    },
+    /// hole of the polygon does not lie inside of its border
+    HoleOutsideBorder { hole_id: usize },
+    /// holes of the polygon intersect not at a single point
+    HolesIntersection {
+        first_hole_id: usize,
+        second_hole_id: usize,
+        relation: Relation,
+        start: Point,
+        end: Point,
+    },
+    /// interiors of the polygon holes intersect
+    HolesOverlap {
+        first_hole_id: usize,
+        second_hole_id: usize,
+    },
+    /// polygon of the multipolygon is invalid
+    Polygon {
+        polygon_id: usize,
+        error: Box<ValidityError<Point>>,
+    },
+    /// contours of the multipolygon polygons intersect
+    /// not at a single point
+    PolygonsIntersection {
+        first_polygon_id: usize,
+        second_polygon_id: usize,
+        relation: Relation,
+        start: Point,
+        end: Point,
+    },
+    /// interiors of the multipolygon polygons intersect
+    PolygonsOverlap {
+        first_polygon_id: usize,
+        second_polygon_id: usize,
+    },
+    /// segments of the geometry intersect in a forbidden way
+    SegmentsIntersection {
+        first_segment_id: usize,
+        second_segment_id: usize,
+        relation: Relation,
+        start: Point,
+        end: Point,
+    },
+    /// multipolygon has fewer polygons than required
+    TooFewPolygons { polygons_count: usize },
+    /// multisegment has fewer segments than required
+    TooFewSegments { segments_count: usize },
+    /// contour has fewer vertices than required
+    TooFewVertices { vertices_count: usize },
+    /// not every pair of neighbour segments of the contour
+    /// touches at their common vertex
+    UntouchedNeighbourSegments {
+        touches_count: usize,
+        segments_count: usize,
+    },
+}
+
+pub trait MakeValid {
+    type Output;
+
+    fn make_valid(self, fill_rule: FillRule) -> Self::Output;
+}
+
+pub trait Validatable<Point> {
+    fn validate(self) -> Result<(), Vec<ValidityError<Point>>>;
+}
+
+impl<Point: fmt::Display> fmt::Display for ValidityError<Point> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Border(error) => {
+                formatter.write_fmt(format_args!("border: {}", error))
+            }
+            Self::CollinearVertices {
+                vertices_ids,
+                vertices,
+            } => formatter.write_fmt(format_args!(
+                "vertices {},{},{} are collinear: {}, {}, {}",
+                vertices_ids[0],
+                vertices_ids[1],
+                vertices_ids[2],
+                vertices[0],
+                vertices[1],
+                vertices[2]
+            )),
+            Self::DegenerateSegment { segment_id, point } => formatter
+                .write_fmt(format_args!(
+                    "segment {} is degenerate at {}",
+                    segment_id, point
+                )),
+            Self::DisconnectedInterior { hole_id, point } => formatter
+                .write_fmt(format_args!(
+                    "hole {} disconnects the interior at {}",
+                    hole_id, point
+                )),
+            Self::Hole { hole_id, error } => formatter
+                .write_fmt(format_args!("hole {}: {}", hole_id, error)),
+            Self::HoleBorderIntersection {
+                hole_id,
+                relation,
+                start,
+                end,
+            } => {
+                formatter
+                    .write_fmt(format_args!("hole {} and border", hole_id))?;
+                write_intersection(formatter, *relation, start, end)
+            }
+            Self::HoleOutsideBorder { hole_id } => formatter.write_fmt(
+                format_args!("hole {} is outside the border", hole_id),
+            ),
+            Self::HolesIntersection {
+                first_hole_id,
+                second_hole_id,
+                relation,
+                start,
+                end,
+            } => {
+                formatter.write_fmt(format_args!(
+                    "holes {} and {}",
+                    first_hole_id, second_hole_id
+                ))?;
+                write_intersection(formatter, *relation, start, end)
+            }
+            Self::HolesOverlap {
+                first_hole_id,
+                second_hole_id,
+            } => formatter.write_fmt(format_args!(
+                "holes {} and {} overlap",
+                first_hole_id, second_hole_id
+            )),
+            Self::Polygon { polygon_id, error } => formatter
+                .write_fmt(format_args!("polygon {}: {}", polygon_id, error)),
+            Self::PolygonsIntersection {
+                first_polygon_id,
+                second_polygon_id,
+                relation,
+                start,
+                end,
+            } => {
+                formatter.write_fmt(format_args!(
+                    "polygons {} and {}",
+                    first_polygon_id, second_polygon_id
+                ))?;
+                write_intersection(formatter, *relation, start, end)
+            }
+            Self::PolygonsOverlap {
+                first_polygon_id,
+                second_polygon_id,
+            } => formatter.write_fmt(format_args!(
+                "polygons {} and {} overlap",
+                first_polygon_id, second_polygon_id
+            )),
+            Self::SegmentsIntersection {
+                first_segment_id,
+                second_segment_id,
+                relation,
+                start,
+                end,
+            } => {
+                formatter.write_fmt(format_args!(
+                    "segments {} and {}",
+                    first_segment_id, second_segment_id
+                ))?;
+                write_intersection(formatter, *relation, start, end)
+            }
+            Self::TooFewPolygons { polygons_count } => formatter.write_fmt(
+                format_args!("too few polygons: {}", polygons_count),
+            ),
+            Self::TooFewSegments { segments_count } => formatter.write_fmt(
+                format_args!("too few segments: {}", segments_count),
+            ),
+            Self::TooFewVertices { vertices_count } => formatter.write_fmt(
+                format_args!("too few vertices: {}", vertices_count),
+            ),
+            Self::UntouchedNeighbourSegments {
+                touches_count,
+                segments_count,
+            } => formatter.write_fmt(format_args!(
+                "only {} of {} neighbour segments touch at vertices",
+                touches_count, segments_count
+            )),
+        }
+    }
+}
+
+fn write_intersection<Point: fmt::Display>(
+    formatter: &mut fmt::Formatter<'_>,
+    relation: Relation,
+    start: &Point,
+    end: &Point,
+) -> fmt::Result {
+    match relation {
+        Relation::Cross => {
+            formatter.write_fmt(format_args!(" cross at {}", start))
+        }
+        Relation::Touch => {
+            formatter.write_fmt(format_args!(" touch at {}", start))
+        }
+        _ => formatter
+            .write_fmt(format_args!(" overlap from {} to {}", start, end)),
+    }
+}
+
+pub(crate) fn segments_to_valid_polygons<Scalar>(
+    segments: Vec<Segment<Scalar>>,
+    fill_rule: FillRule,
+) -> Vec<Polygon<Scalar>>
+where
+    EventsRegistry<Point<Scalar>, true>:
+        Iterator<Item = Event> + for<'a> From<&'a Vec<Segment<Scalar>>>,
+    Operation<Point<Scalar>, EVEN_ODD_FILL>: Iterator<Item = Event>
+        + ReduceEvents<Output = Vec<Polygon<Scalar>>>
+        + From<Vec<(Point<Scalar>, Point<Scalar>, isize)>>,
+    Operation<Point<Scalar>, NON_ZERO_FILL>: Iterator<Item = Event>
+        + ReduceEvents<Output = Vec<Polygon<Scalar>>>
+        + From<Vec<(Point<Scalar>, Point<Scalar>, isize)>>,
+    Point<Scalar>: Clone + Ord,
+    for<'a> &'a Point<Scalar>: Orient,
+    for<'a> &'a Segment<Scalar>: Segmental<Endpoint = &'a Point<Scalar>>,
+{
+    let segments = segments
+        .into_iter()
+        .filter(|segment| {
+            let (start, end) = segment.endpoints();
+            start != end
+        })
+        .collect::<Vec<_>>();
+    let mut winded_segments = to_winded_segments(&segments);
+    match fill_rule {
+        FillRule::EvenOdd => {
+            winded_segments.retain(|(_, _, winding)| winding.is_odd());
+            winded_segments_to_polygons::<Scalar, EVEN_ODD_FILL>(
+                winded_segments,
+            )
+        }
+        FillRule::NonZero => winded_segments_to_polygons::<
+            Scalar,
+            NON_ZERO_FILL,
+        >(winded_segments),
+    }
+}
+
+/// Splits segments into non-crossing & non-overlapping pieces
+/// paired with a signed count of segments directed along them,
+/// omitting pieces whose segments cancel each other out.
+fn to_winded_segments<Scalar>(
+    segments: &Vec<Segment<Scalar>>,
+) -> Vec<(Point<Scalar>, Point<Scalar>, isize)>
+where
+    EventsRegistry<Point<Scalar>, true>:
+        Iterator<Item = Event> + for<'a> From<&'a Vec<Segment<Scalar>>>,
+    Point<Scalar>: Clone + Ord,
+    for<'a> &'a Point<Scalar>: Orient,
+    for<'a> &'a Segment<Scalar>: Segmental<Endpoint = &'a Point<Scalar>>,
+{
+    let pieces = to_unique_non_crossing_or_overlapping_segments::<
+        _,
+        (Point<Scalar>, Point<Scalar>),
+        _,
+    >(segments)
+    .into_iter()
+    .map(to_sorted_pair)
+    .collect::<Vec<_>>();
+    let mut start_pieces_ids = BTreeMap::<&Point<Scalar>, Vec<usize>>::new();
+    for (piece_id, (start, _)) in pieces.iter().enumerate() {
+        start_pieces_ids.entry(start).or_default().push(piece_id);
+    }
+    let mut windings = vec![0isize; pieces.len()];
+    for segment in segments {
+        let (start, end) = segment.endpoints();
+        let ((min_endpoint, max_endpoint), winding) = if start < end {
+            ((start, end), 1)
+        } else {
+            ((end, start), -1)
+        };
+        let mut piece_start = min_endpoint;
+        while piece_start != max_endpoint {
+            let piece_id = start_pieces_ids[piece_start].iter().copied().find(
+                |&piece_id| {
+                    min_endpoint.orient(max_endpoint, &pieces[piece_id].1)
+                        == Orientation::Collinear
+                },
+            );
+            debug_assert!(piece_id.is_some());
+            let piece_id = unsafe { piece_id.unwrap_unchecked() };
+            windings[piece_id] += winding;
+            piece_start = &pieces[piece_id].1;
+        }
+    }
+    pieces
+        .into_iter()
+        .zip(windings)
+        .filter(|(_, winding)| *winding != 0)
+        .map(|((start, end), winding)| (start, end, winding))
+        .collect()
+}
+
+fn winded_segments_to_polygons<Scalar, const KIND: u8>(
+    winded_segments: Vec<(Point<Scalar>, Point<Scalar>, isize)>,
+) -> Vec<Polygon<Scalar>>
+where
+    Operation<Point<Scalar>, KIND>: Iterator<Item = Event>
+        + ReduceEvents<Output = Vec<Polygon<Scalar>>>
+        + From<Vec<(Point<Scalar>, Point<Scalar>, isize)>>,
+{
+    if winded_segments.is_empty() {
+        return vec![];
+    }
+    let mut operation =
+        Operation::<Point<Scalar>, KIND>::from(winded_segments);
+    let mut events = {
+        let (_, maybe_events_count) = operation.size_hint();
+        debug_assert!(maybe_events_count.is_some());
+        Vec::with_capacity(unsafe { maybe_events_count.unwrap_unchecked() })
+    };
+    for event in operation.by_ref() {
+        events.push(event);
+    }
+    operation.reduce_events(events)
+}
@@ -0,0 +1,66 @@
+use rene::geometries::{Multipolygon, Point, Polygon};
+use rene::traits::Centroid;
+
+mod common;
+
+use common::{to_contour, to_point, to_scalar, Scalar};
+
+fn to_halves_point(doubled_x: i64, doubled_y: i64) -> Point<Scalar> {
+    Point::new(
+        to_scalar(doubled_x) / to_scalar(2),
+        to_scalar(doubled_y) / to_scalar(2),
+    )
+}
+
+#[test]
+fn polygon_centroid_is_weighted_by_area() {
+    let polygon = Polygon::new(
+        to_contour(&[(0, 0), (6, 0), (6, 6), (0, 6)]),
+        vec![to_contour(&[(0, 0), (0, 3), (3, 3), (3, 0)])],
+    );
+
+    let centroid: Point<Scalar> = (&polygon).centroid();
+
+    assert!(centroid == to_halves_point(7, 7));
+}
+
+#[test]
+fn multipolygon_centroid_is_weighted_by_area() {
+    let multipolygon = Multipolygon::new(vec![
+        Polygon::new(to_contour(&[(0, 0), (2, 0), (2, 2), (0, 2)]), vec![]),
+        Polygon::new(to_contour(&[(4, 0), (8, 0), (8, 4), (4, 4)]), vec![]),
+    ]);
+
+    let centroid: Point<Scalar> = (&multipolygon).centroid();
+
+    // (4 * (1, 1) + 16 * (6, 2)) / 20
+    assert!(
+        centroid
+            == Point::new(
+                to_scalar(100) / to_scalar(20),
+                to_scalar(36) / to_scalar(20)
+            )
+    );
+}
+
+#[test]
+fn zero_area_polygon_centroid_is_border_vertices_average() {
+    let polygon =
+        Polygon::new(to_contour(&[(0, 0), (1, 1), (2, 2), (5, 5)]), vec![]);
+
+    let centroid: Point<Scalar> = (&polygon).centroid();
+
+    assert!(centroid == to_point(2, 2));
+}
+
+#[test]
+fn zero_area_multipolygon_centroid_is_borders_vertices_average() {
+    let multipolygon = Multipolygon::new(vec![
+        Polygon::new(to_contour(&[(0, 0), (1, 0), (2, 0)]), vec![]),
+        Polygon::new(to_contour(&[(0, 3), (1, 3), (2, 3)]), vec![]),
+    ]);
+
+    let centroid: Point<Scalar> = (&multipolygon).centroid();
+
+    assert!(centroid == to_halves_point(2, 3));
+}
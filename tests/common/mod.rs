@@ -0,0 +1,75 @@
+#![allow(dead_code)]
+
+use rene::geometries::{Contour, Point, Segment};
+use rithm::big_int::BigInt;
+use rithm::fraction::Fraction;
+
+pub type Scalar = Fraction<BigInt<u32, 31>>;
+
+pub fn to_scalar(value: i64) -> Scalar {
+    Scalar::from(BigInt::from(value))
+}
+
+pub fn to_point(x: i64, y: i64) -> Point<Scalar> {
+    Point::new(to_scalar(x), to_scalar(y))
+}
+
+pub fn to_contour(vertices: &[(i64, i64)]) -> Contour<Scalar> {
+    Contour::new(vertices.iter().map(|&(x, y)| to_point(x, y)).collect())
+}
+
+pub fn to_segment(
+    (start_x, start_y): (i64, i64),
+    (end_x, end_y): (i64, i64),
+) -> Segment<Scalar> {
+    Segment::new(to_point(start_x, start_y), to_point(end_x, end_y))
+}
+
+/// Deterministic xorshift generator for reproducible random inputs.
+pub struct Random(u64);
+
+impl Random {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    pub fn below(&mut self, limit: u64) -> i64 {
+        (self.next() % limit) as i64
+    }
+
+    pub fn point(&mut self, limit: u64) -> Point<Scalar> {
+        let x = self.below(limit);
+        let y = self.below(limit);
+        to_point(x, y)
+    }
+
+    /// Non-degenerate segments with small coordinates,
+    /// so crossings, touches & overlaps are frequent.
+    pub fn segments(
+        &mut self,
+        count: usize,
+        limit: u64,
+    ) -> Vec<Segment<Scalar>> {
+        let mut result = Vec::with_capacity(count);
+        while result.len() < count {
+            let start = (self.below(limit), self.below(limit));
+            let end = if self.next() % 3 == 0 {
+                let offset = self.below(limit);
+                (start.0 + offset, start.1 + offset)
+            } else {
+                (self.below(limit), self.below(limit))
+            };
+            if start != end {
+                result.push(to_segment(start, end));
+            }
+        }
+        result
+    }
+}
@@ -0,0 +1,99 @@
+use rene::geometries::{
+    ConstructionError, Contour, Multipolygon, Multisegment, Polygon,
+};
+
+mod common;
+
+use common::{to_contour, to_point, to_segment, Scalar};
+
+#[test]
+fn contour_try_new_rejects_too_few_vertices() {
+    let result =
+        Contour::<Scalar>::try_new(vec![to_point(0, 0), to_point(1, 0)]);
+
+    let error = result.err().unwrap();
+    assert_eq!(
+        error,
+        ConstructionError::TooFewVertices { vertices_count: 2 }
+    );
+    assert_eq!(
+        error.to_string(),
+        "Contour should have at least 3 vertices, but found 2."
+    );
+    assert!(Contour::try_new(vec![
+        to_point(0, 0),
+        to_point(1, 0),
+        to_point(0, 1)
+    ])
+    .is_ok());
+}
+
+#[test]
+fn multisegment_try_new_rejects_too_few_segments() {
+    let result =
+        Multisegment::<Scalar>::try_new(vec![to_segment((0, 0), (1, 0))]);
+
+    let error = result.err().unwrap();
+    assert_eq!(
+        error,
+        ConstructionError::TooFewSegments { segments_count: 1 }
+    );
+    assert_eq!(
+        error.to_string(),
+        "Multisegment should have at least 2 segments, but found 1."
+    );
+    assert!(Multisegment::try_new(vec![
+        to_segment((0, 0), (1, 0)),
+        to_segment((0, 1), (1, 1))
+    ])
+    .is_ok());
+}
+
+#[test]
+fn multipolygon_try_new_rejects_too_few_polygons() {
+    let polygon = Polygon::new(to_contour(&[(0, 0), (1, 0), (0, 1)]), vec![]);
+
+    let result = Multipolygon::try_new(vec![polygon.clone()]);
+
+    let error = result.err().unwrap();
+    assert_eq!(
+        error,
+        ConstructionError::TooFewPolygons { polygons_count: 1 }
+    );
+    assert_eq!(
+        error.to_string(),
+        "Multipolygon should have at least 2 polygons, but found 1."
+    );
+    assert!(Multipolygon::try_new(vec![
+        polygon,
+        Polygon::new(to_contour(&[(2, 0), (3, 0), (2, 1)]), vec![])
+    ])
+    .is_ok());
+}
+
+#[test]
+fn polygon_try_new_reports_invalid_component() {
+    let border = to_contour(&[(0, 0), (4, 0), (4, 4), (0, 4)]);
+    let hole = to_contour(&[(1, 1), (1, 2), (2, 2), (2, 1)]);
+
+    assert!(Polygon::try_new(border.clone(), vec![hole.clone()]).is_ok());
+    let error = Polygon::try_new(
+        border,
+        vec![hole, Contour::new(vec![to_point(3, 3), to_point(3, 2)])],
+    )
+    .err()
+    .unwrap();
+    assert_eq!(
+        error,
+        ConstructionError::Hole {
+            hole_id: 1,
+            error: Box::new(ConstructionError::TooFewVertices {
+                vertices_count: 2
+            }),
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "hole 1: Contour should have at least 3 vertices, but found 2."
+    );
+}
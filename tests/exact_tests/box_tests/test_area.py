@@ -0,0 +1,20 @@
+from hypothesis import given
+from rithm.fraction import Fraction
+
+from rene.exact import Box
+from . import strategies
+
+
+@given(strategies.boxes)
+def test_basic(box: Box) -> None:
+    assert isinstance(box.area, Fraction)
+
+
+@given(strategies.boxes)
+def test_sign(box: Box) -> None:
+    assert box.area >= 0
+
+
+@given(strategies.boxes)
+def test_dimensions(box: Box) -> None:
+    assert box.area == (box.max_x - box.min_x) * (box.max_y - box.min_y)
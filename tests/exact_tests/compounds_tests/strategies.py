@@ -36,3 +36,10 @@ closed_idempotent_compounds_triplets = (
     _closed_idempotent_compounds_strategies.flatmap(to_triplets)
 )
 points = _strategies.points
+non_empty_compounds = (_strategies.segments | _strategies.multisegments
+                       | _strategies.contours | _strategies.polygons
+                       | _strategies.multipolygons)
+non_empty_geometries = points | non_empty_compounds
+non_negative_scalars = (_st.integers(0, _strategies.MAX_VALUE)
+                        | _st.fractions(0, _strategies.MAX_VALUE,
+                                        max_denominator=_strategies.MAX_VALUE))
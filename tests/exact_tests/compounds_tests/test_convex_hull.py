@@ -0,0 +1,38 @@
+from hypothesis import given
+
+from rene import Relation
+from rene.exact import (Contour,
+                        Empty,
+                        Point,
+                        Polygon,
+                        Segment)
+from tests.exact_tests.hints import Compound
+from . import strategies
+
+
+@given(strategies.compounds)
+def test_basic(compound: Compound) -> None:
+    result = compound.convex_hull()
+
+    assert isinstance(result, (Contour, Empty, Point, Segment))
+
+
+@given(strategies.compounds)
+def test_containment(compound: Compound) -> None:
+    result = compound.convex_hull()
+
+    assert (isinstance(result, Empty) is isinstance(compound, Empty)
+            and (not isinstance(result, Contour)
+                 or compound.relate_to(Polygon(result, []))
+                 in (Relation.COMPONENT, Relation.ENCLOSED, Relation.EQUAL,
+                     Relation.WITHIN))
+            and (not isinstance(result, Segment)
+                 or compound.relate_to(result)
+                 in (Relation.COMPONENT, Relation.EQUAL)))
+
+
+@given(strategies.compounds)
+def test_idempotence(compound: Compound) -> None:
+    result = compound.convex_hull()
+
+    assert result.convex_hull() == result
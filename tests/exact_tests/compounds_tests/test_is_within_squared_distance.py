@@ -0,0 +1,47 @@
+from fractions import Fraction
+from typing import Union
+
+from hypothesis import given
+
+from tests.exact_tests.hints import NonEmptyGeometry
+from tests.utils import equivalence
+from . import strategies
+
+
+@given(strategies.non_empty_geometries, strategies.non_empty_geometries,
+       strategies.non_negative_scalars)
+def test_basic(first: NonEmptyGeometry,
+               second: NonEmptyGeometry,
+               squared_threshold: Union[Fraction, int]) -> None:
+    result = first.is_within_squared_distance(second, squared_threshold)
+
+    assert isinstance(result, bool)
+
+
+@given(strategies.non_empty_geometries, strategies.non_empty_geometries,
+       strategies.non_negative_scalars)
+def test_squared_distance_connection(first: NonEmptyGeometry,
+                                     second: NonEmptyGeometry,
+                                     squared_threshold: Union[Fraction, int]
+                                     ) -> None:
+    assert equivalence(
+            first.is_within_squared_distance(second, squared_threshold),
+            first.squared_distance_to(second) <= squared_threshold
+    )
+
+
+@given(strategies.non_empty_geometries, strategies.non_empty_geometries,
+       strategies.non_negative_scalars)
+def test_symmetry(first: NonEmptyGeometry,
+                  second: NonEmptyGeometry,
+                  squared_threshold: Union[Fraction, int]) -> None:
+    assert equivalence(
+            first.is_within_squared_distance(second, squared_threshold),
+            second.is_within_squared_distance(first, squared_threshold)
+    )
+
+
+@given(strategies.non_empty_geometries, strategies.non_negative_scalars)
+def test_reflexivity(geometry: NonEmptyGeometry,
+                     squared_threshold: Union[Fraction, int]) -> None:
+    assert geometry.is_within_squared_distance(geometry, squared_threshold)
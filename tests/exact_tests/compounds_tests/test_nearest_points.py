@@ -0,0 +1,45 @@
+from hypothesis import given
+
+from rene.exact import Point
+from tests.exact_tests.hints import NonEmptyGeometry
+from . import strategies
+
+
+@given(strategies.non_empty_geometries, strategies.non_empty_geometries)
+def test_basic(first: NonEmptyGeometry, second: NonEmptyGeometry) -> None:
+    result = first.nearest_points(second)
+
+    assert isinstance(result, tuple)
+    assert len(result) == 2
+    assert all(isinstance(element, Point) for element in result)
+
+
+@given(strategies.non_empty_geometries, strategies.non_empty_geometries)
+def test_properties(first: NonEmptyGeometry,
+                    second: NonEmptyGeometry) -> None:
+    first_point, second_point = first.nearest_points(second)
+
+    assert _lies_on(first_point, first)
+    assert _lies_on(second_point, second)
+    assert (first_point.squared_distance_to(second_point)
+            == first.squared_distance_to(second))
+
+
+@given(strategies.non_empty_geometries, strategies.non_empty_geometries)
+def test_symmetry(first: NonEmptyGeometry,
+                  second: NonEmptyGeometry) -> None:
+    first_point, second_point = first.nearest_points(second)
+    second_reversed_point, first_reversed_point = second.nearest_points(
+            first
+    )
+
+    assert (first_point.squared_distance_to(second_point)
+            == first_reversed_point.squared_distance_to(
+                    second_reversed_point
+            ))
+
+
+def _lies_on(point: Point, geometry: NonEmptyGeometry) -> bool:
+    return (point == geometry
+            if isinstance(geometry, Point)
+            else point in geometry)
@@ -0,0 +1,42 @@
+from hypothesis import given
+from rithm.fraction import Fraction
+
+from rene import Relation
+from rene.exact import Point
+from tests.exact_tests.hints import (NonEmptyCompound,
+                                     NonEmptyGeometry)
+from tests.utils import equivalence
+from . import strategies
+
+
+@given(strategies.non_empty_geometries, strategies.non_empty_geometries)
+def test_basic(first: NonEmptyGeometry, second: NonEmptyGeometry) -> None:
+    result = first.squared_distance_to(second)
+
+    assert isinstance(result, Fraction)
+    assert result >= 0
+
+
+@given(strategies.non_empty_geometries)
+def test_reflexivity(geometry: NonEmptyGeometry) -> None:
+    assert geometry.squared_distance_to(geometry) == 0
+
+
+@given(strategies.non_empty_geometries, strategies.non_empty_geometries)
+def test_symmetry(first: NonEmptyGeometry,
+                  second: NonEmptyGeometry) -> None:
+    assert (first.squared_distance_to(second)
+            == second.squared_distance_to(first))
+
+
+@given(strategies.non_empty_compounds, strategies.non_empty_compounds)
+def test_intersecting(first: NonEmptyCompound,
+                      second: NonEmptyCompound) -> None:
+    assert equivalence(first.squared_distance_to(second) == 0,
+                       first.relate_to(second) is not Relation.DISJOINT)
+
+
+@given(strategies.non_empty_compounds, strategies.points)
+def test_containing(compound: NonEmptyCompound, point: Point) -> None:
+    assert equivalence(compound.squared_distance_to(point) == 0,
+                       point in compound)
@@ -1,3 +1,4 @@
 from tests.exact_tests import strategies as _strategies
 
+multipolygons = _strategies.multipolygons
 polygons = _strategies.polygons
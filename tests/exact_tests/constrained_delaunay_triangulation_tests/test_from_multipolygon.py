@@ -0,0 +1,35 @@
+from hypothesis import given
+from rithm.fraction import Fraction
+
+from rene import Relation
+from rene.exact import (ConstrainedDelaunayTriangulation,
+                        Multipolygon,
+                        Polygon)
+from tests.utils import is_contour_triangular
+from . import strategies
+
+
+@given(strategies.multipolygons)
+def test_basic(multipolygon: Multipolygon) -> None:
+    result = ConstrainedDelaunayTriangulation.from_multipolygon(multipolygon)
+
+    assert isinstance(result, ConstrainedDelaunayTriangulation)
+
+
+@given(strategies.multipolygons)
+def test_triangles(multipolygon: Multipolygon) -> None:
+    result = ConstrainedDelaunayTriangulation.from_multipolygon(multipolygon)
+
+    triangles = result.triangles
+    assert all(is_contour_triangular(triangle) for triangle in triangles)
+    assert all(Polygon(triangle, []).relate_to(multipolygon)
+               in (Relation.ENCLOSED, Relation.EQUAL, Relation.WITHIN)
+               for triangle in triangles)
+
+
+@given(strategies.multipolygons)
+def test_area(multipolygon: Multipolygon) -> None:
+    result = ConstrainedDelaunayTriangulation.from_multipolygon(multipolygon)
+
+    assert sum([abs(triangle.area) for triangle in result.triangles],
+               Fraction(0)) == multipolygon.area
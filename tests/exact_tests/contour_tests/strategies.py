@@ -5,6 +5,7 @@ from rene.exact import Contour as _Contour
 from tests.exact_tests import strategies as _strategies
 
 points = _strategies.points
+fill_rules = _strategies.fill_rules
 non_zero_integers = _strategies.non_zero_integers
 contours_vertices = _strategies.contours_vertices
 contours_like_vertices = _st.lists(points,
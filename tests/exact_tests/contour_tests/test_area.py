@@ -0,0 +1,30 @@
+from hypothesis import given
+from rithm.fraction import Fraction
+
+from rene import Orientation
+from rene.exact import Contour
+from tests.utils import (reverse_contour_vertices,
+                         rotate_contour)
+from . import strategies
+
+
+@given(strategies.contours_like)
+def test_basic(contour: Contour) -> None:
+    assert isinstance(contour.area, Fraction)
+
+
+@given(strategies.contours)
+def test_sign(contour: Contour) -> None:
+    assert (contour.area > 0
+            if contour.orientation is Orientation.COUNTERCLOCKWISE
+            else contour.area < 0)
+
+
+@given(strategies.contours)
+def test_vertices_reversal(contour: Contour) -> None:
+    assert reverse_contour_vertices(contour).area == -contour.area
+
+
+@given(strategies.contours, strategies.non_zero_integers)
+def test_vertices_rotations(contour: Contour, offset: int) -> None:
+    assert rotate_contour(contour, offset).area == contour.area
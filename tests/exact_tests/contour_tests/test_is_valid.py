@@ -28,3 +28,8 @@ def test_vertices_rotations(contour: Contour, offset: int) -> None:
 @given(strategies.contours)
 def test_vertices_reversal(contour: Contour) -> None:
     assert equivalence(contour.is_valid(), reverse_contour_vertices(contour).is_valid())
+
+
+@given(strategies.contours_like)
+def test_validity_explanation(contour: Contour) -> None:
+    assert equivalence(contour.is_valid(), not contour.explain_validity())
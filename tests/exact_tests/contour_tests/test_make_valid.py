@@ -0,0 +1,38 @@
+from hypothesis import given
+
+from rene import (FillRule,
+                  Relation)
+from rene.exact import (Contour,
+                        Empty,
+                        Multipolygon,
+                        Polygon)
+from . import strategies
+
+
+@given(strategies.contours_like, strategies.fill_rules)
+def test_basic(contour: Contour, fill_rule: FillRule) -> None:
+    result = contour.make_valid(fill_rule)
+
+    assert isinstance(result, (Empty, Multipolygon, Polygon))
+
+
+@given(strategies.contours_like, strategies.fill_rules)
+def test_validity(contour: Contour, fill_rule: FillRule) -> None:
+    result = contour.make_valid(fill_rule)
+
+    assert isinstance(result, Empty) or result.is_valid()
+
+
+@given(strategies.contours_like)
+def test_fill_rules(contour: Contour) -> None:
+    even_odd_result = contour.make_valid(FillRule.EVEN_ODD)
+    non_zero_result = contour.make_valid(FillRule.NON_ZERO)
+
+    assert isinstance(even_odd_result - non_zero_result, Empty)
+
+
+@given(strategies.contours, strategies.fill_rules)
+def test_valid(contour: Contour, fill_rule: FillRule) -> None:
+    result = contour.make_valid(fill_rule)
+
+    assert result.relate_to(Polygon(contour, [])) is Relation.EQUAL
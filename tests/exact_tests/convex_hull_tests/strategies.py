@@ -0,0 +1,5 @@
+from hypothesis import strategies as _st
+
+from tests.exact_tests import strategies as _strategies
+
+points_lists = _st.lists(_strategies.points)
@@ -0,0 +1,54 @@
+from typing import Sequence
+
+from hypothesis import given
+
+from rene import (Location,
+                  Orientation)
+from rene._exact import orient
+from rene.exact import (Contour,
+                        Empty,
+                        Point,
+                        Polygon,
+                        Segment,
+                        convex_hull)
+from tests.utils import to_convex_hull
+from . import strategies
+
+
+@given(strategies.points_lists)
+def test_basic(points: Sequence[Point]) -> None:
+    result = convex_hull(points)
+
+    assert isinstance(result, (Contour, Empty, Point, Segment))
+
+
+@given(strategies.points_lists)
+def test_vertices(points: Sequence[Point]) -> None:
+    result = convex_hull(points)
+
+    vertices = to_convex_hull(points, orient)
+    assert result == (Empty() if not vertices
+                      else (vertices[0] if len(vertices) == 1
+                            else (Segment(*vertices) if len(vertices) == 2
+                                  else Contour(vertices))))
+
+
+@given(strategies.points_lists)
+def test_orientation(points: Sequence[Point]) -> None:
+    result = convex_hull(points)
+
+    assert (not isinstance(result, Contour)
+            or result.orientation is Orientation.COUNTERCLOCKWISE)
+
+
+@given(strategies.points_lists)
+def test_containment(points: Sequence[Point]) -> None:
+    result = convex_hull(points)
+
+    assert all(
+            (point == result if isinstance(result, Point)
+             else (Polygon(result, []).locate(point) is not Location.EXTERIOR
+                   if isinstance(result, Contour)
+                   else point in result))
+            for point in points
+    )
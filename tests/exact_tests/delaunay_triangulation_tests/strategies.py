@@ -7,3 +7,4 @@ points_lists = _st.lists(points,
                          min_size=1)
 two_or_more_points_lists = _st.lists(points,
                                      min_size=2)
+squared_alphas = _st.integers(0, _strategies.MAX_VALUE)
@@ -0,0 +1,72 @@
+from typing import Sequence
+
+from hypothesis import given
+
+from rene import Relation
+from rene.exact import (DelaunayTriangulation,
+                        Empty,
+                        Multipolygon,
+                        Point,
+                        Polygon)
+from tests.utils import to_squared_circumradius
+from . import strategies
+
+
+@given(strategies.points_lists, strategies.squared_alphas)
+def test_basic(points: Sequence[Point], squared_alpha: int) -> None:
+    triangulation = DelaunayTriangulation.from_points(points)
+
+    result = triangulation.alpha_shape(squared_alpha)
+
+    assert isinstance(result, (Empty, Multipolygon, Polygon))
+
+
+@given(strategies.points_lists)
+def test_zero_alpha(points: Sequence[Point]) -> None:
+    triangulation = DelaunayTriangulation.from_points(points)
+
+    result = triangulation.alpha_shape(0)
+
+    assert isinstance(result, Empty)
+
+
+
+@given(strategies.points_lists, strategies.squared_alphas)
+def test_validity(points: Sequence[Point], squared_alpha: int) -> None:
+    triangulation = DelaunayTriangulation.from_points(points)
+
+    result = triangulation.alpha_shape(squared_alpha)
+
+    assert isinstance(result, Empty) or result.is_valid()
+
+
+@given(strategies.points_lists, strategies.squared_alphas,
+       strategies.squared_alphas)
+def test_monotonicity(points: Sequence[Point],
+                      first_squared_alpha: int,
+                      second_squared_alpha: int) -> None:
+    triangulation = DelaunayTriangulation.from_points(points)
+    min_squared_alpha, max_squared_alpha = sorted([first_squared_alpha,
+                                                   second_squared_alpha])
+
+    smaller_result = triangulation.alpha_shape(min_squared_alpha)
+    larger_result = triangulation.alpha_shape(max_squared_alpha)
+
+    assert isinstance(smaller_result - larger_result, Empty)
+
+
+@given(strategies.points_lists)
+def test_max_circumradius(points: Sequence[Point]) -> None:
+    triangulation = DelaunayTriangulation.from_points(points)
+    triangles = triangulation.triangles
+
+    result = triangulation.alpha_shape(
+            max([to_squared_circumradius(*triangle.vertices)
+                 for triangle in triangles],
+                default=0)
+    )
+
+    assert (result.relate_to(Polygon(triangulation.border, []))
+            is Relation.EQUAL
+            if triangles
+            else isinstance(result, Empty))
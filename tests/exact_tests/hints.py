@@ -6,6 +6,14 @@ Compound = _t.Union[
     _exact.Contour, _exact.Empty, _exact.Multipolygon,
     _exact.Multisegment, _exact.Polygon, _exact.Segment
 ]
+NonEmptyCompound = _t.Union[
+    _exact.Contour, _exact.Multipolygon, _exact.Multisegment,
+    _exact.Polygon, _exact.Segment
+]
+NonEmptyGeometry = _t.Union[
+    _exact.Contour, _exact.Multipolygon, _exact.Multisegment, _exact.Point,
+    _exact.Polygon, _exact.Segment
+]
 # here and after we use `TypeVar` instead of `Union` because of
 # https://github.com/python/mypy/issues/6478
 CompoundT = _t.TypeVar(
@@ -5,6 +5,7 @@ from rene.exact import Multipolygon
 from tests.exact_tests import strategies as _strategies
 
 points = _strategies.points
+fill_rules = _strategies.fill_rules
 non_zero_integers = _strategies.non_zero_integers
 multipolygons_polygons = _strategies.multipolygons_polygons
 multipolygons_like_polygons = strategies.lists(
@@ -0,0 +1,24 @@
+from hypothesis import given
+from rithm.fraction import Fraction
+
+from rene.exact import Multipolygon
+from tests.utils import reverse_multipolygon_polygons
+from . import strategies
+
+
+@given(strategies.multipolygons)
+def test_basic(multipolygon: Multipolygon) -> None:
+    assert isinstance(multipolygon.area, Fraction)
+
+
+@given(strategies.multipolygons)
+def test_additivity(multipolygon: Multipolygon) -> None:
+    assert multipolygon.area == sum(
+            [polygon.area for polygon in multipolygon.polygons], Fraction(0)
+    )
+
+
+@given(strategies.multipolygons)
+def test_polygons_reversal(multipolygon: Multipolygon) -> None:
+    assert (reverse_multipolygon_polygons(multipolygon).area
+            == multipolygon.area)
@@ -0,0 +1,37 @@
+from hypothesis import given
+
+from rene import (FillRule,
+                  Relation)
+from rene.exact import (Empty,
+                        Multipolygon,
+                        Polygon)
+from . import strategies
+
+
+@given(strategies.multipolygons_like, strategies.fill_rules)
+def test_basic(multipolygon: Multipolygon, fill_rule: FillRule) -> None:
+    result = multipolygon.make_valid(fill_rule)
+
+    assert isinstance(result, (Empty, Multipolygon, Polygon))
+
+
+@given(strategies.multipolygons_like, strategies.fill_rules)
+def test_validity(multipolygon: Multipolygon, fill_rule: FillRule) -> None:
+    result = multipolygon.make_valid(fill_rule)
+
+    assert isinstance(result, Empty) or result.is_valid()
+
+
+@given(strategies.multipolygons_like)
+def test_fill_rules(multipolygon: Multipolygon) -> None:
+    even_odd_result = multipolygon.make_valid(FillRule.EVEN_ODD)
+    non_zero_result = multipolygon.make_valid(FillRule.NON_ZERO)
+
+    assert isinstance(even_odd_result - non_zero_result, Empty)
+
+
+@given(strategies.multipolygons, strategies.fill_rules)
+def test_valid(multipolygon: Multipolygon, fill_rule: FillRule) -> None:
+    result = multipolygon.make_valid(fill_rule)
+
+    assert result.relate_to(multipolygon) is Relation.EQUAL
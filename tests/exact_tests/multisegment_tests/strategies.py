@@ -12,6 +12,11 @@ multisegments_like_segments = strategies.lists(
         unique=True,
         min_size=MIN_MULTISEGMENT_SEGMENTS_COUNT
 )
+invalid_count_multisegments_segments = strategies.lists(
+        _strategies.segments,
+        unique=True,
+        max_size=MIN_MULTISEGMENT_SEGMENTS_COUNT - 1
+)
 multisegments_like = strategies.builds(Multisegment,
                                        multisegments_like_segments)
 multisegments = _strategies.multisegments
@@ -1,5 +1,6 @@
 from typing import Sequence
 
+import pytest
 from hypothesis import given
 
 from rene.exact import (Multisegment,
@@ -14,3 +15,9 @@ def test_basic(segments: Sequence[Segment]) -> None:
 
     assert isinstance(result, Multisegment)
     assert are_sequences_equivalent(result.segments, segments)
+
+
+@given(strategies.invalid_count_multisegments_segments)
+def test_invalid_segments_count(segments: Sequence[Segment]) -> None:
+    with pytest.raises(ValueError):
+        Multisegment(segments)
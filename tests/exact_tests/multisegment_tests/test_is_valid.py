@@ -21,3 +21,9 @@ def test_vertices_rotations(multisegment: Multisegment, offset: int) -> None:
 def test_vertices_reversal(multisegment: Multisegment) -> None:
     assert equivalence(multisegment.is_valid(),
                        reverse_multisegment(multisegment).is_valid())
+
+
+@given(strategies.multisegments_like)
+def test_validity_explanation(multisegment: Multisegment) -> None:
+    assert equivalence(multisegment.is_valid(),
+                       not multisegment.explain_validity())
@@ -1,7 +1,10 @@
 from hypothesis import strategies as _st
 from rithm.fraction import Fraction as _Fraction
 
-from rene.exact import Point as _Point
+from rene import MIN_CONTOUR_VERTICES_COUNT as _MIN_CONTOUR_VERTICES_COUNT
+from rene.exact import (Contour as _Contour,
+                        Point as _Point,
+                        Polygon as _Polygon)
 from tests.exact_tests import strategies as _strategies
 
 integers = _st.integers()
@@ -13,6 +16,16 @@ scalars = (integers | _st.fractions()
 points = _st.builds(_Point, scalars, scalars)
 polygons_components = _strategies.polygons_components
 polygons = _strategies.polygons
+fill_rules = _strategies.fill_rules
+contours_like = _st.builds(
+        _Contour,
+        _st.lists(_strategies.points,
+                  unique=True,
+                  min_size=_MIN_CONTOUR_VERTICES_COUNT)
+)
+polygons_like = _st.builds(_Polygon, contours_like,
+                           _st.lists(contours_like,
+                                     max_size=2))
 compounds = (
         _strategies.empty_geometries | _strategies.segments
         | _strategies.multisegments | _strategies.contours
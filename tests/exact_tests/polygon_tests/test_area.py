@@ -0,0 +1,34 @@
+from hypothesis import given
+from rithm.fraction import Fraction
+
+from rene.exact import Polygon
+from tests.exact_tests.hints import MaybeShapedCompound
+from . import strategies
+
+
+@given(strategies.polygons)
+def test_basic(polygon: Polygon) -> None:
+    assert isinstance(polygon.area, Fraction)
+
+
+@given(strategies.polygons)
+def test_sign(polygon: Polygon) -> None:
+    assert polygon.area > 0
+
+
+@given(strategies.polygons)
+def test_components(polygon: Polygon) -> None:
+    assert polygon.area == (abs(polygon.border.area)
+                            - sum([abs(hole.area) for hole in polygon.holes],
+                                  Fraction(0)))
+
+
+@given(strategies.polygons, strategies.maybe_shaped_compounds)
+def test_additivity(first: Polygon, second: MaybeShapedCompound) -> None:
+    assert ((first | second).area + (first & second).area
+            == first.area + second.area)
+
+
+@given(strategies.polygons, strategies.maybe_shaped_compounds)
+def test_difference(first: Polygon, second: MaybeShapedCompound) -> None:
+    assert (first - second).area == first.area - (first & second).area
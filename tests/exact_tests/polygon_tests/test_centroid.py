@@ -0,0 +1,28 @@
+from hypothesis import given
+
+from rene.exact import (Contour,
+                        Point,
+                        Polygon)
+from . import strategies
+
+
+@given(strategies.polygons_like)
+def test_basic(polygon: Polygon) -> None:
+    assert isinstance(polygon.centroid, Point)
+
+
+@given(strategies.polygons)
+def test_inside_bounding_box(polygon: Polygon) -> None:
+    centroid = polygon.centroid
+    bounding_box = polygon.bounding_box
+
+    assert bounding_box.min_x <= centroid.x <= bounding_box.max_x
+    assert bounding_box.min_y <= centroid.y <= bounding_box.max_y
+
+
+def test_zero_area() -> None:
+    polygon = Polygon(
+            Contour([Point(0, 0), Point(1, 1), Point(2, 2), Point(5, 5)]), []
+    )
+
+    assert polygon.centroid == Point(2, 2)
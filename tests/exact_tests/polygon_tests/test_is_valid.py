@@ -0,0 +1,40 @@
+from hypothesis import given
+
+from rene.exact import Polygon
+from tests.utils import (equivalence,
+                         reverse_polygon_coordinates,
+                         reverse_polygon_holes,
+                         rotate_polygon_border,
+                         rotate_polygon_holes)
+from . import strategies
+
+
+@given(strategies.polygons_like)
+def test_basic(polygon: Polygon) -> None:
+    assert isinstance(polygon.is_valid(), bool)
+
+
+@given(strategies.polygons)
+def test_valid(polygon: Polygon) -> None:
+    assert polygon.is_valid()
+
+
+@given(strategies.polygons_like)
+def test_reversals(polygon: Polygon) -> None:
+    assert equivalence(polygon.is_valid(),
+                       reverse_polygon_coordinates(polygon).is_valid())
+    assert equivalence(polygon.is_valid(),
+                       reverse_polygon_holes(polygon).is_valid())
+
+
+@given(strategies.polygons_like, strategies.non_zero_integers)
+def test_rotations(polygon: Polygon, offset: int) -> None:
+    assert equivalence(polygon.is_valid(),
+                       rotate_polygon_border(polygon, offset).is_valid())
+    assert equivalence(polygon.is_valid(),
+                       rotate_polygon_holes(polygon, offset).is_valid())
+
+
+@given(strategies.polygons_like)
+def test_validity_explanation(polygon: Polygon) -> None:
+    assert equivalence(polygon.is_valid(), not polygon.explain_validity())
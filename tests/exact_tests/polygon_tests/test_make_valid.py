@@ -0,0 +1,37 @@
+from hypothesis import given
+
+from rene import (FillRule,
+                  Relation)
+from rene.exact import (Empty,
+                        Multipolygon,
+                        Polygon)
+from . import strategies
+
+
+@given(strategies.polygons_like, strategies.fill_rules)
+def test_basic(polygon: Polygon, fill_rule: FillRule) -> None:
+    result = polygon.make_valid(fill_rule)
+
+    assert isinstance(result, (Empty, Multipolygon, Polygon))
+
+
+@given(strategies.polygons_like, strategies.fill_rules)
+def test_validity(polygon: Polygon, fill_rule: FillRule) -> None:
+    result = polygon.make_valid(fill_rule)
+
+    assert isinstance(result, Empty) or result.is_valid()
+
+
+@given(strategies.polygons_like)
+def test_fill_rules(polygon: Polygon) -> None:
+    even_odd_result = polygon.make_valid(FillRule.EVEN_ODD)
+    non_zero_result = polygon.make_valid(FillRule.NON_ZERO)
+
+    assert isinstance(even_odd_result - non_zero_result, Empty)
+
+
+@given(strategies.polygons, strategies.fill_rules)
+def test_valid(polygon: Polygon, fill_rule: FillRule) -> None:
+    result = polygon.make_valid(fill_rule)
+
+    assert result.relate_to(polygon) is Relation.EQUAL
@@ -0,0 +1,27 @@
+from hypothesis import strategies as _st
+
+from rene.exact import (Point as _Point,
+                        Segment as _Segment)
+from tests.exact_tests import strategies as _strategies
+
+segments_lists = _st.lists(_strategies.segments)
+_small_integers = _st.integers(-10, 10)
+
+
+def _to_collinear_segment(start: int, end: int) -> _Segment:
+    return _Segment(_Point(start, start), _Point(end, end))
+
+
+overlapping_segments_lists = _st.lists(
+        _st.lists(_small_integers,
+                  min_size=2,
+                  max_size=2,
+                  unique=True)
+        .map(lambda coordinates: _to_collinear_segment(*coordinates)),
+        min_size=2
+)
+segments_lists |= overlapping_segments_lists.flatmap(
+        lambda overlapping_segments: _st.lists(_strategies.segments).map(
+                overlapping_segments.__add__
+        )
+)
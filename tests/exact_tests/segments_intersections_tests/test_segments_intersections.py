@@ -0,0 +1,76 @@
+import typing as t
+
+from hypothesis import given
+
+from rene import Relation
+from rene.exact import (Point,
+                        Segment,
+                        segments_intersections)
+from . import strategies
+
+
+@given(strategies.segments_lists)
+def test_basic(segments: t.List[Segment]) -> None:
+    result = segments_intersections(segments)
+
+    assert isinstance(result, list)
+    assert all(isinstance(element, tuple) and len(element) == 4
+               for element in result)
+    assert all(isinstance(first_id, int) and isinstance(second_id, int)
+               and isinstance(relation, Relation)
+               and isinstance(intersection, (Point, Segment))
+               for first_id, second_id, relation, intersection in result)
+
+
+@given(strategies.segments_lists)
+def test_brute_force(segments: t.List[Segment]) -> None:
+    result = segments_intersections(segments)
+
+    assert [
+        (first_id, second_id) for first_id, second_id, _, _ in result
+    ] == [
+        (first_id, second_id)
+        for first_id, first_segment in enumerate(segments)
+        for second_id in range(first_id + 1, len(segments))
+        if (first_segment.relate_to(segments[second_id])
+            is not Relation.DISJOINT)
+    ]
+
+
+@given(strategies.segments_lists)
+def test_properties(segments: t.List[Segment]) -> None:
+    result = segments_intersections(segments)
+
+    for first_id, second_id, relation, intersection in result:
+        first_segment, second_segment = segments[first_id], segments[second_id]
+        segments_relation = first_segment.relate_to(second_segment)
+        if relation is Relation.OVERLAP:
+            assert isinstance(intersection, Segment)
+            assert segments_relation in (Relation.COMPONENT,
+                                         Relation.COMPOSITE, Relation.EQUAL,
+                                         Relation.OVERLAP)
+            assert intersection.relate_to(first_segment) in (
+                Relation.COMPONENT, Relation.EQUAL
+            )
+            assert intersection.relate_to(second_segment) in (
+                Relation.COMPONENT, Relation.EQUAL
+            )
+        else:
+            assert relation in (Relation.CROSS, Relation.TOUCH)
+            assert isinstance(intersection, Point)
+            assert segments_relation is relation
+            assert intersection in first_segment
+            assert intersection in second_segment
+
+
+@given(strategies.overlapping_segments_lists)
+def test_overlapping_segments(segments: t.List[Segment]) -> None:
+    result = segments_intersections(segments)
+
+    assert all(relation is not Relation.CROSS for _, _, relation, _ in result)
+    assert len(result) == sum(
+            first_segment.relate_to(segments[second_id])
+            is not Relation.DISJOINT
+            for first_id, first_segment in enumerate(segments)
+            for second_id in range(first_id + 1, len(segments))
+    )
@@ -4,6 +4,7 @@ from ground import hints  # type: ignore
 from hypothesis import strategies
 from hypothesis_geometry import planar  # type: ignore
 
+from rene import FillRule
 from rene.exact import (Box,
                         Contour,
                         Empty,
@@ -27,6 +28,7 @@ scalars_strategies = strategies.sampled_from([
                       allow_nan=False)
 ])
 empty_geometries = strategies.builds(Empty)
+fill_rules = strategies.sampled_from([FillRule.EVEN_ODD, FillRule.NON_ZERO])
 
 
 def to_box(raw_box: hints.Box) -> Box:
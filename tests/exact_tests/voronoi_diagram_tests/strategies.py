@@ -0,0 +1,23 @@
+import typing as _t
+
+from hypothesis import strategies as _st
+
+from rene.exact import (Box as _Box,
+                        Point as _Point)
+from tests.exact_tests import strategies as _strategies
+
+boxes = _strategies.boxes
+points_lists = _st.lists(_strategies.points,
+                         min_size=1)
+
+
+def _to_enclosing_box(points: _t.Sequence[_Point]) -> _Box:
+    xs, ys = [point.x for point in points], [point.y for point in points]
+    return _Box(min(xs) - 1, max(xs) + 1, min(ys) - 1, max(ys) + 1)
+
+
+points_lists_with_boxes = (
+        _st.tuples(points_lists, boxes)
+        | points_lists.map(lambda points: (points,
+                                           _to_enclosing_box(points)))
+)
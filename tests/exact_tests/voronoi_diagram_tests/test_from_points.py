@@ -0,0 +1,62 @@
+from typing import (Sequence,
+                    Tuple)
+
+from hypothesis import given
+
+from rene import Location
+from rene.exact import (Box,
+                        Point,
+                        Polygon,
+                        VoronoiDiagram)
+from . import strategies
+
+
+@given(strategies.points_lists_with_boxes)
+def test_basic(points_with_box: Tuple[Sequence[Point], Box]) -> None:
+    points, box = points_with_box
+
+    result = VoronoiDiagram.from_points(points, box)
+
+    assert isinstance(result, VoronoiDiagram)
+
+
+@given(strategies.points_lists_with_boxes)
+def test_sites(points_with_box: Tuple[Sequence[Point], Box]) -> None:
+    points, box = points_with_box
+
+    result = VoronoiDiagram.from_points(points, box)
+
+    assert result.sites == sorted(set(points))
+    assert len(result.cells) == len(result.sites)
+
+
+@given(strategies.points_lists_with_boxes)
+def test_cells_sites(points_with_box: Tuple[Sequence[Point], Box]) -> None:
+    points, box = points_with_box
+
+    result = VoronoiDiagram.from_points(points, box)
+
+    assert all(
+            cell is not None
+            and Polygon(cell, []).locate(site) is not Location.EXTERIOR
+            for site, cell in zip(result.sites, result.cells)
+            if (box.min_x < site.x < box.max_x
+                and box.min_y < site.y < box.max_y)
+    )
+
+
+@given(strategies.points_lists_with_boxes)
+def test_cells_vertices(points_with_box: Tuple[Sequence[Point], Box]) -> None:
+    points, box = points_with_box
+
+    result = VoronoiDiagram.from_points(points, box)
+
+    sites = result.sites
+    assert all(
+            all(vertex.squared_distance_to(site)
+                <= vertex.squared_distance_to(other_site)
+                for other_site in sites)
+            for site, cell in zip(sites, result.cells)
+            if cell is not None
+            for vertex in cell.vertices
+    )
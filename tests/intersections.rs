@@ -0,0 +1,233 @@
+use rene::geometries::{Contour, Multisegment, Polygon, Segment};
+use rene::intersections::{
+    SegmentsIntersection, SegmentsIntersections, SegmentsIntersectionsWith,
+};
+use rene::locatable::{Locatable, Location};
+use rene::relatable::{Relatable, Relation};
+use rene::traits::Segmental;
+
+mod common;
+
+use common::{to_point, to_segment, Random, Scalar};
+
+fn to_expected_relations(
+    first: &[Segment<Scalar>],
+    second: &[Segment<Scalar>],
+) -> Vec<(usize, usize, Relation)> {
+    let mut result = Vec::new();
+    for (first_id, first_segment) in first.iter().enumerate() {
+        for (second_id, second_segment) in second.iter().enumerate() {
+            let relation = first_segment.relate_to(second_segment);
+            if relation != Relation::Disjoint {
+                result.push((first_id, second_id, relation));
+            }
+        }
+    }
+    result
+}
+
+fn check_intersections(
+    first: &[Segment<Scalar>],
+    second: &[Segment<Scalar>],
+    intersections: &[(usize, usize, SegmentsIntersection<Scalar>)],
+) {
+    let expected = to_expected_relations(first, second);
+    assert_eq!(intersections.len(), expected.len());
+    for (
+        (first_id, second_id, intersection),
+        &(expected_first_id, expected_second_id, relation),
+    ) in intersections.iter().zip(&expected)
+    {
+        assert_eq!(
+            (*first_id, *second_id),
+            (expected_first_id, expected_second_id)
+        );
+        let (first_segment, second_segment) =
+            (&first[*first_id], &second[*second_id]);
+        match intersection {
+            SegmentsIntersection::Cross(point) => {
+                assert_eq!(relation, Relation::Cross);
+                assert_eq!(first_segment.locate(point), Location::Boundary);
+                assert_eq!(second_segment.locate(point), Location::Boundary);
+            }
+            SegmentsIntersection::Touch(point) => {
+                assert_eq!(relation, Relation::Touch);
+                assert_eq!(first_segment.locate(point), Location::Boundary);
+                assert_eq!(second_segment.locate(point), Location::Boundary);
+            }
+            SegmentsIntersection::Overlap(segment) => {
+                assert!(matches!(
+                    relation,
+                    Relation::Component
+                        | Relation::Composite
+                        | Relation::Equal
+                        | Relation::Overlap
+                ));
+                assert!(matches!(
+                    segment.relate_to(first_segment),
+                    Relation::Component | Relation::Equal
+                ));
+                assert!(matches!(
+                    segment.relate_to(second_segment),
+                    Relation::Component | Relation::Equal
+                ));
+                let (first_start, first_end) = first_segment.endpoints();
+                let (second_start, second_end) = second_segment.endpoints();
+                let (start, end) = segment.endpoints();
+                for endpoint in [start, end] {
+                    assert!([
+                        first_start,
+                        first_end,
+                        second_start,
+                        second_end
+                    ]
+                    .contains(&endpoint));
+                }
+            }
+        }
+    }
+}
+
+fn check_self_intersections(
+    segments: &[Segment<Scalar>],
+    intersections: &[(usize, usize, SegmentsIntersection<Scalar>)],
+) {
+    let expected = to_expected_relations(segments, segments)
+        .into_iter()
+        .filter(|&(first_id, second_id, _)| first_id < second_id)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        intersections
+            .iter()
+            .map(|(first_id, second_id, _)| (*first_id, *second_id))
+            .collect::<Vec<_>>(),
+        expected
+            .iter()
+            .map(|&(first_id, second_id, _)| (first_id, second_id))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn segments_intersections_of_collinear_chains() {
+    let chain = vec![
+        to_segment((0, 0), (2, 0)),
+        to_segment((1, 0), (3, 0)),
+        to_segment((2, 0), (4, 0)),
+        to_segment((3, 0), (5, 0)),
+        to_segment((4, 0), (6, 0)),
+    ];
+    let intersections = chain
+        .as_slice()
+        .segments_intersections()
+        .collect::<Vec<_>>();
+    check_self_intersections(&chain, &intersections);
+    assert_eq!(intersections.len(), 7);
+    let mut reversed_chain = chain.clone();
+    reversed_chain.reverse();
+    let intersections = reversed_chain
+        .as_slice()
+        .segments_intersections()
+        .collect::<Vec<_>>();
+    check_self_intersections(&reversed_chain, &intersections);
+}
+
+#[test]
+fn segments_intersections_of_collinear_segments_match_brute_force() {
+    let mut random = Random::new(2_463_534_242);
+    for _ in 0..2_000 {
+        let count = 3 + random.below(10) as usize;
+        let segments = (0..count)
+            .map(|_| {
+                let start = random.below(8);
+                let end = start + 1 + random.below(4);
+                if random.below(2) == 0 {
+                    to_segment((start, start), (end, end))
+                } else {
+                    to_segment((end, end), (start, start))
+                }
+            })
+            .collect::<Vec<_>>();
+        let intersections = segments
+            .as_slice()
+            .segments_intersections()
+            .collect::<Vec<_>>();
+        check_self_intersections(&segments, &intersections);
+    }
+}
+
+#[test]
+fn segments_intersections_with_matches_brute_force() {
+    let mut random = Random::new(88_172_645_463_325_252);
+    for iteration in 0..2_000 {
+        let limit = [3, 5, 8, 30][iteration % 4];
+        let first_count = 1 + random.below(10) as usize;
+        let first = random.segments(first_count, limit);
+        let second_count = 1 + random.below(10) as usize;
+        let second = random.segments(second_count, limit);
+        let intersections = first
+            .as_slice()
+            .segments_intersections_with(second.as_slice())
+            .collect::<Vec<_>>();
+        check_intersections(&first, &second, &intersections);
+        if first.len() > 1 && second.len() > 1 {
+            let intersections = (&Multisegment::new(first.clone()))
+                .segments_intersections_with(&Multisegment::new(
+                    second.clone(),
+                ))
+                .collect::<Vec<_>>();
+            check_intersections(&first, &second, &intersections);
+        }
+    }
+}
+
+#[test]
+fn segments_intersections_with_overlaps_within_sets() {
+    let first = vec![to_segment((0, 0), (4, 0)), to_segment((2, 0), (6, 0))];
+    let second =
+        vec![to_segment((3, -1), (3, 1)), to_segment((5, -1), (5, 1))];
+    let intersections = first
+        .as_slice()
+        .segments_intersections_with(second.as_slice())
+        .collect::<Vec<_>>();
+    check_intersections(&first, &second, &intersections);
+    assert_eq!(intersections.len(), 3);
+    assert!(matches!(
+        &intersections[0],
+        (0, 0, SegmentsIntersection::Cross(point)) if point == &to_point(3, 0)
+    ));
+}
+
+#[test]
+fn segments_intersections_with_polygon() {
+    let polygon = Polygon::new(
+        Contour::new(vec![
+            to_point(0, 0),
+            to_point(4, 0),
+            to_point(4, 4),
+            to_point(0, 4),
+        ]),
+        vec![Contour::new(vec![
+            to_point(1, 1),
+            to_point(1, 3),
+            to_point(3, 3),
+            to_point(3, 1),
+        ])],
+    );
+    let segments =
+        vec![to_segment((0, 2), (5, 2)), to_segment((2, 0), (2, 1))];
+    let polygon_segments = vec![
+        to_segment((0, 0), (4, 0)),
+        to_segment((4, 0), (4, 4)),
+        to_segment((4, 4), (0, 4)),
+        to_segment((0, 4), (0, 0)),
+        to_segment((1, 1), (1, 3)),
+        to_segment((1, 3), (3, 3)),
+        to_segment((3, 3), (3, 1)),
+        to_segment((3, 1), (1, 1)),
+    ];
+    let intersections = (&Multisegment::new(segments.clone()))
+        .segments_intersections_with(&polygon)
+        .collect::<Vec<_>>();
+    check_intersections(&segments, &polygon_segments, &intersections);
+}
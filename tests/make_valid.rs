@@ -0,0 +1,85 @@
+use rene::geometries::{Contour, Multipolygon, Polygon};
+use rene::traits::Area;
+use rene::validatable::{FillRule, MakeValid};
+
+mod common;
+
+use common::{to_point, to_scalar, Scalar};
+
+fn to_square(
+    (min_x, min_y): (i64, i64),
+    (max_x, max_y): (i64, i64),
+) -> Polygon<Scalar> {
+    Polygon::new(
+        Contour::from(vec![
+            to_point(min_x, min_y),
+            to_point(max_x, min_y),
+            to_point(max_x, max_y),
+            to_point(min_x, max_y),
+        ]),
+        vec![],
+    )
+}
+
+fn to_area(polygons: &[Polygon<Scalar>]) -> Scalar {
+    polygons
+        .iter()
+        .fold(to_scalar(0), |result, polygon| result + polygon.area())
+}
+
+#[test]
+fn self_overlapping_spike_is_removed() {
+    let contour = Contour::from(vec![
+        to_point(0, 0),
+        to_point(4, 0),
+        to_point(4, 4),
+        to_point(2, 4),
+        to_point(2, 6),
+        to_point(2, 4),
+        to_point(0, 4),
+    ]);
+    for fill_rule in [FillRule::EvenOdd, FillRule::NonZero] {
+        let result = (&contour).make_valid(fill_rule);
+        assert_eq!(result.len(), 1);
+        assert_eq!(to_area(&result), to_scalar(16));
+    }
+}
+
+#[test]
+fn polygons_with_common_edge_are_merged() {
+    let multipolygon = Multipolygon::new(vec![
+        to_square((0, 0), (2, 2)),
+        to_square((2, 0), (4, 2)),
+    ]);
+    for fill_rule in [FillRule::EvenOdd, FillRule::NonZero] {
+        let result = (&multipolygon).make_valid(fill_rule);
+        assert_eq!(result.len(), 1);
+        assert_eq!(to_area(&result), to_scalar(8));
+    }
+}
+
+#[test]
+fn coincident_polygons_windings_are_summed() {
+    let multipolygon = Multipolygon::new(vec![
+        to_square((0, 0), (2, 2)),
+        to_square((0, 0), (2, 2)),
+    ]);
+    assert!((&multipolygon).make_valid(FillRule::EvenOdd).is_empty());
+    let result = (&multipolygon).make_valid(FillRule::NonZero);
+    assert_eq!(result.len(), 1);
+    assert_eq!(to_area(&result), to_scalar(4));
+}
+
+#[test]
+fn partially_overlapping_edges_are_split() {
+    let multipolygon = Multipolygon::new(vec![
+        to_square((0, 0), (3, 3)),
+        to_square((1, 0), (2, 1)),
+        to_square((2, 0), (4, 1)),
+    ]);
+    let even_odd_result = (&multipolygon).make_valid(FillRule::EvenOdd);
+    assert_eq!(to_area(&even_odd_result), to_scalar(9 - 2 + 1));
+    let non_zero_result = (&multipolygon).make_valid(FillRule::NonZero);
+    assert_eq!(non_zero_result.len(), 1);
+    assert_eq!(to_area(&non_zero_result), to_scalar(10));
+}
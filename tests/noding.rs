@@ -0,0 +1,120 @@
+use rene::geometries::{Multisegment, Point, Segment};
+use rene::noding::ToNodedSegments;
+use rene::relatable::{Relatable, Relation};
+use rene::traits::Segmental;
+
+mod common;
+
+use common::{to_point, to_segment, Random, Scalar};
+
+fn to_sorted_endpoints(
+    segment: &Segment<Scalar>,
+) -> (&Point<Scalar>, &Point<Scalar>) {
+    let (start, end) = segment.endpoints();
+    if start < end {
+        (start, end)
+    } else {
+        (end, start)
+    }
+}
+
+fn check_noded_segments(
+    segments: &[Segment<Scalar>],
+    noded_segments: &[Segment<Scalar>],
+) {
+    for (index, first) in noded_segments.iter().enumerate() {
+        for second in &noded_segments[index + 1..] {
+            match first.relate_to(second) {
+                Relation::Disjoint => {}
+                Relation::Touch => {
+                    let (first_start, first_end) = first.endpoints();
+                    let (second_start, second_end) = second.endpoints();
+                    assert!(
+                        [first_start, first_end].contains(&second_start)
+                            || [first_start, first_end].contains(&second_end)
+                    );
+                }
+                relation => panic!("unexpected relation: {:?}", relation),
+            }
+        }
+        assert!(segments.iter().any(|segment| matches!(
+            first.relate_to(segment),
+            Relation::Component | Relation::Equal
+        )));
+    }
+    for segment in segments {
+        let (start, end) = to_sorted_endpoints(segment);
+        if start == end {
+            continue;
+        }
+        let mut pieces = noded_segments
+            .iter()
+            .filter(|piece| {
+                matches!(
+                    piece.relate_to(segment),
+                    Relation::Component | Relation::Equal
+                )
+            })
+            .map(to_sorted_endpoints)
+            .collect::<Vec<_>>();
+        pieces.sort();
+        assert!(pieces.first().map(|(start, _)| *start) == Some(start));
+        assert!(pieces.last().map(|(_, end)| *end) == Some(end));
+        assert!(pieces.windows(2).all(|pair| pair[0].1 == pair[1].0));
+    }
+}
+
+#[test]
+fn noded_segments_meet_only_at_endpoints() {
+    let mut random = Random::new(1_234_567_891);
+    for iteration in 0..300 {
+        let limit = [3, 5, 8, 30][iteration % 4];
+        let count = 1 + random.below(12) as usize;
+        let segments = random.segments(count, limit);
+        let noded_segments = segments.as_slice().to_noded_segments();
+        check_noded_segments(&segments, &noded_segments);
+        if segments.len() > 1 {
+            let multisegment = Multisegment::new(segments.clone());
+            assert!(
+                (&multisegment).to_noded_segments().len()
+                    == noded_segments.len()
+            );
+        }
+    }
+}
+
+#[test]
+fn noding_skips_degenerate_segments() {
+    let segments = vec![
+        to_segment((0, 0), (4, 0)),
+        to_segment((2, 0), (2, 0)),
+        to_segment((1, -1), (1, 1)),
+    ];
+
+    let noded_segments = segments.as_slice().to_noded_segments();
+
+    check_noded_segments(&segments, &noded_segments);
+    assert_eq!(noded_segments.len(), 4);
+    let degenerate_point = to_point(2, 0);
+    assert!(noded_segments.iter().all(|segment| {
+        let (start, end) = segment.endpoints();
+        start != &degenerate_point && end != &degenerate_point
+    }));
+}
+
+#[test]
+fn noding_handles_less_than_two_segments() {
+    let no_segments = Vec::<Segment<Scalar>>::new();
+    assert!(no_segments.as_slice().to_noded_segments().is_empty());
+    let degenerate_segments = vec![to_segment((1, 1), (1, 1))];
+    assert!(degenerate_segments
+        .as_slice()
+        .to_noded_segments()
+        .is_empty());
+    let segments = vec![to_segment((0, 0), (1, 1))];
+    assert!(segments.as_slice().to_noded_segments() == segments);
+    assert!(
+        Multisegment::try_new(segments.as_slice().to_noded_segments())
+            .is_err()
+    );
+}
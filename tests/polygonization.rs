@@ -0,0 +1,149 @@
+use rene::geometries::{Contour, Multipolygon, Point, Polygon, Segment};
+use rene::polygonization::Polygonize;
+use rene::traits::{Area, Elemental, Union};
+
+mod common;
+
+use common::{to_scalar, to_segment, Random, Scalar};
+
+fn to_cross_product(
+    first: &Point<Scalar>,
+    second: &Point<Scalar>,
+    third: &Point<Scalar>,
+) -> Scalar {
+    let (first_x, first_y) = first.coordinates();
+    let (second_x, second_y) = second.coordinates();
+    let (third_x, third_y) = third.coordinates();
+    (second_x.clone() - first_x.clone()) * (third_y.clone() - first_y.clone())
+        - (second_y.clone() - first_y.clone())
+            * (third_x.clone() - first_x.clone())
+}
+
+/// counterclockwise triangles strictly containing the center,
+/// so their union is star-shaped and does not enclose any gaps
+fn to_triangles(
+    random: &mut Random,
+    count: usize,
+    limit: u64,
+) -> Vec<[Point<Scalar>; 3]> {
+    let center = Point::new(
+        to_scalar(limit as i64) / to_scalar(2),
+        to_scalar(limit as i64) / to_scalar(2),
+    );
+    let mut result = Vec::with_capacity(count);
+    while result.len() < count {
+        let mut vertices = [
+            random.point(limit),
+            random.point(limit),
+            random.point(limit),
+        ];
+        if to_cross_product(&vertices[0], &vertices[1], &vertices[2])
+            < to_scalar(0)
+        {
+            vertices.swap(1, 2);
+        }
+        if (0..3).all(|index| {
+            to_cross_product(
+                &vertices[index],
+                &vertices[(index + 1) % 3],
+                &center,
+            ) > to_scalar(0)
+        }) {
+            result.push(vertices);
+        }
+    }
+    result
+}
+
+fn to_union(polygons: Vec<Polygon<Scalar>>) -> Vec<Polygon<Scalar>> {
+    polygons.into_iter().fold(Vec::new(), |result, polygon| {
+        match result.len() {
+            0 => vec![polygon],
+            1 => (&result[0]).union(&polygon),
+            _ => (&Multipolygon::new(result)).union(&polygon),
+        }
+    })
+}
+
+fn to_midpoint(
+    first: &Point<Scalar>,
+    second: &Point<Scalar>,
+) -> Point<Scalar> {
+    let (first_x, first_y) = first.coordinates();
+    let (second_x, second_y) = second.coordinates();
+    Point::new(
+        (first_x.clone() + second_x.clone()) / to_scalar(2),
+        (first_y.clone() + second_y.clone()) / to_scalar(2),
+    )
+}
+
+#[test]
+fn faces_areas_sum_to_closed_rings_union_area() {
+    let mut random = Random::new(161_803_398);
+    for iteration in 0..60 {
+        let limit = [4, 8, 16][iteration % 3];
+        let count = 1 + random.below(5) as usize;
+        let triangles = to_triangles(&mut random, count, limit);
+        let mut segments = Vec::<Segment<Scalar>>::new();
+        for (index, [first, second, third]) in triangles.iter().enumerate() {
+            segments.push(Segment::new(first.clone(), second.clone()));
+            segments.push(Segment::new(second.clone(), third.clone()));
+            segments.push(Segment::new(third.clone(), first.clone()));
+            if index % 2 == 0 {
+                // chord splitting the triangle into two faces
+                segments.push(Segment::new(
+                    first.clone(),
+                    to_midpoint(second, third),
+                ));
+            }
+        }
+        let polygonization = segments.as_slice().polygonize();
+        let union = to_union(
+            triangles
+                .iter()
+                .map(|vertices| {
+                    Polygon::new(Contour::from(vertices.clone()), vec![])
+                })
+                .collect(),
+        );
+        assert!(polygonization.get_dangles().is_empty());
+        assert!(polygonization.get_cut_edges().is_empty());
+        assert_eq!(union.len(), 1);
+        assert!(
+            polygonization
+                .get_polygons()
+                .iter()
+                .fold(to_scalar(0), |result, polygon| result + polygon.area())
+                == union[0].area()
+        );
+    }
+}
+
+#[test]
+fn dangles_and_cut_edges_do_not_contribute_area() {
+    let segments = [
+        ((0, 0), (2, 0)),
+        ((2, 0), (2, 2)),
+        ((2, 2), (0, 2)),
+        ((0, 2), (0, 0)),
+        ((2, 1), (4, 1)),
+        ((4, 0), (5, 0)),
+        ((5, 0), (5, 3)),
+        ((5, 3), (4, 3)),
+        ((4, 3), (4, 0)),
+        ((0, 0), (-1, -1)),
+    ]
+    .iter()
+    .map(|&(start, end)| to_segment(start, end))
+    .collect::<Vec<_>>();
+    let polygonization = segments.as_slice().polygonize();
+    assert_eq!(polygonization.get_dangles().len(), 1);
+    assert_eq!(polygonization.get_cut_edges().len(), 1);
+    assert!(
+        polygonization
+            .get_polygons()
+            .iter()
+            .fold(to_scalar(0), |result, polygon| result + polygon.area())
+            == to_scalar(7)
+    );
+}
@@ -0,0 +1,126 @@
+use rene::geometries::{Multisegment, Polygon, Segment};
+use rene::locatable::Locatable;
+use rene::relatable::{Relatable, Relation};
+use rene::seidel::TrapezoidationBuilder;
+use rene::traits::Segmental;
+
+mod common;
+
+use common::{to_contour, Random, Scalar};
+
+/// segments which may share endpoints but intersect nowhere else
+fn to_non_crossing_segments(
+    random: &mut Random,
+    count: usize,
+    limit: u64,
+) -> Vec<Segment<Scalar>> {
+    let mut result = Vec::<Segment<Scalar>>::with_capacity(count);
+    for candidate in random.segments(4 * count, limit) {
+        if result.len() == count {
+            break;
+        }
+        let (start, end) = (&candidate).endpoints();
+        if result
+            .iter()
+            .all(|segment| match candidate.relate_to(segment) {
+                Relation::Disjoint => true,
+                Relation::Touch => {
+                    let (other_start, other_end) = segment.endpoints();
+                    [start, end].contains(&other_start)
+                        || [start, end].contains(&other_end)
+                }
+                _ => false,
+            })
+        {
+            result.push(candidate);
+        }
+    }
+    result
+}
+
+fn to_polygons() -> Vec<Polygon<Scalar>> {
+    vec![
+        // comb with deep concavities
+        Polygon::new(
+            to_contour(&[
+                (0, 0),
+                (12, 0),
+                (12, 10),
+                (10, 10),
+                (10, 2),
+                (8, 2),
+                (8, 10),
+                (6, 10),
+                (6, 2),
+                (4, 2),
+                (4, 10),
+                (2, 10),
+                (2, 2),
+                (0, 2),
+            ]),
+            vec![],
+        ),
+        // square with holes starting at their leftmost vertices
+        Polygon::new(
+            to_contour(&[(0, 0), (14, 0), (14, 12), (0, 12)]),
+            vec![
+                to_contour(&[(2, 2), (2, 6), (6, 6), (6, 2)]),
+                to_contour(&[(8, 2), (10, 6), (12, 2)]),
+                to_contour(&[(3, 8), (4, 10), (7, 10), (8, 8)]),
+            ],
+        ),
+    ]
+}
+
+#[test]
+fn builder_keeps_seed() {
+    assert_eq!(TrapezoidationBuilder::new(42).seed(), 42);
+}
+
+#[test]
+fn trapezoidation_of_polygon_locates_like_polygon() {
+    let mut random = Random::new(2_463_534_242);
+    for polygon in to_polygons() {
+        for seed in [0, 1, 7, 1_000_003] {
+            let trapezoidation =
+                TrapezoidationBuilder::new(seed).build_from_polygon(&polygon);
+            assert_eq!(
+                trapezoidation.height(),
+                TrapezoidationBuilder::new(seed)
+                    .build_from_polygon(&polygon)
+                    .height()
+            );
+            for _ in 0..200 {
+                let point = random.point(16);
+                assert_eq!(
+                    (&trapezoidation).locate(&point),
+                    (&polygon).locate(&point)
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn trapezoidation_of_multisegment_locates_like_multisegment() {
+    let mut random = Random::new(88_172_645_463_325_252);
+    for _ in 0..50 {
+        let count = 2 + random.below(6) as usize;
+        let segments = to_non_crossing_segments(&mut random, count, 12);
+        if segments.len() < 2 {
+            continue;
+        }
+        let multisegment = Multisegment::new(segments);
+        for seed in [0, 3, 11] {
+            let trapezoidation = TrapezoidationBuilder::new(seed)
+                .build_from_multisegment(&multisegment);
+            for _ in 0..50 {
+                let point = random.point(14);
+                assert_eq!(
+                    (&trapezoidation).locate(&point),
+                    (&multisegment).locate(&point)
+                );
+            }
+        }
+    }
+}
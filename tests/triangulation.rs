@@ -0,0 +1,1141 @@
+use std::collections::BTreeSet;
+
+use rene::geometries::{
+    Contour, Multipolygon, Multisegment, Point, Polygon, Segment,
+};
+use rene::locatable::{Locatable, Location};
+use rene::traits::{
+    Area, Elemental, Multisegmental, Multivertexal, Polygonal,
+};
+use rene::triangulation::{
+    ConstrainedDelaunayTriangulation, DelaunayTriangulation,
+    RefinementBuilder, RefinementError, TriangleLocation,
+};
+
+mod common;
+
+use common::{to_contour, to_point, to_scalar, Scalar};
+
+fn to_polygons() -> Vec<Polygon<Scalar>> {
+    vec![
+        // comb with deep concavities
+        Polygon::new(
+            to_contour(&[
+                (0, 0),
+                (12, 0),
+                (12, 10),
+                (10, 10),
+                (10, 2),
+                (8, 2),
+                (8, 10),
+                (6, 10),
+                (6, 2),
+                (4, 2),
+                (4, 10),
+                (2, 10),
+                (2, 2),
+                (0, 2),
+            ]),
+            vec![],
+        ),
+        // holes including a triangular one & one touching the border
+        Polygon::new(
+            to_contour(&[(0, 0), (14, 0), (14, 12), (0, 12), (0, 8)]),
+            vec![
+                to_contour(&[(2, 2), (2, 6), (6, 6), (6, 2)]),
+                to_contour(&[(8, 2), (10, 6), (12, 2)]),
+                to_contour(&[(0, 8), (4, 11), (8, 8), (4, 10)]),
+                to_contour(&[(9, 8), (9, 10), (10, 9), (12, 10), (12, 8)]),
+            ],
+        ),
+        // spiral
+        Polygon::new(
+            to_contour(&[
+                (0, 0),
+                (12, 0),
+                (12, 12),
+                (2, 12),
+                (2, 4),
+                (8, 4),
+                (8, 8),
+                (6, 8),
+                (6, 6),
+                (4, 6),
+                (4, 10),
+                (10, 10),
+                (10, 2),
+                (0, 2),
+            ]),
+            vec![],
+        ),
+    ]
+}
+
+fn to_multipolygons() -> Vec<Multipolygon<Scalar>> {
+    vec![
+        // island inside of a hole
+        Multipolygon::new(vec![
+            Polygon::new(
+                to_contour(&[(0, 0), (12, 0), (12, 12), (0, 12)]),
+                vec![to_contour(&[(2, 2), (2, 10), (10, 10), (10, 2)])],
+            ),
+            Polygon::new(
+                to_contour(&[(4, 4), (8, 4), (8, 8), (4, 8)]),
+                vec![],
+            ),
+        ]),
+        // polygons touching at a vertex
+        Multipolygon::new(vec![
+            Polygon::new(
+                to_contour(&[(0, 0), (6, 0), (6, 6), (0, 6)]),
+                vec![],
+            ),
+            Polygon::new(
+                to_contour(&[(6, 6), (12, 4), (12, 12), (8, 12)]),
+                vec![],
+            ),
+        ]),
+    ]
+}
+
+fn to_query_points() -> Vec<Point<Scalar>> {
+    let mut result = Vec::new();
+    for x in -2..16 {
+        for y in -2..16 {
+            result.push(to_point(x, y));
+        }
+    }
+    // shuffle deterministically, so walks start from various edges
+    let mut random = common::Random::new(2_718_281_828);
+    for index in (1..result.len()).rev() {
+        let other_index = random.below(index as u64 + 1) as usize;
+        result.swap(index, other_index);
+    }
+    result
+}
+
+fn check_triangle_location(
+    location: TriangleLocation,
+    point: &Point<Scalar>,
+    vertices: &[Point<Scalar>],
+    triangles: &[Contour<Scalar>],
+    expected_location: Location,
+) {
+    match location {
+        TriangleLocation::Edge([start_index, end_index]) => {
+            assert_ne!(expected_location, Location::Exterior);
+            assert_eq!(
+                (&Segment::new(
+                    vertices[start_index].clone(),
+                    vertices[end_index].clone(),
+                ))
+                    .locate(point),
+                Location::Boundary
+            );
+        }
+        TriangleLocation::Exterior => {
+            assert_eq!(expected_location, Location::Exterior)
+        }
+        TriangleLocation::Triangle(index) => {
+            assert_eq!(expected_location, Location::Interior);
+            assert_eq!(
+                (&Polygon::new(triangles[index].clone(), vec![]))
+                    .locate(point),
+                Location::Interior
+            );
+        }
+        TriangleLocation::Vertex(index) => {
+            assert_ne!(expected_location, Location::Exterior);
+            assert!(vertices[index] == *point);
+        }
+    }
+}
+
+#[test]
+fn triangulations_are_send_and_sync() {
+    fn assert_send_and_sync<T: Send + Sync>() {}
+    assert_send_and_sync::<DelaunayTriangulation<Point<Scalar>>>();
+    assert_send_and_sync::<ConstrainedDelaunayTriangulation<Point<Scalar>>>();
+}
+
+#[test]
+fn constrained_delaunay_triangulation_of_polygon_locates_points() {
+    for (index, polygon) in to_polygons().into_iter().enumerate() {
+        let triangulation =
+            ConstrainedDelaunayTriangulation::<Point<Scalar>>::from(&polygon);
+        let vertices = triangulation.get_vertices().to_vec();
+        let triangles = triangulation.to_triangles();
+        for point in to_query_points() {
+            let expected_location = (&polygon).locate(&point);
+            assert_eq!(
+                (&triangulation).locate(&point),
+                expected_location,
+                "{} {}",
+                index,
+                point
+            );
+            check_triangle_location(
+                triangulation.locate_triangle(&point),
+                &point,
+                &vertices,
+                &triangles,
+                expected_location,
+            );
+        }
+    }
+}
+
+#[test]
+fn constrained_delaunay_triangulation_of_multipolygon_locates_points() {
+    for (index, multipolygon) in to_multipolygons().into_iter().enumerate() {
+        let triangulation =
+            ConstrainedDelaunayTriangulation::<Point<Scalar>>::from(
+                &multipolygon,
+            );
+        let vertices = triangulation.get_vertices().to_vec();
+        let triangles = triangulation.to_triangles();
+        for point in to_query_points() {
+            let expected_location = (&multipolygon).locate(&point);
+            assert_eq!(
+                (&triangulation).locate(&point),
+                expected_location,
+                "{} {}",
+                index,
+                point
+            );
+            check_triangle_location(
+                triangulation.locate_triangle(&point),
+                &point,
+                &vertices,
+                &triangles,
+                expected_location,
+            );
+        }
+    }
+}
+
+#[test]
+fn delaunay_triangulation_locates_points() {
+    let mut random = common::Random::new(31_415_926);
+    for limit in [2, 4, 8, 14] {
+        let points = (0..30).map(|_| random.point(limit)).collect::<Vec<_>>();
+        let triangulation = DelaunayTriangulation::from(points);
+        let vertices = triangulation.get_vertices().to_vec();
+        let triangles = triangulation.to_triangles();
+        let border = triangulation.to_border().ok();
+        for point in to_query_points() {
+            let expected_location = match &border {
+                Some(border) => {
+                    (&Polygon::new(border.clone(), vec![])).locate(&point)
+                }
+                None => Location::Exterior,
+            };
+            assert_eq!((&triangulation).locate(&point), expected_location);
+            check_triangle_location(
+                triangulation.locate_triangle(&point),
+                &point,
+                &vertices,
+                &triangles,
+                expected_location,
+            );
+        }
+    }
+}
+
+#[test]
+fn delaunay_triangulation_of_square() {
+    let mut triangulation = DelaunayTriangulation::from(vec![
+        to_point(0, 0),
+        to_point(1, 0),
+        to_point(1, 1),
+        to_point(0, 1),
+        to_point(0, 0),
+    ]);
+    assert_eq!(
+        triangulation.to_triangles_indices().collect::<Vec<_>>(),
+        [[0, 2, 1], [1, 2, 3]]
+    );
+    assert_eq!(
+        triangulation.to_edges_indices(),
+        [[0, 1], [0, 2], [1, 2], [1, 3], [2, 3]]
+    );
+    assert_eq!(
+        triangulation.to_triangles_neighbours(),
+        [[None, Some(1), None], [Some(0), None, None]]
+    );
+    assert_eq!(
+        triangulation.to_vertices_edges_indices(),
+        [vec![0, 1], vec![0, 2, 3], vec![4, 2, 1], vec![4, 3]]
+    );
+    assert_eq!(
+        triangulation.locate_triangle(&to_point(0, 0)),
+        TriangleLocation::Vertex(0)
+    );
+    assert_eq!(
+        triangulation.locate_triangle(&to_point(2, 2)),
+        TriangleLocation::Exterior
+    );
+    assert_eq!((&triangulation).locate(&to_point(1, 1)), Location::Boundary);
+    assert_eq!(
+        triangulation.to_gabriel_graph_edges_indices(),
+        [[0, 1], [0, 2], [1, 3], [2, 3]]
+    );
+    assert_eq!(
+        triangulation.to_relative_neighbourhood_graph_edges_indices(),
+        [[0, 1], [0, 2], [1, 3], [2, 3]]
+    );
+    assert_eq!(
+        triangulation.to_minimum_spanning_tree_edges_indices(),
+        [[0, 1], [0, 2], [1, 3]]
+    );
+    assert!(
+        triangulation.to_minimum_spanning_tree().unwrap()
+            == Multisegment::new(vec![
+                Segment::new(to_point(0, 0), to_point(0, 1)),
+                Segment::new(to_point(0, 0), to_point(1, 0)),
+                Segment::new(to_point(0, 1), to_point(1, 1)),
+            ])
+    );
+    assert!(triangulation.to_alpha_shape(&to_scalar(0)).is_empty());
+    assert!(
+        triangulation.to_alpha_shape(&to_scalar(1))
+            == [Polygon::new(
+                to_contour(&[(0, 0), (1, 0), (1, 1), (0, 1)]),
+                vec![],
+            )]
+    );
+    assert!(triangulation.insert(to_point(2, 1)));
+    assert!(!triangulation.insert(to_point(2, 1)));
+    assert_eq!(triangulation.get_vertices().len(), 5);
+    assert_eq!(triangulation.to_triangles().len(), 3);
+    assert!(triangulation.remove(&to_point(0, 0)));
+    assert!(!triangulation.remove(&to_point(0, 0)));
+    assert_eq!(triangulation.to_triangles().len(), 2);
+}
+
+#[test]
+fn delaunay_triangulation_updates_match_rebuilding() {
+    let mut random = common::Random::new(1_618_033_988);
+    for limit in [3, 6, 20] {
+        let mut points = Vec::<Point<Scalar>>::new();
+        let mut triangulation = DelaunayTriangulation::from(points.clone());
+        for step in 0..200 {
+            let point = random.point(limit);
+            let position =
+                points.iter().position(|candidate| *candidate == point);
+            if step % 3 == 2 {
+                assert_eq!(triangulation.remove(&point), position.is_some());
+                if let Some(position) = position {
+                    points.swap_remove(position);
+                }
+            } else {
+                assert_eq!(
+                    triangulation.insert(point.clone()),
+                    position.is_none()
+                );
+                if position.is_none() {
+                    points.push(point);
+                }
+            }
+            assert!(triangulation.get_vertices() == points);
+            check_matches_rebuilt(&triangulation, &points);
+        }
+    }
+}
+
+fn check_matches_rebuilt(
+    triangulation: &DelaunayTriangulation<Point<Scalar>>,
+    points: &[Point<Scalar>],
+) {
+    let rebuilt = DelaunayTriangulation::from(points.to_vec());
+    let mut vertices = triangulation.get_vertices().to_vec();
+    vertices.sort();
+    assert!(vertices == rebuilt.get_vertices());
+    assert_eq!(
+        triangulation.to_edges_indices().len(),
+        rebuilt.to_edges_indices().len()
+    );
+    assert_eq!(
+        triangulation.to_triangles().len(),
+        rebuilt.to_triangles().len()
+    );
+    assert!(triangulation.to_border().ok() == rebuilt.to_border().ok());
+}
+
+#[test]
+fn delaunay_triangulation_removes_hull_vertices() {
+    let mut random = common::Random::new(2_718_281_828);
+    for limit in [3, 6, 20] {
+        for _ in 0..20 {
+            let mut points =
+                (0..15).map(|_| random.point(limit)).collect::<Vec<_>>();
+            let mut triangulation =
+                DelaunayTriangulation::from(points.clone());
+            while let Ok(border) = triangulation.to_border() {
+                let vertex = border.vertices()[0].clone();
+                assert!(triangulation.remove(&vertex));
+                points.retain(|point| *point != vertex);
+                check_matches_rebuilt(&triangulation, &points);
+            }
+        }
+    }
+}
+
+#[test]
+fn delaunay_triangulation_updates_collinear_points() {
+    let line_points = (0..5).map(|x| to_point(x, x)).collect::<Vec<_>>();
+    for removed_point in &line_points {
+        let mut points = line_points.clone();
+        let mut triangulation = DelaunayTriangulation::from(points.clone());
+        assert!(triangulation.remove(removed_point));
+        points.retain(|point| point != removed_point);
+        check_matches_rebuilt(&triangulation, &points);
+        assert!(triangulation.insert(removed_point.clone()));
+        points.push(removed_point.clone());
+        check_matches_rebuilt(&triangulation, &points);
+    }
+    for off_line_point in [to_point(2, 0), to_point(0, 4), to_point(5, 6)] {
+        let mut points = line_points.clone();
+        let mut triangulation = DelaunayTriangulation::from(points.clone());
+        assert!(triangulation.insert(off_line_point.clone()));
+        points.push(off_line_point.clone());
+        check_matches_rebuilt(&triangulation, &points);
+        assert!(triangulation.to_border().is_ok());
+        assert!(triangulation.remove(&off_line_point));
+        points.retain(|point| *point != off_line_point);
+        check_matches_rebuilt(&triangulation, &points);
+        assert!(triangulation.to_border().is_err());
+    }
+}
+
+fn to_triangles_area(triangles: &[Contour<Scalar>]) -> Scalar {
+    triangles.iter().fold(to_scalar(0), |result, triangle| {
+        let area = triangle.area();
+        assert!(area > to_scalar(0));
+        result + area
+    })
+}
+
+fn check_triangles_indices(
+    vertices: &[Point<Scalar>],
+    triangles_indices: &[[usize; 3]],
+    triangles: &[Contour<Scalar>],
+) {
+    assert_eq!(triangles_indices.len(), triangles.len());
+    for (&[first, second, third], triangle) in
+        triangles_indices.iter().zip(triangles)
+    {
+        assert!(
+            Contour::from([
+                vertices[first].clone(),
+                vertices[second].clone(),
+                vertices[third].clone(),
+            ]) == *triangle
+        );
+    }
+}
+
+#[test]
+fn delaunay_triangulation_from_points_covers_convex_hull() {
+    let mut random = common::Random::new(2_718_281_828);
+    for limit in [4, 10, 50] {
+        let mut points = (0..40)
+            .map(|_| random.point(limit))
+            .collect::<Vec<Point<Scalar>>>();
+        let triangulation = DelaunayTriangulation::from(points.clone());
+        points.sort();
+        points.dedup();
+        assert!(triangulation.get_vertices() == points.as_slice());
+        let triangles = triangulation.to_triangles();
+        check_triangles_indices(
+            triangulation.get_vertices(),
+            &triangulation.to_triangles_indices().collect::<Vec<_>>(),
+            &triangles,
+        );
+        let border = triangulation.to_border().unwrap();
+        assert!(to_triangles_area(&triangles) == (&border).area());
+        let hull = Polygon::new(border, vec![]);
+        assert!(points
+            .iter()
+            .all(|point| (&hull).locate(point) != Location::Exterior));
+    }
+    let collinear_points =
+        vec![to_point(0, 0), to_point(1, 1), to_point(2, 2)];
+    let triangulation = DelaunayTriangulation::from(collinear_points);
+    assert!(triangulation.to_triangles().is_empty());
+    assert!(triangulation.to_border().is_err());
+}
+
+#[test]
+fn delaunay_triangulation_from_polygon_uses_its_vertices() {
+    for polygon in to_polygons() {
+        let triangulation = DelaunayTriangulation::from(&polygon);
+        let mut vertices = (&polygon)
+            .border()
+            .vertices()
+            .into_iter()
+            .chain(
+                (&polygon)
+                    .holes()
+                    .into_iter()
+                    .flat_map(|hole| hole.vertices().into_iter()),
+            )
+            .cloned()
+            .collect::<Vec<_>>();
+        vertices.sort();
+        vertices.dedup();
+        assert!(triangulation.get_vertices() == vertices.as_slice());
+        let triangles = triangulation.to_triangles();
+        check_triangles_indices(
+            triangulation.get_vertices(),
+            &triangulation.to_triangles_indices().collect::<Vec<_>>(),
+            &triangles,
+        );
+        assert!(
+            to_triangles_area(&triangles)
+                == (&triangulation.to_border().unwrap()).area()
+        );
+    }
+}
+
+#[test]
+fn constrained_delaunay_triangulation_of_polygon_covers_it() {
+    for polygon in to_polygons() {
+        let triangulation =
+            ConstrainedDelaunayTriangulation::<Point<Scalar>>::from(&polygon);
+        let triangles = triangulation.to_triangles();
+        check_triangles_indices(
+            triangulation.get_vertices(),
+            &triangulation.to_triangles_indices().collect::<Vec<_>>(),
+            &triangles,
+        );
+        assert!(to_triangles_area(&triangles) == (&polygon).area());
+        // collinear vertices are not a part of the border
+        assert!(
+            (&triangulation.to_border().unwrap()).area()
+                == (&polygon).border().area()
+        );
+    }
+}
+
+fn to_doubled_area(vertices: &[&Point<Scalar>]) -> Scalar {
+    vertices.iter().zip(vertices.iter().cycle().skip(1)).fold(
+        to_scalar(0),
+        |result, (start, end)| {
+            result + start.x() * end.y() - start.y() * end.x()
+        },
+    )
+}
+
+fn to_triangle_squared_cosines(vertices: &[&Point<Scalar>]) -> Vec<Scalar> {
+    (0..3)
+        .filter_map(|index| {
+            let apex = vertices[index];
+            let (start, end) =
+                (vertices[(index + 1) % 3], vertices[(index + 2) % 3]);
+            let (start_dx, start_dy) =
+                (start.x() - apex.x(), start.y() - apex.y());
+            let (end_dx, end_dy) = (end.x() - apex.x(), end.y() - apex.y());
+            let dot_product = &start_dx * &end_dx + &start_dy * &end_dy;
+            (dot_product > to_scalar(0)).then(|| {
+                &dot_product * &dot_product
+                    / ((&start_dx * &start_dx + &start_dy * &start_dy)
+                        * (&end_dx * &end_dx + &end_dy * &end_dy))
+            })
+        })
+        .collect()
+}
+
+#[test]
+fn refinement_builder_rejects_invalid_bounds() {
+    for (numerator, denominator) in [(1, 4), (1, 5), (0, 1), (5, 4)] {
+        assert_eq!(
+            RefinementBuilder::new(
+                to_scalar(numerator) / to_scalar(denominator)
+            )
+            .err(),
+            Some(RefinementError::MaxSquaredCosineOutOfRange)
+        );
+    }
+    for (numerator, denominator) in [(1, 3), (6, 7)] {
+        let max_squared_cosine = to_scalar(numerator) / to_scalar(denominator);
+        assert_eq!(
+            RefinementBuilder::new(max_squared_cosine.clone()).err(),
+            Some(RefinementError::UnboundedSteinerPointsCount)
+        );
+        assert!(RefinementBuilder::new_with_max_steiner_points_count(
+            max_squared_cosine,
+            10
+        )
+        .is_ok());
+    }
+    let builder = RefinementBuilder::new(to_scalar(1)).unwrap();
+    for max_area in [0, -1] {
+        assert_eq!(
+            builder.clone().with_max_area(to_scalar(max_area)).err(),
+            Some(RefinementError::NonPositiveMaxArea)
+        );
+    }
+}
+
+#[test]
+fn refinement_satisfies_bounds() {
+    let max_squared_cosine = to_scalar(7) / to_scalar(8);
+    let max_area = to_scalar(3);
+    let builder = RefinementBuilder::new(max_squared_cosine.clone())
+        .unwrap()
+        .with_max_area(max_area.clone())
+        .unwrap();
+    let polygons_contours_vertices = [
+        vec![vec![(0, 0), (16, 0), (16, 1), (0, 1)]],
+        vec![
+            vec![(0, 0), (9, 0), (9, 9), (0, 9)],
+            vec![(3, 3), (3, 6), (6, 6), (6, 3)],
+        ],
+        vec![vec![(0, 0), (8, 0), (8, 2), (2, 2), (2, 8), (0, 8)]],
+    ];
+    for contours_vertices in &polygons_contours_vertices {
+        let polygon = Polygon::new(
+            to_contour(&contours_vertices[0]),
+            contours_vertices[1..]
+                .iter()
+                .map(|hole_vertices| to_contour(hole_vertices))
+                .collect(),
+        );
+        let triangulation = builder.build_from_polygon(&polygon);
+        let expected_doubled_area =
+            contours_vertices
+                .iter()
+                .fold(to_scalar(0), |result, vertices| {
+                    let points = vertices
+                        .iter()
+                        .map(|&(x, y)| to_point(x, y))
+                        .collect::<Vec<_>>();
+                    result
+                        + to_doubled_area(&points.iter().collect::<Vec<_>>())
+                });
+        let triangles_vertices = triangulation
+            .to_triangles_vertices()
+            .map(|(first, second, third)| [first, second, third])
+            .collect::<Vec<_>>();
+        assert!(
+            triangles_vertices
+                .iter()
+                .fold(to_scalar(0), |result, vertices| {
+                    result + to_doubled_area(vertices)
+                })
+                == expected_doubled_area
+        );
+        for vertices in &triangles_vertices {
+            assert!(to_doubled_area(vertices) <= &max_area + &max_area);
+            assert!(to_triangle_squared_cosines(vertices)
+                .iter()
+                .all(|squared_cosine| *squared_cosine <= max_squared_cosine));
+        }
+        assert!(triangulation
+            .get_vertices()
+            .windows(2)
+            .all(|pair| pair[0] < pair[1]));
+    }
+}
+
+#[test]
+fn refinement_stops_at_steiner_points_limit() {
+    let polygon =
+        Polygon::new(to_contour(&[(0, 0), (16, 0), (16, 1), (0, 1)]), vec![]);
+    for count in [0, 1, 5, 20] {
+        let triangulation =
+            RefinementBuilder::new_with_max_steiner_points_count(
+                to_scalar(1) / to_scalar(3),
+                count,
+            )
+            .unwrap()
+            .with_max_area(to_scalar(1) / to_scalar(8))
+            .unwrap()
+            .build_from_polygon(&polygon);
+        assert_eq!(triangulation.get_vertices().len(), 4 + count);
+    }
+}
+
+#[test]
+fn refinement_stops_near_small_input_angles() {
+    let max_squared_cosine = to_scalar(7) / to_scalar(8);
+    let builder = RefinementBuilder::new(max_squared_cosine.clone()).unwrap();
+    let polygon = Polygon::new(
+        to_contour(&[(0, 0), (32, 1), (32, 2), (31, 4), (30, 2)]),
+        vec![],
+    );
+    let triangulation = builder.build_from_polygon(&polygon);
+    let triangles_vertices = triangulation
+        .to_triangles_vertices()
+        .map(|(first, second, third)| [first, second, third])
+        .collect::<Vec<_>>();
+    assert!(
+        triangles_vertices
+            .iter()
+            .fold(to_scalar(0), |result, vertices| {
+                result + to_doubled_area(vertices)
+            })
+            == to_doubled_area(
+                &[(0, 0), (32, 1), (32, 2), (31, 4), (30, 2)]
+                    .map(|(x, y)| to_point(x, y))
+                    .iter()
+                    .collect::<Vec<_>>()
+            )
+    );
+    // only triangles cut off from the small input angle stay bad
+    let lies_on_small_angle_sides = |point: &Point<Scalar>| {
+        [(32, 1), (30, 2)].iter().any(|&(x, y)| {
+            to_scalar(x) * point.y() == to_scalar(y) * point.x()
+        })
+    };
+    assert!(triangles_vertices.iter().all(|vertices| {
+        to_triangle_squared_cosines(vertices)
+            .iter()
+            .all(|squared_cosine| *squared_cosine <= max_squared_cosine)
+            || vertices
+                .iter()
+                .all(|vertex| lies_on_small_angle_sides(vertex))
+    }));
+}
+
+/// checks if the point lies strictly inside of the circumcircle
+/// of the counterclockwise triangle
+fn is_point_in_circumcircle(
+    point: &Point<Scalar>,
+    vertices: [&Point<Scalar>; 3],
+) -> bool {
+    let [(first_dx, first_dy), (second_dx, second_dy), (third_dx, third_dy)] =
+        vertices.map(|vertex| (vertex.x() - point.x(), vertex.y() - point.y()));
+    let to_squared_norm = |dx: &Scalar, dy: &Scalar| dx * dx + dy * dy;
+    to_squared_norm(&first_dx, &first_dy)
+        * (&second_dx * &third_dy - &second_dy * &third_dx)
+        - to_squared_norm(&second_dx, &second_dy)
+            * (&first_dx * &third_dy - &first_dy * &third_dx)
+        + to_squared_norm(&third_dx, &third_dy)
+            * (&first_dx * &second_dy - &first_dy * &second_dx)
+        > to_scalar(0)
+}
+
+#[test]
+fn refinement_keeps_constrained_delaunay_property() {
+    let max_squared_cosine = to_scalar(7) / to_scalar(8);
+    let max_area = to_scalar(2);
+    let builder = RefinementBuilder::new(max_squared_cosine.clone())
+        .unwrap()
+        .with_max_area(max_area.clone())
+        .unwrap();
+    let multipolygon = Multipolygon::new(vec![
+        // with triangular hole
+        Polygon::new(
+            to_contour(&[(0, 0), (8, 0), (8, 8), (0, 8)]),
+            vec![to_contour(&[(2, 2), (4, 6), (6, 2)])],
+        ),
+        // touching the first one at a vertex
+        Polygon::new(
+            to_contour(&[(8, 8), (12, 8), (12, 12), (8, 12)]),
+            vec![],
+        ),
+    ]);
+    let triangulation = builder.build_from_multipolygon(&multipolygon);
+    let vertices = triangulation.get_vertices();
+    assert!(vertices.windows(2).all(|pair| pair[0] < pair[1]));
+    let triangles_indices =
+        triangulation.to_triangles_indices().collect::<Vec<_>>();
+    let to_triangle_vertices =
+        |indices: &[usize; 3]| indices.map(|index| &vertices[index]);
+    assert!(
+        triangles_indices
+            .iter()
+            .fold(to_scalar(0), |result, indices| {
+                result + to_doubled_area(&to_triangle_vertices(indices))
+            })
+            == to_scalar(2 * (64 - 8 + 16))
+    );
+    let constraints = triangulation
+        .to_edges_indices()
+        .into_iter()
+        .zip(triangulation.to_edges_constraints_flags())
+        .filter(|&(_, is_constraint)| is_constraint)
+        .map(|(edge_indices, _)| edge_indices)
+        .collect::<BTreeSet<_>>();
+    for (indices, neighbours) in triangles_indices
+        .iter()
+        .zip(triangulation.to_triangles_neighbours())
+    {
+        let triangle_vertices = to_triangle_vertices(indices);
+        assert!(to_doubled_area(&triangle_vertices) <= &max_area + &max_area);
+        assert!(to_triangle_squared_cosines(&triangle_vertices)
+            .iter()
+            .all(|squared_cosine| *squared_cosine <= max_squared_cosine));
+        for (position, neighbour) in neighbours.iter().enumerate() {
+            let neighbour_index = match neighbour {
+                Some(neighbour_index) => *neighbour_index,
+                None => continue,
+            };
+            let mut edge_indices =
+                [indices[position], indices[(position + 1) % 3]];
+            edge_indices.sort_unstable();
+            if constraints.contains(&edge_indices) {
+                continue;
+            }
+            let apex_index = *triangles_indices[neighbour_index]
+                .iter()
+                .find(|index| !edge_indices.contains(index))
+                .unwrap();
+            assert!(!is_point_in_circumcircle(
+                &vertices[apex_index],
+                triangle_vertices
+            ));
+        }
+    }
+}
+
+#[test]
+fn constrained_delaunay_triangulation_of_multipolygon_covers_it() {
+    for multipolygon in to_multipolygons() {
+        let triangulation =
+            ConstrainedDelaunayTriangulation::from(&multipolygon);
+        let triangles = triangulation.to_triangles();
+        check_triangles_indices(
+            triangulation.get_vertices(),
+            &triangulation.to_triangles_indices().collect::<Vec<_>>(),
+            &triangles,
+        );
+        assert!(to_triangles_area(&triangles) == (&multipolygon).area());
+    }
+}
+
+#[test]
+fn constrained_delaunay_triangulation_of_points_has_constraints_edges() {
+    let mut random = common::Random::new(314_159_265);
+    for iteration in 0..300 {
+        let limit = [4, 8, 20][iteration % 3];
+        let points = (0..random.below(8))
+            .map(|_| random.point(limit))
+            .collect::<Vec<_>>();
+        let segments_count = 2 + random.below(6) as usize;
+        let constraints =
+            Multisegment::new(random.segments(segments_count, limit));
+        let triangulation =
+            ConstrainedDelaunayTriangulation::from((points, &constraints));
+        let vertices = triangulation.get_vertices();
+        let mut constraints_edges = BTreeSet::new();
+        for segment in (&constraints).segments() {
+            let segment_vertices_indices = (0..vertices.len())
+                .filter(|&index| {
+                    segment.locate(&vertices[index]) == Location::Boundary
+                })
+                .collect::<Vec<_>>();
+            // endpoints & crossings of constraints become vertices
+            assert!(segment_vertices_indices.len() >= 2);
+            constraints_edges.extend(
+                segment_vertices_indices
+                    .windows(2)
+                    .map(|indices| [indices[0], indices[1]]),
+            );
+        }
+        assert!(
+            triangulation
+                .to_edges_indices()
+                .into_iter()
+                .zip(triangulation.to_edges_constraints_flags())
+                .filter_map(|(edge, is_constraint)| {
+                    is_constraint.then_some(edge)
+                })
+                .collect::<BTreeSet<_>>()
+                == constraints_edges
+        );
+        let triangles_indices =
+            triangulation.to_triangles_indices().collect::<Vec<_>>();
+        if triangles_indices.is_empty() {
+            // all vertices are collinear
+            continue;
+        }
+        for [start, end] in constraints_edges {
+            assert!(triangles_indices
+                .iter()
+                .any(|triangle| triangle.contains(&start)
+                    && triangle.contains(&end)));
+        }
+    }
+}
+
+fn to_cross_product(
+    vertex: &Point<Scalar>,
+    first: &Point<Scalar>,
+    second: &Point<Scalar>,
+) -> Scalar {
+    let (vertex_x, vertex_y) = vertex.coordinates();
+    let (first_x, first_y) = first.coordinates();
+    let (second_x, second_y) = second.coordinates();
+    (first_x.clone() - vertex_x.clone())
+        * (second_y.clone() - vertex_y.clone())
+        - (first_y.clone() - vertex_y.clone())
+            * (second_x.clone() - vertex_x.clone())
+}
+
+fn check_incidences(
+    vertices: &[Point<Scalar>],
+    edges_indices: &[[usize; 2]],
+    triangles_indices: &[[usize; 3]],
+    triangles_neighbours: &[[Option<usize>; 3]],
+    vertices_edges_indices: &[Vec<usize>],
+) {
+    assert!(edges_indices.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(edges_indices.iter().all(|&[start, end]| start < end));
+    assert_eq!(triangles_neighbours.len(), triangles_indices.len());
+    for (triangle_index, (triangle, neighbours)) in triangles_indices
+        .iter()
+        .zip(triangles_neighbours)
+        .enumerate()
+    {
+        for offset in 0..3 {
+            let (start, end) = (triangle[offset], triangle[(offset + 1) % 3]);
+            let edge = [start.min(end), start.max(end)];
+            assert!(edges_indices.binary_search(&edge).is_ok());
+            let edge_triangles_indices = triangles_indices
+                .iter()
+                .enumerate()
+                .filter(|(_, other)| {
+                    other.contains(&start) && other.contains(&end)
+                })
+                .map(|(index, _)| index)
+                .filter(|&index| index != triangle_index)
+                .collect::<Vec<_>>();
+            match neighbours[offset] {
+                Some(neighbour_index) => {
+                    assert_eq!(edge_triangles_indices, [neighbour_index]);
+                    assert!(triangles_neighbours[neighbour_index]
+                        .contains(&Some(triangle_index)));
+                }
+                None => assert!(edge_triangles_indices.is_empty()),
+            }
+        }
+    }
+    assert_eq!(vertices_edges_indices.len(), vertices.len());
+    for (vertex_index, vertex_edges_indices) in
+        vertices_edges_indices.iter().enumerate()
+    {
+        let mut sorted_edges_indices = vertex_edges_indices.clone();
+        sorted_edges_indices.sort_unstable();
+        assert!(sorted_edges_indices.into_iter().eq((0..edges_indices.len())
+            .filter(|&index| edges_indices[index].contains(&vertex_index))));
+        let neighbours = vertex_edges_indices
+            .iter()
+            .map(|&edge_index| {
+                let [start, end] = edges_indices[edge_index];
+                &vertices[if start == vertex_index { end } else { start }]
+            })
+            .collect::<Vec<_>>();
+        if neighbours.len() > 2 {
+            // at most one gap between consecutive neighbours
+            // is not a counterclockwise turn (the one facing the exterior)
+            let vertex = &vertices[vertex_index];
+            assert!(
+                (0..neighbours.len())
+                    .filter(|&index| {
+                        to_cross_product(
+                            vertex,
+                            neighbours[index],
+                            neighbours[(index + 1) % neighbours.len()],
+                        ) <= to_scalar(0)
+                    })
+                    .count()
+                    <= 1
+            );
+        }
+    }
+}
+
+#[test]
+fn delaunay_triangulation_incidences_are_consistent() {
+    let mut random = common::Random::new(1_414_213_562);
+    for limit in [3, 5, 10, 40] {
+        for points_count in [1, 2, 3, 10, 40] {
+            let points = (0..points_count)
+                .map(|_| random.point(limit))
+                .collect::<Vec<_>>();
+            let triangulation = DelaunayTriangulation::from(points);
+            check_incidences(
+                triangulation.get_vertices(),
+                &triangulation.to_edges_indices(),
+                &triangulation.to_triangles_indices().collect::<Vec<_>>(),
+                &triangulation.to_triangles_neighbours(),
+                &triangulation.to_vertices_edges_indices(),
+            );
+        }
+    }
+}
+
+#[test]
+fn constrained_delaunay_triangulation_incidences_are_consistent() {
+    let triangulations = to_polygons()
+        .iter()
+        .map(ConstrainedDelaunayTriangulation::<Point<Scalar>>::from)
+        .chain(
+            to_multipolygons()
+                .iter()
+                .map(ConstrainedDelaunayTriangulation::from),
+        )
+        .collect::<Vec<_>>();
+    for triangulation in triangulations {
+        let edges_indices = triangulation.to_edges_indices();
+        check_incidences(
+            triangulation.get_vertices(),
+            &edges_indices,
+            &triangulation.to_triangles_indices().collect::<Vec<_>>(),
+            &triangulation.to_triangles_neighbours(),
+            &triangulation.to_vertices_edges_indices(),
+        );
+        assert_eq!(
+            triangulation.to_edges_constraints_flags().len(),
+            edges_indices.len()
+        );
+    }
+}
+
+fn to_squared_distance(
+    first: &Point<Scalar>,
+    second: &Point<Scalar>,
+) -> Scalar {
+    let (first_x, first_y) = first.coordinates();
+    let (second_x, second_y) = second.coordinates();
+    let (delta_x, delta_y) = (
+        first_x.clone() - second_x.clone(),
+        first_y.clone() - second_y.clone(),
+    );
+    delta_x.clone() * delta_x + delta_y.clone() * delta_y
+}
+
+fn to_dot_product(
+    vertex: &Point<Scalar>,
+    first: &Point<Scalar>,
+    second: &Point<Scalar>,
+) -> Scalar {
+    let (vertex_x, vertex_y) = vertex.coordinates();
+    let (first_x, first_y) = first.coordinates();
+    let (second_x, second_y) = second.coordinates();
+    (first_x.clone() - vertex_x.clone())
+        * (second_x.clone() - vertex_x.clone())
+        + (first_y.clone() - vertex_y.clone())
+            * (second_y.clone() - vertex_y.clone())
+}
+
+fn to_sorted_squared_lengths(
+    vertices: &[Point<Scalar>],
+    edges_indices: &[[usize; 2]],
+) -> Vec<Scalar> {
+    let mut result = edges_indices
+        .iter()
+        .map(|&[start, end]| {
+            to_squared_distance(&vertices[start], &vertices[end])
+        })
+        .collect::<Vec<_>>();
+    result.sort();
+    result
+}
+
+fn to_brute_force_minimum_spanning_tree_squared_lengths(
+    vertices: &[Point<Scalar>],
+) -> Vec<Scalar> {
+    let mut edges = (0..vertices.len())
+        .flat_map(|start| {
+            (start + 1..vertices.len()).map(move |end| [start, end])
+        })
+        .collect::<Vec<_>>();
+    edges.sort_by_key(|&[start, end]| {
+        to_squared_distance(&vertices[start], &vertices[end])
+    });
+    let mut components = (0..vertices.len()).collect::<Vec<_>>();
+    let mut result = Vec::new();
+    for [start, end] in edges {
+        let (start_component, end_component) =
+            (components[start], components[end]);
+        if start_component != end_component {
+            for component in &mut components {
+                if *component == end_component {
+                    *component = start_component;
+                }
+            }
+            result.push(to_squared_distance(&vertices[start], &vertices[end]));
+        }
+    }
+    result
+}
+
+#[test]
+fn proximity_graphs_are_nested_and_match_brute_force() {
+    let mut random = common::Random::new(577_215_664);
+    for iteration in 0..200 {
+        let limit = [3, 6, 12, 50][iteration % 4];
+        let points_count = 1 + random.below(25);
+        let points = (0..points_count)
+            .map(|_| random.point(limit))
+            .collect::<Vec<_>>();
+        let triangulation = DelaunayTriangulation::from(points);
+        let vertices = triangulation.get_vertices();
+        let delaunay_edges = triangulation.to_edges_indices();
+        let gabriel_edges = triangulation.to_gabriel_graph_edges_indices();
+        let relative_neighbourhood_edges =
+            triangulation.to_relative_neighbourhood_graph_edges_indices();
+        let minimum_spanning_tree_edges =
+            triangulation.to_minimum_spanning_tree_edges_indices();
+        assert!(minimum_spanning_tree_edges
+            .iter()
+            .all(|edge| relative_neighbourhood_edges.contains(edge)));
+        assert!(relative_neighbourhood_edges
+            .iter()
+            .all(|edge| gabriel_edges.contains(edge)));
+        assert!(gabriel_edges
+            .iter()
+            .all(|edge| delaunay_edges.contains(edge)));
+        let pairs = (0..vertices.len())
+            .flat_map(|start| {
+                (start + 1..vertices.len()).map(move |end| [start, end])
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            gabriel_edges,
+            pairs
+                .iter()
+                .copied()
+                .filter(|&[start, end]| (0..vertices.len()).all(|index| {
+                    index == start
+                        || index == end
+                        || to_dot_product(
+                            &vertices[index],
+                            &vertices[start],
+                            &vertices[end],
+                        ) > to_scalar(0)
+                }))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            relative_neighbourhood_edges,
+            pairs
+                .iter()
+                .copied()
+                .filter(|&[start, end]| {
+                    let squared_length =
+                        to_squared_distance(&vertices[start], &vertices[end]);
+                    (0..vertices.len()).all(|index| {
+                        to_squared_distance(&vertices[index], &vertices[start])
+                            >= squared_length
+                            || to_squared_distance(
+                                &vertices[index],
+                                &vertices[end],
+                            ) >= squared_length
+                    })
+                })
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(minimum_spanning_tree_edges.len(), vertices.len() - 1);
+        assert!(
+            to_sorted_squared_lengths(vertices, &minimum_spanning_tree_edges)
+                == to_brute_force_minimum_spanning_tree_squared_lengths(
+                    vertices
+                )
+        );
+    }
+}
@@ -260,6 +260,18 @@ def to_pairs(values: _st.SearchStrategy[_T],
     return _st.tuples(values, values)
 
 
+def to_squared_circumradius(first: _PointT,
+                            second: _PointT,
+                            third: _PointT,
+                            /) -> t.Any:
+    doubled_area = ((second.x - first.x) * (third.y - first.y)
+                    - (second.y - first.y) * (third.x - first.x))
+    return (_to_squared_distance(first, second)
+            * _to_squared_distance(second, third)
+            * _to_squared_distance(third, first)
+            / (4 * doubled_area * doubled_area))
+
+
 def to_triplets(values: _st.SearchStrategy[_T],
                 /) -> _st.SearchStrategy[t.Tuple[_T, _T, _T]]:
     return _st.tuples(values, values, values)
@@ -280,6 +292,11 @@ def _to_max_sub_hull(points: t.Iterable[_PointT],
     return result
 
 
+def _to_squared_distance(first: _PointT, second: _PointT, /) -> t.Any:
+    return ((first.x - second.x) * (first.x - second.x)
+            + (first.y - second.y) * (first.y - second.y))
+
+
 def _to_sub_hull(points: t.Iterable[_PointT],
                  orienteer: _Orienteer[_PointT],
                  /) -> t.List[_PointT]: